@@ -21,7 +21,7 @@ async fn main() -> Result<(), walletd_bitcoin::Error> {
     println!("next receive address: {}", btc_wallet.receive_address()?);
 
     let balance = btc_wallet.balance().await?;
-    println!("bitcoin wallet balance: {} satoshi", balance.confirmed);
+    println!("bitcoin wallet balance: {}", balance.confirmed);
 
     Ok(())
 }
@@ -0,0 +1,282 @@
+use crate::{BitcoinAmount, BitcoinDenomination, Error, NetworkParams};
+use bdk::bitcoin::{Address, Network};
+use std::fmt;
+use std::str::FromStr;
+
+/// The scheme of a BIP21 URI
+const SCHEME: &str = "bitcoin:";
+
+/// A [BIP21](https://github.com/bitcoin/bips/blob/master/bip-0021.mediawiki) payment URI such as `bitcoin:bc1q...?amount=0.001&label=Shop`
+///
+/// Parsed with [FromStr] and built with [Display][fmt::Display], the amount is kept as a [BitcoinAmount].
+/// A URI with a required (`req-` prefixed) parameter that is not understood is rejected when parsing, see [`PaymentUri::parse_with_required`].
+/// [`BitcoinWallet::transfer_to_uri`][crate::BitcoinWallet::transfer_to_uri] pays a URI after checking its address against the network of the wallet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentUri {
+    /// The address to pay
+    pub address: Address,
+    /// The amount to pay
+    pub amount: Option<BitcoinAmount>,
+    /// The label of the recipient
+    pub label: Option<String>,
+    /// A message describing the payment
+    pub message: Option<String>,
+    /// The other parameters, including the supported required (`req-` prefixed) parameters, in the order they appear in the URI
+    pub params: Vec<(String, String)>,
+}
+
+impl PaymentUri {
+    /// Returns a URI paying the address without amount, label or message
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            amount: None,
+            label: None,
+            message: None,
+            params: Vec::new(),
+        }
+    }
+
+    /// Parses a URI whose address is encoded with the prefixes of the `network_params`, such as the address of a private chain with its own bech32 hrp,
+    /// accepting the required (`req-` prefixed) parameters listed in `supported_required`, which are kept in [params][PaymentUri::params]
+    ///
+    /// Returns an [error][Error] if the URI is invalid, if its address does not belong to the network or if it holds a required parameter that is not supported.
+    pub fn parse_with_required(
+        uri: &str,
+        supported_required: &[&str],
+        network_params: &NetworkParams,
+    ) -> Result<Self, Error> {
+        Self::parse(uri, supported_required, Some(network_params))
+    }
+
+    /// Parses a URI with the address of the network of the `network_params` or, if None, of any of the standard networks
+    fn parse(
+        uri: &str,
+        supported_required: &[&str],
+        network_params: Option<&NetworkParams>,
+    ) -> Result<Self, Error> {
+        let uri = uri.trim();
+        let rest = match uri.get(..SCHEME.len()) {
+            Some(scheme) if scheme.eq_ignore_ascii_case(SCHEME) => &uri[SCHEME.len()..],
+            _ => return Err(Error::FromStr(format!("{} is not a bitcoin URI", uri))),
+        };
+        let (address, query) = match rest.split_once('?') {
+            Some((address, query)) => (address, Some(query)),
+            None => (rest, None),
+        };
+        let address = match network_params {
+            Some(network_params) => network_params.parse_address(address)?,
+            None => Address::from_str(address)
+                .map_err(|e| Error::InvalidAddress(format!("{}: {}", address, e)))?,
+        };
+        let mut payment_uri = Self::new(address);
+        for param in query.into_iter().flat_map(|query| query.split('&')) {
+            if param.is_empty() {
+                continue;
+            }
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            let value = percent_decode(value)?;
+            match key {
+                "amount" => {
+                    if payment_uri.amount.is_some() {
+                        return Err(Error::FromStr("The amount is specified twice".to_string()));
+                    }
+                    payment_uri.amount = Some(BitcoinAmount::from_str_in(
+                        &value,
+                        BitcoinDenomination::Bitcoin,
+                    )?);
+                }
+                "label" => payment_uri.label = Some(value),
+                "message" => payment_uri.message = Some(value),
+                _ => {
+                    if key.starts_with("req-") && !supported_required.contains(&key) {
+                        return Err(Error::FromStr(format!(
+                            "Required parameter {} is not supported",
+                            key
+                        )));
+                    }
+                    payment_uri.params.push((key.to_string(), value));
+                }
+            }
+        }
+        Ok(payment_uri)
+    }
+
+    /// Checks that the address of the URI is valid for the `network`
+    pub fn validate_network(&self, network: Network) -> Result<(), Error> {
+        if !self.address.is_valid_for_network(network) {
+            return Err(Error::WrongNetwork {
+                address: self.address.to_string(),
+                network,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PaymentUri {
+    type Err = Error;
+
+    /// Parses a URI with the address of any of the standard networks, rejecting it if it holds any required (`req-` prefixed) parameter
+    fn from_str(uri: &str) -> Result<Self, Error> {
+        Self::parse(uri, &[], None)
+    }
+}
+
+impl fmt::Display for PaymentUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", SCHEME, self.address)?;
+        let mut params = Vec::new();
+        if let Some(amount) = self.amount {
+            params.push(format!("amount={}", amount.btc()));
+        }
+        if let Some(label) = &self.label {
+            params.push(format!("label={}", percent_encode(label)));
+        }
+        if let Some(message) = &self.message {
+            params.push(format!("message={}", percent_encode(message)));
+        }
+        for (key, value) in &self.params {
+            params.push(format!("{}={}", key, percent_encode(value)));
+        }
+        if !params.is_empty() {
+            write!(f, "?{}", params.join("&"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Percent-encodes every character except the unreserved characters of RFC 3986
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Decodes a percent-encoded UTF-8 value
+fn percent_decode(value: &str) -> Result<String, Error> {
+    let invalid = || Error::FromStr(format!("Invalid percent-encoding in {}", value));
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut chars = value.bytes();
+    while let Some(byte) = chars.next() {
+        if byte == b'%' {
+            let high = chars.next().ok_or_else(invalid)?;
+            let low = chars.next().ok_or_else(invalid)?;
+            let hex = [high, low];
+            let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+
+    #[test]
+    fn test_parse() -> Result<(), Error> {
+        let uri = PaymentUri::from_str(&format!(
+            "BITCOIN:{}?amount=20.3&label=Luke-Jr&message=Donation%20for%20project%20xyz&somethingyoudontunderstand=50",
+            ADDRESS
+        ))?;
+        assert_eq!(uri.address.to_string(), ADDRESS);
+        assert_eq!(uri.amount, Some(BitcoinAmount::from_sat(2_030_000_000)));
+        assert_eq!(uri.label.as_deref(), Some("Luke-Jr"));
+        assert_eq!(uri.message.as_deref(), Some("Donation for project xyz"));
+        assert_eq!(
+            uri.params,
+            vec![("somethingyoudontunderstand".to_string(), "50".to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_required_params() -> Result<(), Error> {
+        let uri = format!("bitcoin:{}?req-somethingyoudontunderstand=50", ADDRESS);
+        assert!(PaymentUri::from_str(&uri).is_err());
+        let parsed = PaymentUri::parse_with_required(
+            &uri,
+            &["req-somethingyoudontunderstand"],
+            &NetworkParams::default(),
+        )?;
+        assert_eq!(parsed.params[0].0, "req-somethingyoudontunderstand");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_with_custom_hrp() -> Result<(), Error> {
+        let params = NetworkParams {
+            bech32_hrp: "rt".to_string(),
+            ..NetworkParams::from(Network::Regtest)
+        };
+        let address = params.address_to_string(&Address::from_str(ADDRESS).unwrap());
+        assert!(address.starts_with("rt1q"));
+        let uri = format!("bitcoin:{}?amount=0.001", address);
+        assert!(PaymentUri::from_str(&uri).is_err());
+        let parsed = PaymentUri::parse_with_required(&uri, &[], &params)?;
+        assert_eq!(params.address_to_string(&parsed.address), address);
+        parsed.validate_network(Network::Regtest)?;
+        assert!(matches!(
+            PaymentUri::parse_with_required(&format!("bitcoin:{}", ADDRESS), &[], &params),
+            Err(Error::WrongNetwork { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), Error> {
+        let mut uri = PaymentUri::new(Address::from_str(ADDRESS).unwrap());
+        uri.amount = Some(BitcoinAmount::from_sat(100_000));
+        uri.label = Some("Café & Co".to_string());
+        let built = uri.to_string();
+        assert_eq!(
+            built,
+            format!(
+                "bitcoin:{}?amount=0.001&label=Caf%C3%A9%20%26%20Co",
+                ADDRESS
+            )
+        );
+        assert_eq!(PaymentUri::from_str(&built)?, uri);
+        assert_eq!(
+            PaymentUri::new(Address::from_str(ADDRESS).unwrap()).to_string(),
+            format!("bitcoin:{}", ADDRESS)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_uri() {
+        for invalid in [
+            format!("litecoin:{}", ADDRESS),
+            "bitcoin:notanaddress".to_string(),
+            format!("bitcoin:{}?amount=1.123456789", ADDRESS),
+            format!("bitcoin:{}?amount=1e3", ADDRESS),
+            format!("bitcoin:{}?amount=1&amount=2", ADDRESS),
+            format!("bitcoin:{}?label=%ZZ", ADDRESS),
+        ] {
+            assert!(PaymentUri::from_str(&invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_validate_network() -> Result<(), Error> {
+        let uri = PaymentUri::from_str(&format!("bitcoin:{}", ADDRESS))?;
+        uri.validate_network(Network::Bitcoin)?;
+        assert!(matches!(
+            uri.validate_network(Network::Testnet),
+            Err(Error::WrongNetwork { .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,376 @@
+use crate::Error;
+use bdk::bitcoin::Amount;
+use bdk::Balance;
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+/// A unit in which a [BitcoinAmount] is expressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitcoinDenomination {
+    /// BTC, 100,000,000 satoshi
+    Bitcoin,
+    /// mBTC, 100,000 satoshi
+    MilliBitcoin,
+    /// bits (µBTC), 100 satoshi
+    Bit,
+    /// sat, the smallest unit
+    Satoshi,
+}
+
+impl BitcoinDenomination {
+    /// Returns the number of decimals of an amount in the unit, which is the power of ten of satoshi in one unit
+    pub fn decimals(&self) -> usize {
+        match self {
+            Self::Bitcoin => 8,
+            Self::MilliBitcoin => 5,
+            Self::Bit => 2,
+            Self::Satoshi => 0,
+        }
+    }
+
+    /// Returns the number of satoshi in one unit
+    fn satoshi(&self) -> u64 {
+        10u64.pow(self.decimals() as u32)
+    }
+}
+
+impl fmt::Display for BitcoinDenomination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Bitcoin => "BTC",
+            Self::MilliBitcoin => "mBTC",
+            Self::Bit => "bits",
+            Self::Satoshi => "sat",
+        })
+    }
+}
+
+impl FromStr for BitcoinDenomination {
+    type Err = Error;
+
+    /// Parses the symbol of the unit, case-insensitive except for the prefix of mBTC
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit {
+            "mBTC" | "mbtc" => return Ok(Self::MilliBitcoin),
+            "MBTC" => return Err(Error::FromStr(format!("Ambiguous unit {}", unit))),
+            _ => {}
+        }
+        match unit.to_lowercase().as_str() {
+            "btc" => Ok(Self::Bitcoin),
+            "bit" | "bits" | "µbtc" | "ubtc" => Ok(Self::Bit),
+            "sat" | "sats" | "satoshi" => Ok(Self::Satoshi),
+            _ => Err(Error::FromStr(format!("Unknown unit {}", unit))),
+        }
+    }
+}
+
+/// Contains a field representing the amount of satoshi in the amount. Also has functions to convert to and from BTC, mBTC, bits and satoshi.
+///
+/// The conversions parse and format exact decimal strings, with a `.` as decimal separator and no digit grouping whatever the locale.
+/// Parsed with [FromStr] from a value followed by its unit such as `0.001 BTC`, and displayed in BTC.
+#[derive(Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Hash)]
+pub struct BitcoinAmount {
+    /// The number of satoshi in the amount
+    pub satoshi: u64,
+}
+
+impl ops::Add<Self> for BitcoinAmount {
+    type Output = Result<Self, Error>;
+
+    fn add(self, rhs: Self) -> Result<Self, Error> {
+        Ok(Self {
+            satoshi: self
+                .satoshi
+                .checked_add(rhs.satoshi)
+                .ok_or(Error::Overflow(format!(
+                    "Overflow when adding {} to {} satoshi",
+                    rhs.satoshi, self.satoshi
+                )))?,
+        })
+    }
+}
+
+impl ops::Sub for BitcoinAmount {
+    type Output = Result<Self, Error>;
+
+    fn sub(self, rhs: Self) -> Result<Self, Error> {
+        Ok(Self {
+            satoshi: self
+                .satoshi
+                .checked_sub(rhs.satoshi)
+                .ok_or(Error::Overflow(format!(
+                    "Overflow when subtracting {} from {} satoshi",
+                    rhs.satoshi, self.satoshi
+                )))?,
+        })
+    }
+}
+
+impl ops::Mul<u64> for BitcoinAmount {
+    type Output = Result<Self, Error>;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        Ok(Self {
+            satoshi: self
+                .satoshi
+                .checked_mul(rhs)
+                .ok_or(Error::Overflow(format!(
+                    "Overflow when multiplying {} satoshi by {}",
+                    self.satoshi, rhs
+                )))?,
+        })
+    }
+}
+
+impl BitcoinAmount {
+    /// Creates a new BitcoinAmount from the satoshi amount
+    pub fn from_sat(satoshi: u64) -> Self {
+        Self { satoshi }
+    }
+
+    /// Returns the number of satoshi in the amount
+    pub fn sat(&self) -> u64 {
+        self.satoshi
+    }
+
+    /// Creates a new BitcoinAmount from a decimal string in BTC, such as `0.001`
+    pub fn from_btc(btc: &str) -> Result<Self, Error> {
+        Self::from_str_in(btc, BitcoinDenomination::Bitcoin)
+    }
+
+    /// Returns the amount in BTC as a decimal string
+    pub fn btc(&self) -> String {
+        self.to_string_in(BitcoinDenomination::Bitcoin)
+    }
+
+    /// Creates a new BitcoinAmount from a decimal string in the `denomination`
+    ///
+    /// Returns an [error][Error] if the value is not a plain decimal number, has more decimals than the denomination allows or overflows.
+    pub fn from_str_in(value: &str, denomination: BitcoinDenomination) -> Result<Self, Error> {
+        let invalid = || Error::FromStr(format!("Invalid amount {} {}", value, denomination));
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        let decimals = denomination.decimals();
+        if (whole.is_empty() && fraction.is_empty())
+            || fraction.len() > decimals
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let whole: u64 = match whole.is_empty() {
+            true => 0,
+            false => whole.parse().map_err(|_| invalid())?,
+        };
+        let fraction: u64 = match decimals {
+            0 => 0,
+            _ => format!("{:0<width$}", fraction, width = decimals)
+                .parse()
+                .map_err(|_| invalid())?,
+        };
+        whole
+            .checked_mul(denomination.satoshi())
+            .and_then(|satoshi| satoshi.checked_add(fraction))
+            .map(Self::from_sat)
+            .ok_or_else(|| {
+                Error::Overflow(format!("Amount {} {} is too large", value, denomination))
+            })
+    }
+
+    /// Returns the amount as a decimal string in the `denomination`, without trailing zeros
+    pub fn to_string_in(&self, denomination: BitcoinDenomination) -> String {
+        let whole = self.satoshi / denomination.satoshi();
+        let fraction = self.satoshi % denomination.satoshi();
+        if fraction == 0 {
+            return whole.to_string();
+        }
+        let fraction = format!("{:0width$}", fraction, width = denomination.decimals());
+        format!("{}.{}", whole, fraction.trim_end_matches('0'))
+    }
+
+    /// Returns the amount as a decimal string in the `denomination` followed by its unit, such as `1.5 mBTC`
+    pub fn to_string_with_denomination(&self, denomination: BitcoinDenomination) -> String {
+        format!("{} {}", self.to_string_in(denomination), denomination)
+    }
+}
+
+impl fmt::Display for BitcoinAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_with_denomination(BitcoinDenomination::Bitcoin))
+    }
+}
+
+impl FromStr for BitcoinAmount {
+    type Err = Error;
+
+    fn from_str(amount: &str) -> Result<Self, Self::Err> {
+        let (value, unit) = amount.trim().split_once(' ').ok_or_else(|| {
+            Error::FromStr(format!(
+                "The amount {} needs a unit such as BTC or sat",
+                amount
+            ))
+        })?;
+        Self::from_str_in(value, unit.trim().parse()?)
+    }
+}
+
+impl From<Amount> for BitcoinAmount {
+    fn from(amount: Amount) -> Self {
+        Self::from_sat(amount.to_sat())
+    }
+}
+
+impl From<BitcoinAmount> for Amount {
+    fn from(amount: BitcoinAmount) -> Self {
+        Amount::from_sat(amount.satoshi)
+    }
+}
+
+/// The balance of a [BitcoinWallet][crate::BitcoinWallet] split by the state of its outputs
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct BitcoinBalance {
+    /// The outputs of coinbase transactions that are not mature yet
+    pub immature: BitcoinAmount,
+    /// The unconfirmed outputs received from the wallet itself, such as the change
+    pub trusted_pending: BitcoinAmount,
+    /// The unconfirmed outputs received from others
+    pub untrusted_pending: BitcoinAmount,
+    /// The confirmed outputs
+    pub confirmed: BitcoinAmount,
+}
+
+impl BitcoinBalance {
+    /// Returns the amount that can be spent without waiting for confirmations, the confirmed and trusted pending outputs
+    pub fn spendable(&self) -> BitcoinAmount {
+        BitcoinAmount::from_sat(
+            self.confirmed
+                .satoshi
+                .saturating_add(self.trusted_pending.satoshi),
+        )
+    }
+
+    /// Returns the sum of all the outputs
+    pub fn total(&self) -> BitcoinAmount {
+        BitcoinAmount::from_sat(
+            self.spendable()
+                .satoshi
+                .saturating_add(self.untrusted_pending.satoshi)
+                .saturating_add(self.immature.satoshi),
+        )
+    }
+}
+
+impl From<Balance> for BitcoinBalance {
+    fn from(balance: Balance) -> Self {
+        Self {
+            immature: BitcoinAmount::from_sat(balance.immature),
+            trusted_pending: BitcoinAmount::from_sat(balance.trusted_pending),
+            untrusted_pending: BitcoinAmount::from_sat(balance.untrusted_pending),
+            confirmed: BitcoinAmount::from_sat(balance.confirmed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_in_denominations() -> Result<(), Error> {
+        let amount = BitcoinAmount::from_sat(123_456_789);
+        for (value, denomination) in [
+            ("1.23456789", BitcoinDenomination::Bitcoin),
+            ("1234.56789", BitcoinDenomination::MilliBitcoin),
+            ("1234567.89", BitcoinDenomination::Bit),
+            ("123456789", BitcoinDenomination::Satoshi),
+        ] {
+            assert_eq!(BitcoinAmount::from_str_in(value, denomination)?, amount);
+            assert_eq!(amount.to_string_in(denomination), value);
+        }
+        assert_eq!(BitcoinAmount::from_btc(".5")?.sat(), 50_000_000);
+        assert_eq!(
+            BitcoinAmount::from_btc("21000000")?.sat(),
+            2_100_000_000_000_000
+        );
+        assert_eq!(BitcoinAmount::from_sat(100_000).btc(), "0.001");
+        assert_eq!(BitcoinAmount::from_sat(0).btc(), "0");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid_amounts() {
+        for (value, denomination) in [
+            ("", BitcoinDenomination::Bitcoin),
+            (".", BitcoinDenomination::Bitcoin),
+            ("1.123456789", BitcoinDenomination::Bitcoin),
+            ("1.5", BitcoinDenomination::Satoshi),
+            ("1,5", BitcoinDenomination::Bitcoin),
+            ("1e3", BitcoinDenomination::Bitcoin),
+            ("-1", BitcoinDenomination::Bitcoin),
+            ("+1", BitcoinDenomination::Bitcoin),
+        ] {
+            assert!(matches!(
+                BitcoinAmount::from_str_in(value, denomination),
+                Err(Error::FromStr(_))
+            ));
+        }
+        assert!(matches!(
+            BitcoinAmount::from_btc("184467440738"),
+            Err(Error::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_display_and_from_str() -> Result<(), Error> {
+        let amount = BitcoinAmount::from_sat(150_000);
+        assert_eq!(amount.to_string(), "0.0015 BTC");
+        assert_eq!(
+            amount.to_string_with_denomination(BitcoinDenomination::MilliBitcoin),
+            "1.5 mBTC"
+        );
+        for value in [
+            "0.0015 BTC",
+            "1.5 mBTC",
+            "1500 bits",
+            "150000 sat",
+            " 150000 sats ",
+        ] {
+            assert_eq!(BitcoinAmount::from_str(value)?, amount);
+        }
+        assert!(BitcoinAmount::from_str("0.0015").is_err());
+        assert!(BitcoinAmount::from_str("1.5 MBTC").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_arithmetic() -> Result<(), Error> {
+        let amount = BitcoinAmount::from_sat(1_000);
+        assert_eq!((amount + amount)?, BitcoinAmount::from_sat(2_000));
+        assert_eq!((amount - BitcoinAmount::from_sat(400))?.sat(), 600);
+        assert_eq!((amount * 3)?.sat(), 3_000);
+        assert!(matches!(
+            BitcoinAmount::from_sat(0) - amount,
+            Err(Error::Overflow(_))
+        ));
+        assert!(matches!(
+            BitcoinAmount::from_sat(u64::MAX) + amount,
+            Err(Error::Overflow(_))
+        ));
+        assert!(matches!(amount * u64::MAX, Err(Error::Overflow(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_balance() {
+        let balance = BitcoinBalance::from(Balance {
+            immature: 1,
+            trusted_pending: 10,
+            untrusted_pending: 100,
+            confirmed: 1_000,
+        });
+        assert_eq!(balance.spendable().sat(), 1_010);
+        assert_eq!(balance.total().sat(), 1_111);
+    }
+}
@@ -1,22 +1,46 @@
-use crate::Error;
-use bdk::bitcoin::{Address, Txid};
-use bdk::blockchain::{Blockchain, GetHeight, WalletSync};
+use crate::discovery::{self, DiscoveredAccount, DEFAULT_GAP_LIMIT};
+use crate::fee::{self, FeeMap, DEFAULT_CONFIRMATION_TARGETS};
+use crate::history;
+use crate::message;
+use crate::psbt::{self, Psbt};
+use crate::silent_payment::{self, SilentPaymentKeys};
+use crate::utxo::FrozenOutpoints;
+use crate::xpub::AccountXpub;
+use crate::{
+    BitcoinAmount, BitcoinBalance, BitcoinBlockchainConfig, BitcoinDatabaseConfig,
+    BitcoinTransaction, Error, FeePolicy, MessageSignatureFormat, NetworkParams, PaymentUri,
+    SilentPayment, SilentPaymentAddress, TransactionDirection, TransactionQuery, Utxo,
+};
+use async_trait::async_trait;
+use bdk::bitcoin::secp256k1::{
+    KeyPair, PublicKey, Secp256k1, SecretKey, Signing, Verification, XOnlyPublicKey,
+};
+use bdk::bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey};
+use bdk::bitcoin::util::schnorr::{TapTweak, TweakedPublicKey};
+use bdk::bitcoin::{Address, OutPoint, Script, Transaction, Txid};
+use bdk::blockchain::{AnyBlockchain, Blockchain, GetHeight, WalletSync};
 use bdk::keys::bip39::Mnemonic;
 use bdk::keys::{DerivableKey, ExtendedKey};
-use bdk::template::Bip84;
 use bdk::wallet::AddressInfo;
 
-pub use bdk::bitcoin::{AddressType, Script};
-use bdk::{bitcoin::Network, database::MemoryDatabase, wallet::AddressIndex, Wallet};
-use bdk::{Balance, KeychainKind, SignOptions, SyncOptions};
+pub use bdk::bitcoin::AddressType;
+use bdk::database::{AnyDatabase, ConfigurableDatabase, Database};
+use bdk::miniscript::descriptor::{DescriptorSecretKey, Wildcard};
+use bdk::{bitcoin::Network, wallet::AddressIndex, Wallet};
+use bdk::{FeeRate, KeychainKind, SignOptions, SyncOptions, TransactionDetails};
+use std::path::Path;
 use std::str::FromStr;
-use walletd_hd_key::slip44;
+use walletd_coin_core::{CryptoWallet, CryptoWalletBuilder, HDNetworkType, Seed};
 use walletd_hd_key::HDPurpose;
 
 /// Represents a Hierarchical Deterministic (HD) Bitcoin wallet.
 pub struct BitcoinWallet {
-    wallet: Option<Wallet<MemoryDatabase>>,
+    wallet: Option<Wallet<AnyDatabase>>,
     address_format: AddressType,
+    hd_purpose: HDPurpose,
+    frozen_outpoints: FrozenOutpoints,
+    silent_payment_keys: Option<SilentPaymentKeys>,
+    network_params: NetworkParams,
 }
 
 impl Default for BitcoinWallet {
@@ -24,71 +48,547 @@ impl Default for BitcoinWallet {
         Self {
             wallet: None,
             address_format: AddressType::P2wpkh,
+            hd_purpose: HDPurpose::BIP84,
+            frozen_outpoints: FrozenOutpoints::default(),
+            silent_payment_keys: None,
+            network_params: NetworkParams::default(),
         }
     }
 }
 
 impl BitcoinWallet {
     /// Returns the bitcoin balance of the wallet.
-    pub async fn balance(&self) -> Result<Balance, Error> {
-        let balance = self.wallet.as_ref().unwrap().get_balance().unwrap();
-        Ok(balance)
+    pub async fn balance(&self) -> Result<BitcoinBalance, Error> {
+        Ok(self.bdk_wallet()?.get_balance()?.into())
     }
-    /// Builds and sends a transaction to the blockchain.
+    /// Builds and sends a transaction paying each of the `recipients` (address, amount) to the blockchain.
+    ///
+    /// All recipients are paid in a single (batch) transaction, the change is sent back to the wallet.
+    /// The fee is determined by the `fee_policy`, a [FeePolicy::ConfirmationTarget] is resolved using the fee estimate of the `blockchain`.
+    /// Creates, signs, finalizes and broadcasts the transaction in one step, see [`create_psbt`][Self::create_psbt] for a workflow that separates these steps.
+    /// Returns the [TransactionDetails] of the broadcast transaction, which include its txid, fee, inputs and outputs.
     pub async fn transfer<B: Blockchain>(
         &self,
         blockchain: &B,
-        send_amount: u64,
-        to_public_address: &str,
-    ) -> Result<Txid, Error> {
-        let recipient_address = Address::from_str(to_public_address).unwrap();
+        recipients: &[(&str, BitcoinAmount)],
+        fee_policy: FeePolicy,
+    ) -> Result<TransactionDetails, Error> {
+        let fee_policy = fee_policy.resolve(blockchain)?;
+        let (psbt, tx_details) = self.create_psbt(recipients, fee_policy)?;
+        self.sign_and_broadcast(blockchain, psbt, tx_details).await
+    }
 
-        let wallet = self.wallet.as_ref().unwrap();
-        let mut tx_builder = wallet.build_tx();
+    /// Builds and sends a transaction paying a [BIP21 payment URI][PaymentUri], the fee is determined by the `fee_policy`.
+    ///
+    /// The URI of a chain with custom [network parameters][NetworkParams] is parsed with [`PaymentUri::parse_with_required`] and the [network parameters][Self::network_params] of the wallet.
+    /// Returns an [error][Error] if the URI does not specify an amount or if its address is not valid for the network of the wallet.
+    pub async fn transfer_to_uri<B: Blockchain>(
+        &self,
+        blockchain: &B,
+        payment_uri: &PaymentUri,
+        fee_policy: FeePolicy,
+    ) -> Result<TransactionDetails, Error> {
+        payment_uri.validate_network(self.network()?)?;
+        let amount = payment_uri.amount.ok_or_else(|| {
+            Error::MissingInfo("The payment URI does not specify an amount".to_string())
+        })?;
+        let address = self.network_params.address_to_string(&payment_uri.address);
+        self.transfer(blockchain, &[(address.as_str(), amount)], fee_policy)
+            .await
+    }
+
+    /// Builds and sends a transaction that sweeps all the funds of the wallet to `to_address`, the fee is subtracted from the swept amount.
+    ///
+    /// The fee is determined by the `fee_policy`, a [FeePolicy::ConfirmationTarget] is resolved using the fee estimate of the `blockchain`.
+    /// Returns the [TransactionDetails] of the broadcast transaction, which include its txid, fee, inputs and outputs.
+    pub async fn drain<B: Blockchain>(
+        &self,
+        blockchain: &B,
+        to_address: &str,
+        fee_policy: FeePolicy,
+    ) -> Result<TransactionDetails, Error> {
+        let fee_policy = fee_policy.resolve(blockchain)?;
+        let (psbt, tx_details) = self.create_drain_psbt(to_address, fee_policy)?;
+        self.sign_and_broadcast(blockchain, psbt, tx_details).await
+    }
+
+    /// Creates an unsigned [PSBT][Psbt] paying each of the `recipients` (address, amount), with the fee determined by the `fee_policy`.
+    ///
+    /// Replace-by-fee is enabled on the transaction.
+    /// A recipient can be a [silent payment address][SilentPaymentAddress] (`sp1...`), whose output is derived from the private keys of the selected inputs, so the wallet can not be watch-only.
+    /// Adding inputs to such a transaction invalidates the silent payment, speed it up with [`cpfp`][Self::cpfp] rather than [`bump_fee`][Self::bump_fee] if the change does not cover the higher fee.
+    /// A [FeePolicy::ConfirmationTarget] needs a blockchain backend, resolve it first with [`FeePolicy::resolve`], otherwise an [error][Error] is returned.
+    /// Returns the PSBT along with the details of the transaction (such as the fee), the PSBT can be exported with [`psbt::to_base64`][crate::psbt::to_base64] to be signed on another instance built from the same mnemonic.
+    pub fn create_psbt(
+        &self,
+        recipients: &[(&str, BitcoinAmount)],
+        fee_policy: FeePolicy,
+    ) -> Result<(Psbt, TransactionDetails), Error> {
+        if recipients.is_empty() {
+            return Err(Error::MissingInfo("No recipients specified".to_string()));
+        }
+        self.build_psbt(recipients, None, None, fee_policy)
+    }
+
+    /// Creates an unsigned [PSBT][Psbt] spending all the funds of the wallet to `to_address`, the fee determined by the `fee_policy` is subtracted from the swept amount.
+    ///
+    /// Works like [`create_psbt`][Self::create_psbt] otherwise.
+    pub fn create_drain_psbt(
+        &self,
+        to_address: &str,
+        fee_policy: FeePolicy,
+    ) -> Result<(Psbt, TransactionDetails), Error> {
+        self.build_psbt(&[], Some(to_address), None, fee_policy)
+    }
+
+    /// Builds and sends a transaction paying each of the `recipients` (address, amount) that spends only the specified `inputs`, the change is sent back to the wallet.
+    ///
+    /// Works like [`transfer`][Self::transfer] otherwise, see [`create_psbt_with_inputs`][Self::create_psbt_with_inputs] for the restrictions on the inputs.
+    pub async fn transfer_with_inputs<B: Blockchain>(
+        &self,
+        blockchain: &B,
+        inputs: &[OutPoint],
+        recipients: &[(&str, BitcoinAmount)],
+        fee_policy: FeePolicy,
+    ) -> Result<TransactionDetails, Error> {
+        let fee_policy = fee_policy.resolve(blockchain)?;
+        let (psbt, tx_details) = self.create_psbt_with_inputs(inputs, recipients, fee_policy)?;
+        self.sign_and_broadcast(blockchain, psbt, tx_details).await
+    }
+
+    /// Creates an unsigned [PSBT][Psbt] paying each of the `recipients` (address, amount) that spends only the specified `inputs`, the change is sent back to the wallet.
+    ///
+    /// All of the `inputs` are spent, so sending a small amount to one of the wallet's own addresses consolidates them into a single output.
+    /// Returns an [error][Error] if an input is frozen or is not an unspent output of the wallet.
+    /// Works like [`create_psbt`][Self::create_psbt] otherwise.
+    pub fn create_psbt_with_inputs(
+        &self,
+        inputs: &[OutPoint],
+        recipients: &[(&str, BitcoinAmount)],
+        fee_policy: FeePolicy,
+    ) -> Result<(Psbt, TransactionDetails), Error> {
+        if inputs.is_empty() {
+            return Err(Error::MissingInfo("No inputs specified".to_string()));
+        }
+        if recipients.is_empty() {
+            return Err(Error::MissingInfo("No recipients specified".to_string()));
+        }
+        if let Some(frozen) = inputs
+            .iter()
+            .find(|outpoint| self.frozen_outpoints.contains(outpoint))
+        {
+            return Err(Error::FrozenOutpoint(frozen.to_string()));
+        }
+        self.build_psbt(recipients, None, Some(inputs), fee_policy)
+    }
+
+    /// Returns the unspent outputs (UTXOs) of the wallet, including the frozen ones.
+    ///
+    /// The wallet needs to be [synced][Self::sync] for the list to be up to date.
+    pub fn list_unspent(&self) -> Result<Vec<Utxo>, Error> {
+        let wallet = self.bdk_wallet()?;
+        let mut utxos = Vec::new();
+        for local_utxo in wallet.list_unspent()? {
+            let confirmation_height = wallet
+                .get_tx(&local_utxo.outpoint.txid, false)?
+                .and_then(|tx_details| tx_details.confirmation_time)
+                .map(|block_time| block_time.height);
+            utxos.push(Utxo {
+                outpoint: local_utxo.outpoint,
+                value: local_utxo.txout.value,
+                keychain: local_utxo.keychain,
+                confirmation_height,
+                is_frozen: self.frozen_outpoints.contains(&local_utxo.outpoint),
+            });
+        }
+        Ok(utxos)
+    }
+
+    /// Returns the transactions of the wallet matching the `query`, newest first.
+    ///
+    /// The wallet needs to be [synced][Self::sync] for the list to be up to date.
+    pub fn transactions(&self, query: &TransactionQuery) -> Result<Vec<BitcoinTransaction>, Error> {
+        let wallet = self.bdk_wallet()?;
+        let network = wallet.network();
+        let mut transactions = Vec::new();
+        for tx_details in wallet.list_transactions(true)? {
+            let confirmation_time = tx_details.confirmation_time.as_ref();
+            let raw_transaction = tx_details
+                .transaction
+                .as_ref()
+                .ok_or(Error::TransactionInfoUnavailable)?;
+            let mut external_outputs = Vec::new();
+            for output in &raw_transaction.output {
+                if !wallet.is_mine(&output.script_pubkey)? {
+                    external_outputs.push(output);
+                }
+            }
+            let (direction, counterparties) = if tx_details.sent == 0 {
+                let senders = raw_transaction
+                    .input
+                    .iter()
+                    .filter_map(|input| history::input_address(input, network))
+                    .map(|address| self.network_params.address_to_string(&address))
+                    .collect();
+                (TransactionDirection::Received, senders)
+            } else if external_outputs.is_empty() {
+                (TransactionDirection::SelfTransfer, Vec::new())
+            } else {
+                let recipients = external_outputs
+                    .iter()
+                    .filter_map(|output| Address::from_script(&output.script_pubkey, network).ok())
+                    .map(|address| self.network_params.address_to_string(&address))
+                    .collect();
+                (TransactionDirection::Sent, recipients)
+            };
+            transactions.push(BitcoinTransaction {
+                txid: tx_details.txid,
+                direction,
+                sent: tx_details.sent,
+                received: tx_details.received,
+                fee: tx_details.fee,
+                confirmation_height: confirmation_time.map(|block_time| block_time.height),
+                confirmation_time: confirmation_time.map(|block_time| block_time.timestamp),
+                counterparties,
+            });
+        }
+        Ok(query.apply(transactions))
+    }
+
+    /// Freezes the outpoint so it is never selected as an input of a transaction created by the wallet, returns false if it was already frozen.
+    ///
+    /// With a persistent [database][BitcoinDatabaseConfig] the frozen outpoints are stored in a file next to it and restored when the wallet is built again.
+    pub fn freeze(&mut self, outpoint: OutPoint) -> Result<bool, Error> {
+        self.frozen_outpoints.insert(outpoint)
+    }
+
+    /// Unfreezes a previously [frozen][Self::freeze] outpoint, returns false if it was not frozen.
+    pub fn unfreeze(&mut self, outpoint: &OutPoint) -> Result<bool, Error> {
+        self.frozen_outpoints.remove(outpoint)
+    }
+
+    /// Returns the frozen outpoints of the wallet.
+    pub fn frozen_outpoints(&self) -> Vec<OutPoint> {
+        self.frozen_outpoints.outpoints()
+    }
+
+    /// Builds and sends a transaction replacing the unconfirmed transaction `txid` (replace-by-fee) with the fee rate increased to `new_fee_rate` (sat/vB).
+    ///
+    /// Returns the [TransactionDetails] of the replacement transaction, see [`create_bump_fee_psbt`][Self::create_bump_fee_psbt] for the requirements on the original transaction.
+    pub async fn bump_fee<B: Blockchain>(
+        &self,
+        blockchain: &B,
+        txid: &Txid,
+        new_fee_rate: f32,
+    ) -> Result<TransactionDetails, Error> {
+        let (psbt, tx_details) = self.create_bump_fee_psbt(txid, new_fee_rate)?;
+        self.sign_and_broadcast(blockchain, psbt, tx_details).await
+    }
+
+    /// Creates an unsigned [PSBT][Psbt] replacing the unconfirmed transaction `txid` (replace-by-fee) with the fee rate increased to `new_fee_rate` (sat/vB).
+    ///
+    /// The original transaction needs to be sent by this wallet, signal replaceability (as every transaction created by the wallet does) and be unconfirmed, otherwise an [error][Error] is returned.
+    /// The additional fee is taken from the change output, new inputs are added if the change is not enough.
+    pub fn create_bump_fee_psbt(
+        &self,
+        txid: &Txid,
+        new_fee_rate: f32,
+    ) -> Result<(Psbt, TransactionDetails), Error> {
+        let wallet = self.bdk_wallet()?;
+        let mut tx_builder = wallet.build_fee_bump(*txid)?;
         tx_builder
-            .add_recipient(recipient_address.script_pubkey(), send_amount)
+            .fee_rate(FeeRate::from_sat_per_vb(fee::check_fee_rate(new_fee_rate)?))
+            .unspendable(self.frozen_outpoints.outpoints())
             .enable_rbf();
-        let (mut psbt, tx_details) = tx_builder.finish().unwrap();
+        Ok(tx_builder.finish()?)
+    }
 
-        println!("Transaction details: {:#?}", tx_details);
+    /// Builds and sends a child transaction spending the outputs of the unconfirmed transaction `txid` that belong to this wallet (child-pays-for-parent), so that the parent and child together pay `target_fee_rate` (sat/vB).
+    ///
+    /// Returns the [TransactionDetails] of the child transaction, see [`create_cpfp_psbt`][Self::create_cpfp_psbt] for the requirements on the parent transaction.
+    pub async fn cpfp<B: Blockchain>(
+        &self,
+        blockchain: &B,
+        txid: &Txid,
+        target_fee_rate: f32,
+    ) -> Result<TransactionDetails, Error> {
+        let (psbt, tx_details) = self.create_cpfp_psbt(txid, target_fee_rate)?;
+        self.sign_and_broadcast(blockchain, psbt, tx_details).await
+    }
 
-        let finalized = wallet.sign(&mut psbt, SignOptions::default()).unwrap();
-        assert!(finalized, "Tx has not been finalized");
-        println!("Transaction Signed: {}", finalized);
+    /// Creates an unsigned [PSBT][Psbt] for a child transaction spending the outputs of the unconfirmed transaction `txid` that belong to this wallet (child-pays-for-parent), so that the parent and child together pay `target_fee_rate` (sat/vB).
+    ///
+    /// The outputs spent are the change outputs of the parent, or the outputs received by the wallet if the parent has no change output, frozen outputs are not spent.
+    /// The funds are sent to a new change address of the wallet.
+    /// The parent needs to be known to the wallet, unconfirmed and its fee needs to be known (all its inputs belong to the wallet), otherwise an [error][Error] is returned.
+    pub fn create_cpfp_psbt(
+        &self,
+        txid: &Txid,
+        target_fee_rate: f32,
+    ) -> Result<(Psbt, TransactionDetails), Error> {
+        fee::check_fee_rate(target_fee_rate)?;
+        let wallet = self.bdk_wallet()?;
+        let parent = wallet
+            .get_tx(txid, true)?
+            .ok_or(bdk::Error::TransactionNotFound)?;
+        if parent.confirmation_time.is_some() {
+            return Err(bdk::Error::TransactionConfirmed.into());
+        }
+        let parent_fee = parent.fee.ok_or(Error::TransactionInfoUnavailable)?;
+        let parent_vsize = parent
+            .transaction
+            .as_ref()
+            .ok_or(Error::TransactionInfoUnavailable)?
+            .vsize();
 
-        let raw_transaction = psbt.extract_tx();
-        let txid = raw_transaction.txid();
-        blockchain.broadcast(&raw_transaction).unwrap();
+        let parent_utxos: Vec<_> = wallet
+            .list_unspent()?
+            .into_iter()
+            .filter(|utxo| {
+                utxo.outpoint.txid == *txid && !self.frozen_outpoints.contains(&utxo.outpoint)
+            })
+            .collect();
+        let change_outpoints: Vec<OutPoint> = parent_utxos
+            .iter()
+            .filter(|utxo| utxo.keychain == KeychainKind::Internal)
+            .map(|utxo| utxo.outpoint)
+            .collect();
+        let inputs = if change_outpoints.is_empty() {
+            parent_utxos.iter().map(|utxo| utxo.outpoint).collect()
+        } else {
+            change_outpoints
+        };
+        if inputs.is_empty() {
+            return Err(Error::MissingData(format!(
+                "Transaction {} has no spendable output belonging to the wallet",
+                txid
+            )));
+        }
+
+        let drain_to = wallet.get_internal_address(AddressIndex::New)?;
+        let build_child = |absolute_fee: Option<u64>| -> Result<(Psbt, TransactionDetails), Error> {
+            let mut tx_builder = wallet.build_tx();
+            tx_builder
+                .add_utxos(&inputs)?
+                .manually_selected_only()
+                .drain_to(drain_to.script_pubkey())
+                .enable_rbf();
+            match absolute_fee {
+                Some(fee) => tx_builder.fee_absolute(fee),
+                None => tx_builder.fee_rate(FeeRate::from_sat_per_vb(target_fee_rate)),
+            };
+            Ok(tx_builder.finish()?)
+        };
+        // A child paying the target fee rate on its own gives the fee needed for its size,
+        // the child then also pays the part of the fee the parent is missing to reach the target fee rate
+        let (psbt, child_details) = build_child(None)?;
+        let parent_target_fee = (target_fee_rate * parent_vsize as f32).ceil() as u64;
+        if parent_target_fee <= parent_fee {
+            return Ok((psbt, child_details));
+        }
+        let child_fee = child_details.fee.ok_or(Error::TransactionInfoUnavailable)?;
+        build_child(Some(child_fee + parent_target_fee - parent_fee))
+    }
+
+    /// Adds the signatures of this wallet to the [PSBT][Psbt] without finalizing it.
+    ///
+    /// The wallet does not need to be synced to sign, so the PSBT can be signed by an offline (air-gapped) instance built from the same mnemonic.
+    /// Returns an [error][Error] if the wallet could not add any signature to the PSBT.
+    pub fn sign_psbt(&self, psbt: &mut Psbt) -> Result<(), Error> {
+        if self.is_watch_only() {
+            return Err(Error::WatchOnly);
+        }
+        let unsigned = psbt.clone();
+        let sign_options = SignOptions {
+            try_finalize: false,
+            ..Default::default()
+        };
+        self.bdk_wallet()?.sign(psbt, sign_options)?;
+        if *psbt == unsigned {
+            return Err(Error::Psbt(
+                "No inputs of the PSBT could be signed by this wallet".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Signs the message with the key of the `address` of the wallet, returns the base64 encoded signature.
+    ///
+    /// The signature uses the [default format][MessageSignatureFormat::default_for] of the address: BIP137 for P2PKH, P2SH-P2WPKH and P2WPKH addresses and BIP322 for P2TR addresses.
+    /// Returns an [error][Error] if the address was not generated by the wallet or if the wallet is watch-only.
+    pub fn sign_message(&self, address: &str, message: &str) -> Result<String, Error> {
+        let format = MessageSignatureFormat::default_for(&self.parse_address(address)?)?;
+        self.sign_message_with_format(address, message, format)
+    }
+
+    /// Signs the message with the key of the `address` of the wallet in the specified [format][MessageSignatureFormat], returns the base64 encoded signature.
+    ///
+    /// BIP322 simple signatures are supported for P2SH-P2WPKH, P2WPKH and P2TR addresses, [Error::CurrentlyNotSupported] is returned for P2PKH addresses.
+    /// Returns an [error][Error] if the address was not generated by the wallet or if the wallet is watch-only.
+    pub fn sign_message_with_format(
+        &self,
+        address: &str,
+        message: &str,
+        format: MessageSignatureFormat,
+    ) -> Result<String, Error> {
+        if self.is_watch_only() {
+            return Err(Error::WatchOnly);
+        }
+        let address = self.parse_address(address)?;
+        let secp = Secp256k1::new();
+        let secret_key = self.secret_key(&secp, &address)?;
+        message::sign(&secp, &secret_key, &address, message, format)
+    }
+
+    /// Verifies the base64 encoded `signature` of the message for the `address`, which does not need to belong to the wallet.
+    ///
+    /// Both BIP137 and BIP322 simple signatures are accepted, the format is detected from the signature.
+    /// Returns false if the signature was not made by the key of the address and an [error][Error] if the signature is malformed.
+    pub fn verify_message(
+        &self,
+        address: &str,
+        message: &str,
+        signature: &str,
+    ) -> Result<bool, Error> {
+        let address = self.parse_address(address)?;
+        message::verify(
+            &Secp256k1::verification_only(),
+            &address,
+            message,
+            signature,
+        )
+    }
+
+    /// Returns the [BIP352](https://github.com/bitcoin/bips/blob/master/bip-0352.mediawiki) silent payment address of the wallet, a static address that can be published without the payments to it being linked on-chain.
+    ///
+    /// The scan and spend keys of the address are derived at m/352'/coin_type'/account_index'/1'/0 and m/352'/coin_type'/account_index'/0'/0.
+    /// The payments to the address are not part of the bdk wallet, they are found with [`scan_silent_payments`][Self::scan_silent_payments].
+    /// Returns [Error::MissingMnemonicSeed] if the wallet was not built from a mnemonic or mnemonic seed.
+    pub fn silent_payment_address(&self) -> Result<SilentPaymentAddress, Error> {
+        Ok(self.silent_payment_keys()?.address(&Secp256k1::new()))
+    }
+
+    /// Returns the silent payment address with the `label`, which tells apart the payments to the wallet, for example per payer.
+    ///
+    /// The label 0 is reserved for the change, the labels need to be passed to the scan to find the payments to the labeled addresses.
+    pub fn silent_payment_address_with_label(
+        &self,
+        label: u32,
+    ) -> Result<SilentPaymentAddress, Error> {
+        self.silent_payment_keys()?
+            .labeled_address(&Secp256k1::new(), label)
+    }
+
+    /// Returns the silent payments to the wallet among the taproot `output_keys` of a transaction, given the [tweak data][silent_payment::tweak_data] of its inputs.
+    ///
+    /// The tweak data can be computed from the transaction or served by an indexer, the outputs are checked for the [labeled addresses][Self::silent_payment_address_with_label] with the `labels` and for the change label.
+    pub fn scan_silent_payments(
+        &self,
+        tweak_data: &PublicKey,
+        output_keys: &[XOnlyPublicKey],
+        labels: &[u32],
+    ) -> Result<Vec<SilentPayment>, Error> {
+        self.silent_payment_keys()?
+            .scan(&Secp256k1::new(), tweak_data, output_keys, labels)
+    }
+
+    /// Returns the silent payments to the wallet in the `transaction` along with their outpoints, the `prevouts` are the script pubkeys of the outputs spent by its inputs.
+    ///
+    /// Works like [`scan_silent_payments`][Self::scan_silent_payments] with the tweak data computed from the inputs of the transaction.
+    pub fn scan_silent_payment_transaction(
+        &self,
+        transaction: &Transaction,
+        prevouts: &[Script],
+        labels: &[u32],
+    ) -> Result<Vec<(OutPoint, SilentPayment)>, Error> {
+        let Some(tweak_data) = silent_payment::tweak_data(transaction, prevouts)? else {
+            return Ok(Vec::new());
+        };
+        let outputs: Vec<(u32, XOnlyPublicKey)> = (0u32..)
+            .zip(&transaction.output)
+            .filter(|(_, output)| output.script_pubkey.is_v1_p2tr())
+            .filter_map(|(vout, output)| {
+                let output_key =
+                    XOnlyPublicKey::from_slice(&output.script_pubkey.as_bytes()[2..]).ok()?;
+                Some((vout, output_key))
+            })
+            .collect();
+        let output_keys: Vec<XOnlyPublicKey> =
+            outputs.iter().map(|(_, output_key)| *output_key).collect();
+        let txid = transaction.txid();
+        Ok(self
+            .scan_silent_payments(&tweak_data, &output_keys, labels)?
+            .into_iter()
+            .filter_map(|payment| {
+                let (vout, _) = outputs
+                    .iter()
+                    .find(|(_, output_key)| *output_key == payment.output_key)?;
+                Some((OutPoint::new(txid, *vout), payment))
+            })
+            .collect())
+    }
+
+    /// Finalizes the inputs of a signed [PSBT][Psbt], returns true if all the inputs were finalized and the transaction is ready to be broadcast.
+    pub fn finalize_psbt(&self, psbt: &mut Psbt) -> Result<bool, Error> {
+        Ok(self
+            .bdk_wallet()?
+            .finalize_psbt(psbt, SignOptions::default())?)
+    }
 
+    /// Extracts the transaction from a finalized [PSBT][Psbt] and broadcasts it to the blockchain, returns the [Txid] of the transaction.
+    ///
+    /// Returns an [error][Error] if the PSBT is not finalized.
+    pub async fn broadcast<B: Blockchain>(
+        &self,
+        blockchain: &B,
+        psbt: &Psbt,
+    ) -> Result<Txid, Error> {
+        if !psbt::is_finalized(psbt) {
+            return Err(Error::Psbt("The PSBT is not finalized".to_string()));
+        }
+        let raw_transaction = psbt.clone().extract_tx();
+        let txid = raw_transaction.txid();
+        blockchain
+            .broadcast(&raw_transaction)
+            .map_err(|e| Error::BroadcastTransaction(e.to_string()))?;
         Ok(txid)
     }
 
+    /// Returns the fee rate estimates (sat/vB) of the `blockchain` backend for the [default confirmation targets][DEFAULT_CONFIRMATION_TARGETS], keyed by the target number of blocks.
+    ///
+    /// Returns [Error::MissingFeeMap] if the backend does not provide any estimate.
+    pub async fn estimate_fee<B: Blockchain>(&self, blockchain: &B) -> Result<FeeMap, Error> {
+        fee::estimate_fee_map(blockchain, &DEFAULT_CONFIRMATION_TARGETS)
+    }
+
+    /// Connects to the blockchain backend specified by the `config` for the network of the wallet
+    ///
+    /// The returned blockchain can be passed to every method of the wallet taking a blockchain, such as [`sync`][Self::sync] and [`transfer`][Self::transfer].
+    pub fn connect(&self, config: &BitcoinBlockchainConfig) -> Result<AnyBlockchain, Error> {
+        config.connect(self.network()?)
+    }
+
     /// Syncs the wallet with the blockchain by adding previously used addresses to the wallet.
+    ///
+    /// Returns [Error::Sync] if the blockchain backend fails.
     pub async fn sync<B: WalletSync + GetHeight>(&mut self, blockchain: &B) -> Result<(), Error> {
-        let _ = self
-            .wallet
-            .as_mut()
-            .unwrap()
-            .sync(blockchain, SyncOptions::default());
-        Ok(())
+        self.bdk_wallet()?
+            .sync(blockchain, SyncOptions::default())
+            .map_err(|e| Error::Sync(e.to_string()))
     }
     /// Retrieves the next recevie address of the wallet.
     pub fn receive_address(&self) -> Result<String, Error> {
         let next_receive_address = self.next_address()?;
-        Ok(next_receive_address.to_string())
+        Ok(self
+            .network_params
+            .address_to_string(&next_receive_address.address))
     }
 
-    /// Returns the coin type id num based on the [Bitcoin network][Network].
-    /// Returns an [error][Error] if the network is not supported.
+    /// Returns the coin type id num based on the [network parameters][NetworkParams], 0 for [Network::Bitcoin] and 1 for the test networks unless the parameters specify another one.
+    /// Returns an [error][Error] if the wallet has no network.
     pub fn coin_type_id(&self) -> Result<u32, Error> {
-        match self.network()? {
-            Network::Bitcoin => Ok(slip44::Coin::Bitcoin.id()),
-            Network::Testnet | Network::Regtest => Ok(slip44::Coin::Testnet.id()),
-            other => Err(Error::CurrentlyNotSupported(format!(
-                "Network {} currently not supported",
-                other
-            ))),
-        }
+        self.network()?;
+        Ok(self.network_params.coin_type)
     }
 
     /// Returns the [default HDPurpose][HDPurpose] based on the [address format][AddressType]
@@ -97,17 +597,10 @@ impl BitcoinWallet {
     /// If the address format is [AddressType::P2pkh] the default purpose is [HDPurpose::BIP44]
     /// If the address format is [AddressType::P2sh] the default purpose is [HDPurpose::BIP49]
     /// If the address format is [AddressType::P2wpkh] the default purpose is [HDPurpose::BIP84]
+    /// If the address format is [AddressType::P2tr] the default purpose is [HDPurpose::BIP86]
     /// Other address formats are currently not supported and will return an [error][Error]
     pub fn default_hd_purpose(&self) -> Result<HDPurpose, Error> {
-        match self.address_format() {
-            AddressType::P2pkh => Ok(HDPurpose::BIP44),
-            AddressType::P2sh => Ok(HDPurpose::BIP49),
-            AddressType::P2wpkh => Ok(HDPurpose::BIP84),
-            other => Err(Error::CurrentlyNotSupported(format!(
-                "Address format {} currently not supported",
-                other
-            ))),
-        }
+        default_hd_purpose(self.address_format())
     }
 
     /// Returns the address format
@@ -115,6 +608,25 @@ impl BitcoinWallet {
         self.address_format
     }
 
+    /// Returns true if the wallet holds no private keys, a watch-only wallet can be synced, generate addresses and create PSBTs but refuses to sign
+    pub fn is_watch_only(&self) -> bool {
+        self.wallet.as_ref().is_some_and(|wallet| {
+            [KeychainKind::External, KeychainKind::Internal]
+                .iter()
+                .all(|keychain| wallet.get_signers(*keychain).signers().is_empty())
+        })
+    }
+
+    /// Returns the HD purpose used for the derivation path of the wallet
+    pub fn hd_purpose(&self) -> HDPurpose {
+        self.hd_purpose
+    }
+
+    /// Returns the parameters of the network of the wallet, which encode its addresses
+    pub fn network_params(&self) -> &NetworkParams {
+        &self.network_params
+    }
+
     /// Returns the network based on the master HDKey
     pub fn network(&self) -> Result<Network, Error> {
         match &self.wallet {
@@ -127,19 +639,297 @@ impl BitcoinWallet {
     ///
     /// Returns an [error][Error] with details if it encounters a problem while deriving the next address
     pub fn next_address(&self) -> Result<AddressInfo, Error> {
-        let address = self
-            .wallet
-            .as_ref()
-            .unwrap()
-            .get_address(AddressIndex::New)
-            .unwrap();
-        Ok(address)
+        Ok(self.bdk_wallet()?.get_address(AddressIndex::New)?)
     }
 
     /// Returns the Builder for [BitcoinWallet]
     pub fn builder() -> BitcoinWalletBuilder {
         BitcoinWalletBuilder::new()
     }
+
+    /// Builds an unsigned PSBT paying the `recipients` and, if specified, sweeping all the funds of the wallet to `drain_to`
+    ///
+    /// If `inputs` are specified only those are spent, otherwise the inputs are selected from the outputs of the wallet that are not frozen
+    fn build_psbt(
+        &self,
+        recipients: &[(&str, BitcoinAmount)],
+        drain_to: Option<&str>,
+        inputs: Option<&[OutPoint]>,
+        fee_policy: FeePolicy,
+    ) -> Result<(Psbt, TransactionDetails), Error> {
+        let wallet = self.bdk_wallet()?;
+        let mut tx_builder = wallet.build_tx();
+        match inputs {
+            Some(inputs) => {
+                tx_builder.add_utxos(inputs)?.manually_selected_only();
+            }
+            None => {
+                tx_builder.unspendable(self.frozen_outpoints.outpoints());
+            }
+        }
+        // the outputs of silent payments are only known once the inputs are selected
+        let mut silent_payments = Vec::new();
+        let mut script_pubkey = |address: &str| -> Result<Script, Error> {
+            if !silent_payment::is_silent_payment_address(address) {
+                return Ok(self.parse_address(address)?.script_pubkey());
+            }
+            if self.is_watch_only() {
+                return Err(Error::WatchOnly);
+            }
+            let address = self.parse_silent_payment_address(address)?;
+            let placeholder = silent_payment::placeholder_script(silent_payments.len());
+            silent_payments.push((placeholder.clone(), address));
+            Ok(placeholder)
+        };
+        for (address, amount) in recipients {
+            tx_builder.add_recipient(script_pubkey(address)?, amount.satoshi);
+        }
+        if let Some(drain_to) = drain_to {
+            tx_builder.drain_wallet().drain_to(script_pubkey(drain_to)?);
+        }
+        match fee_policy {
+            FeePolicy::Default => {}
+            FeePolicy::FeeRate(sat_per_vb) => {
+                tx_builder.fee_rate(FeeRate::from_sat_per_vb(fee::check_fee_rate(sat_per_vb)?));
+            }
+            FeePolicy::AbsoluteFee(fee) => {
+                tx_builder.fee_absolute(fee.satoshi);
+            }
+            FeePolicy::ConfirmationTarget(_) => return Err(Error::MissingInfo(
+                "A confirmation target fee policy needs to be resolved with a blockchain backend"
+                    .to_string(),
+            )),
+        }
+        tx_builder.enable_rbf();
+        let (mut psbt, mut tx_details) = tx_builder.finish()?;
+        if !silent_payments.is_empty() {
+            self.set_silent_payment_outputs(&mut psbt, &silent_payments)?;
+            tx_details.txid = psbt.unsigned_tx.txid();
+            tx_details.transaction = Some(psbt.unsigned_tx.clone());
+        }
+        Ok((psbt, tx_details))
+    }
+
+    /// Replaces the placeholder outputs of the silent payment `recipients` with their taproot outputs, derived from the private keys of the inputs of the PSBT
+    fn set_silent_payment_outputs(
+        &self,
+        psbt: &mut Psbt,
+        recipients: &[(Script, SilentPaymentAddress)],
+    ) -> Result<(), Error> {
+        let secp = Secp256k1::new();
+        let wallet = self.bdk_wallet()?;
+        let mut input_keys = Vec::new();
+        for input in &psbt.unsigned_tx.input {
+            let utxo = wallet.get_utxo(input.previous_output)?.ok_or_else(|| {
+                Error::MissingData(format!(
+                    "Input {} does not belong to the wallet",
+                    input.previous_output
+                ))
+            })?;
+            let address = Address::from_script(&utxo.txout.script_pubkey, wallet.network())
+                .map_err(|e| Error::ScriptInvalid(e.to_string()))?;
+            let secret_key = self.secret_key(&secp, &address)?;
+            // a taproot input contributes the private key of its tweaked output key
+            input_keys.push(match address.address_type() {
+                Some(AddressType::P2tr) => {
+                    let keypair = KeyPair::from_secret_key(&secp, &secret_key)
+                        .tap_tweak(&secp, None)
+                        .to_inner();
+                    (SecretKey::from_keypair(&keypair), true)
+                }
+                _ => (secret_key, false),
+            });
+        }
+        let outpoints: Vec<OutPoint> = psbt
+            .unsigned_tx
+            .input
+            .iter()
+            .map(|input| input.previous_output)
+            .collect();
+        let addresses: Vec<SilentPaymentAddress> =
+            recipients.iter().map(|(_, address)| *address).collect();
+        let output_keys = silent_payment::output_keys(&secp, &input_keys, &outpoints, &addresses)?;
+        for ((placeholder, _), output_key) in recipients.iter().zip(output_keys) {
+            let output = psbt
+                .unsigned_tx
+                .output
+                .iter_mut()
+                .find(|output| output.script_pubkey == *placeholder)
+                .ok_or_else(|| Error::MissingData("Silent payment output not found".to_string()))?;
+            output.script_pubkey =
+                Script::new_v1_p2tr_tweaked(TweakedPublicKey::dangerous_assume_tweaked(output_key));
+        }
+        Ok(())
+    }
+
+    /// Signs, finalizes and broadcasts the PSBT, returns the transaction details updated with the signed transaction
+    async fn sign_and_broadcast<B: Blockchain>(
+        &self,
+        blockchain: &B,
+        mut psbt: Psbt,
+        mut tx_details: TransactionDetails,
+    ) -> Result<TransactionDetails, Error> {
+        self.sign_psbt(&mut psbt)?;
+        if !self.finalize_psbt(&mut psbt)? {
+            return Err(Error::Psbt(
+                "Transaction could not be finalized".to_string(),
+            ));
+        }
+        tx_details.txid = self.broadcast(blockchain, &psbt).await?;
+        tx_details.transaction = Some(psbt.extract_tx());
+        Ok(tx_details)
+    }
+
+    /// Returns the underlying bdk wallet
+    fn bdk_wallet(&self) -> Result<&Wallet<AnyDatabase>, Error> {
+        self.wallet
+            .as_ref()
+            .ok_or_else(|| Error::MissingInfo("The wallet has not been built".to_string()))
+    }
+
+    /// Returns the private key of an address generated by the wallet
+    fn secret_key<C: Signing + Verification>(
+        &self,
+        secp: &Secp256k1<C>,
+        address: &Address,
+    ) -> Result<SecretKey, Error> {
+        let wallet = self.bdk_wallet()?;
+        let (keychain, index) = wallet
+            .database()
+            .get_path_from_script_pubkey(&address.script_pubkey())?
+            .ok_or_else(|| {
+                Error::MissingData(format!("Address {} does not belong to the wallet", address))
+            })?;
+        for signer in wallet.get_signers(keychain).signers() {
+            let secret_key = match signer.descriptor_secret_key() {
+                Some(DescriptorSecretKey::XPrv(xkey)) => {
+                    let derivation_path = match xkey.wildcard {
+                        Wildcard::None => xkey.derivation_path.clone(),
+                        _ => xkey.derivation_path.child(
+                            ChildNumber::from_normal_idx(index)
+                                .map_err(|e| Error::MessageSignature(e.to_string()))?,
+                        ),
+                    };
+                    xkey.xkey
+                        .derive_priv(secp, &derivation_path)
+                        .map_err(|e| Error::MessageSignature(e.to_string()))?
+                        .private_key
+                }
+                Some(DescriptorSecretKey::Single(single)) => single.key.inner,
+                _ => continue,
+            };
+            if message::is_key_of(secp, &secret_key, address) {
+                return Ok(secret_key);
+            }
+        }
+        Err(Error::MissingData(format!(
+            "No private key found for address {}",
+            address
+        )))
+    }
+
+    /// Parses an address and checks that it is valid for the network of the wallet
+    fn parse_address(&self, address: &str) -> Result<Address, Error> {
+        self.network()?;
+        self.network_params.parse_address(address)
+    }
+
+    /// Parses a silent payment address and checks that it is valid for the network of the wallet
+    fn parse_silent_payment_address(&self, address: &str) -> Result<SilentPaymentAddress, Error> {
+        let network = self.network()?;
+        let address = SilentPaymentAddress::from_str(address)?;
+        if !address.is_valid_for_network(network) {
+            return Err(Error::WrongNetwork {
+                address: address.to_string(),
+                network,
+            });
+        }
+        Ok(address)
+    }
+
+    /// Returns the silent payment keys of the wallet
+    fn silent_payment_keys(&self) -> Result<&SilentPaymentKeys, Error> {
+        self.silent_payment_keys
+            .as_ref()
+            .ok_or(Error::MissingMnemonicSeed)
+    }
+
+    /// Opens a wallet previously persisted to the sqlite database file at `path`, restoring its sync state, address indices and transaction history.
+    ///
+    /// The database only stores public wallet data, so the keys are derived using the mnemonic and options specified on the `builder`, which need to match the ones the wallet was created with.
+    /// Any database specified on the `builder` is replaced by the sqlite database at `path`.
+    ///
+    /// Returns an [error][Error] if no database exists at `path` or if the database belongs to a different wallet.
+    pub fn open<P: AsRef<Path>>(path: P, builder: &BitcoinWalletBuilder) -> Result<Self, Error> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(Error::MissingData(format!(
+                "No wallet database found at {}",
+                path.display()
+            )));
+        }
+        let mut builder = builder.clone();
+        builder.database(BitcoinDatabaseConfig::Sqlite(path.to_path_buf()));
+        builder.build()
+    }
+}
+
+#[async_trait(?Send)]
+impl CryptoWallet for BitcoinWallet {
+    type ErrorType = Error;
+    type CryptoAmount = BitcoinAmount;
+    type AddressFormat = AddressType;
+    type NetworkType = Network;
+    type BlockchainClient = AnyBlockchain;
+    type WalletBuilder = BitcoinWalletBuilder;
+
+    fn builder() -> Self::WalletBuilder {
+        BitcoinWalletBuilder::new()
+    }
+
+    /// Returns the spendable balance (confirmed and trusted pending)
+    async fn balance(
+        &self,
+        _blockchain_client: &Self::BlockchainClient,
+    ) -> Result<Self::CryptoAmount, Self::ErrorType> {
+        let balance = BitcoinWallet::balance(self).await?;
+        Ok(balance.spendable())
+    }
+
+    fn receive_address(&self) -> Result<String, Self::ErrorType> {
+        BitcoinWallet::receive_address(self)
+    }
+
+    async fn transfer(
+        &self,
+        blockchain_client: &Self::BlockchainClient,
+        send_amount: Self::CryptoAmount,
+        to_address: &str,
+    ) -> Result<String, Self::ErrorType> {
+        let tx_details = BitcoinWallet::transfer(
+            self,
+            blockchain_client,
+            &[(to_address, send_amount)],
+            FeePolicy::Default,
+        )
+        .await?;
+        Ok(tx_details.txid.to_string())
+    }
+
+    async fn sync(
+        &mut self,
+        blockchain_client: &Self::BlockchainClient,
+    ) -> Result<(), Self::ErrorType> {
+        BitcoinWallet::sync(self, blockchain_client).await
+    }
+
+    fn network(&self) -> Result<Self::NetworkType, Self::ErrorType> {
+        BitcoinWallet::network(self)
+    }
+
+    fn address_format(&self) -> Self::AddressFormat {
+        BitcoinWallet::address_format(self)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -147,21 +937,39 @@ impl BitcoinWallet {
 pub struct BitcoinWalletBuilder {
     /// The address format used to generate the wallet, if the address format is not provided, the default address format is P2wpkh
     address_format: AddressType,
-    /// The HD purpose used to generate the wallet
+    /// The HD purpose used to generate the wallet, if the HD purpose is not provided, the default purpose for the address format is used
     hd_purpose: Option<HDPurpose>,
-    /// The mnemonic seed used to import the wallet
+    /// The mnemonic used to import the wallet
     mnemonic: Option<Mnemonic>,
-    /// The default network type is Network::Bitcoin
-    network_type: Network,
+    /// The mnemonic seed used to import the wallet, takes precedence over the mnemonic if both are specified
+    mnemonic_seed: Option<Seed>,
+    /// The account-level extended public key used to import a watch-only wallet
+    extended_public_key: Option<String>,
+    /// The external and internal output descriptors used to import the wallet, take precedence over the extended public key
+    descriptors: Option<(String, Option<String>)>,
+    /// The index of the account in the derivation path, the default is the first account (0)
+    account_index: u32,
+    /// The number of consecutive unused addresses after which the scan of an account stops during account discovery
+    gap_limit: u32,
+    /// The parameters of the network, the default network is Network::Bitcoin
+    network_params: NetworkParams,
+    /// The database used to store the wallet data, the default is an in-memory database
+    database: BitcoinDatabaseConfig,
 }
 
 impl Default for BitcoinWalletBuilder {
     fn default() -> Self {
         Self {
             address_format: AddressType::P2wpkh,
-            hd_purpose: Some(HDPurpose::BIP84),
+            hd_purpose: None,
             mnemonic: None,
-            network_type: Network::Bitcoin,
+            mnemonic_seed: None,
+            extended_public_key: None,
+            descriptors: None,
+            account_index: 0,
+            gap_limit: DEFAULT_GAP_LIMIT,
+            network_params: NetworkParams::default(),
+            database: BitcoinDatabaseConfig::Memory,
         }
     }
 }
@@ -178,41 +986,169 @@ impl BitcoinWalletBuilder {
         self
     }
 
+    /// Allows specification of the mnemonic seed for the wallet, the seed takes precedence over a mnemonic if both are specified
+    pub fn mnemonic_seed(&mut self, mnemonic_seed: Seed) -> &mut Self {
+        self.mnemonic_seed = Some(mnemonic_seed);
+        self
+    }
+
+    /// Allows specification of an account-level extended public key to build a watch-only wallet, which can not sign transactions
+    ///
+    /// Accepts xpub, ypub and zpub keys (tpub, upub and vpub on the test networks), ypub keys use the [AddressType::P2sh] (P2SH-P2WPKH) and zpub keys the [AddressType::P2wpkh] address format, xpub keys use the address format of the builder.
+    /// The key can be preceded by its origin such as `[73c5da0a/84'/0'/0']xpub...`, which lets a wallet built from the mnemonic sign the PSBTs created by the watch-only wallet.
+    /// A mnemonic or mnemonic seed takes precedence over the extended public key if both are specified.
+    pub fn extended_public_key(&mut self, extended_public_key: &str) -> &mut Self {
+        self.extended_public_key = Some(extended_public_key.to_string());
+        self
+    }
+
+    /// Allows specification of the external and (optionally) internal output descriptors of the wallet, the address format is determined by the descriptors
+    ///
+    /// The wallet is watch-only if the descriptors only contain public keys.
+    /// The descriptors take precedence over an extended public key, a mnemonic or mnemonic seed takes precedence over the descriptors.
+    pub fn descriptors(&mut self, external: &str, internal: Option<&str>) -> &mut Self {
+        self.descriptors = Some((external.to_string(), internal.map(str::to_string)));
+        self
+    }
+
     /// Allows specification of the address format to use for the wallet
+    ///
+    /// Supported address formats are [AddressType::P2pkh], [AddressType::P2sh] (P2SH-P2WPKH), [AddressType::P2wpkh] and [AddressType::P2tr]
     pub fn address_format(&mut self, address_format: AddressType) -> &mut Self {
         self.address_format = address_format;
         self
     }
 
+    /// Allows specification of the HD purpose used in the derivation path of the wallet, the default is the purpose associated with the address format
+    pub fn hd_purpose(&mut self, hd_purpose: HDPurpose) -> &mut Self {
+        self.hd_purpose = Some(hd_purpose);
+        self
+    }
+
+    /// Allows specification of the index of the account derived from the mnemonic, the default is the first account (0)
+    ///
+    /// The derivation path of the wallet is m/purpose'/coin_type'/account_index'.
+    pub fn account_index(&mut self, account_index: u32) -> &mut Self {
+        self.account_index = account_index;
+        self
+    }
+
+    /// Allows specification of the gap limit used by [`discover_accounts`][Self::discover_accounts], the default is [DEFAULT_GAP_LIMIT]
+    pub fn gap_limit(&mut self, gap_limit: u32) -> &mut Self {
+        self.gap_limit = gap_limit;
+        self
+    }
+
     /// Allows specification of the network type for the wallet, the default is Network::Bitcoin
+    ///
+    /// Uses the standard [parameters][NetworkParams] of the network, replacing the ones specified with [`network_params`][Self::network_params].
     pub fn network_type(&mut self, network_type: Network) -> &mut Self {
-        self.network_type = network_type;
+        self.network_params = network_type.into();
+        self
+    }
+
+    /// Allows specification of the parameters of a private network, such as a regtest or signet chain with its own address prefixes or coin type
+    ///
+    /// The wallet encodes and parses its addresses with the parameters and follows the network they are based on otherwise, replacing the network specified with [`network_type`][Self::network_type].
+    pub fn network_params(&mut self, network_params: NetworkParams) -> &mut Self {
+        self.network_params = network_params;
+        self
+    }
+
+    /// Allows specification of the database used to store the wallet data, the default is [BitcoinDatabaseConfig::Memory]
+    ///
+    /// Use a persistent database to keep the sync state, address indices and transaction history across runs
+    pub fn database(&mut self, database: BitcoinDatabaseConfig) -> &mut Self {
+        self.database = database;
         self
     }
 
-    /// Used to import an existing wallet from a mnemonic seed and specified network type
+    /// Used to import an existing wallet from a mnemonic seed, output descriptors or an extended public key and specified network type
     pub fn build(&self) -> Result<BitcoinWallet, Error> {
-        if self.mnemonic.is_none() {
-            return Err(Error::MissingMnemonicSeed);
-        }
-        let mnemonic_words = self.mnemonic.clone();
-        let mnemonic = Mnemonic::parse(mnemonic_words.unwrap().to_string()).unwrap();
-
-        // Generate the extended key
-        let xkey: ExtendedKey = mnemonic.into_extended_key().unwrap();
-        // Get xprv from the extended key
-        let xprv = xkey.into_xprv(self.network_type).unwrap();
-        let wallet: Wallet<MemoryDatabase> = Wallet::new(
-            Bip84(xprv, KeychainKind::External),
-            Some(Bip84(xprv, KeychainKind::Internal)),
-            Network::Testnet,
-            MemoryDatabase::new(),
-        )
-        .unwrap();
+        let xprv = master_xprv(
+            self.mnemonic_seed.as_ref(),
+            self.mnemonic.as_ref(),
+            self.network_params.network,
+        )?;
+        let (external, internal, address_format, hd_purpose) = match xprv {
+            Some(xprv) => {
+                let hd_purpose = match self.hd_purpose {
+                    Some(hd_purpose) => hd_purpose,
+                    None => self.default_hd_purpose()?,
+                };
+                let (external, internal) = self.xprv_descriptors(&xprv, hd_purpose)?;
+                (
+                    external,
+                    Some(internal),
+                    Some(self.address_format),
+                    Some(hd_purpose),
+                )
+            }
+            None => match (&self.descriptors, &self.extended_public_key) {
+                (Some((external, internal)), _) => (external.clone(), internal.clone(), None, None),
+                (None, Some(extended_public_key)) => {
+                    let account =
+                        AccountXpub::parse(extended_public_key, self.network_params.network)?;
+                    if account.script_kind.is_multisig() {
+                        return Err(Error::UnableToImportWallet(format!(
+                            "Extended public key {} is a multisig key, use a MultisigWallet",
+                            extended_public_key
+                        )));
+                    }
+                    let address_format = account
+                        .script_kind
+                        .address_format()
+                        .unwrap_or(self.address_format);
+                    let external = descriptor(address_format, &account.descriptor_key(0))?;
+                    let internal = descriptor(address_format, &account.descriptor_key(1))?;
+                    (external, Some(internal), Some(address_format), None)
+                }
+                (None, None) => return Err(Error::MissingMnemonicSeed),
+            },
+        };
+        let database = AnyDatabase::from_config(&self.database.to_any_config())?;
+        let wallet: Wallet<AnyDatabase> = Wallet::new(
+            external.as_str(),
+            internal.as_deref(),
+            self.network_params.network,
+            database,
+        )?;
+
+        // the address format of descriptors is given by their first address
+        let address_format = match address_format {
+            Some(address_format) => address_format,
+            None => wallet
+                .get_address(AddressIndex::Peek(0))?
+                .address_type()
+                .ok_or_else(|| {
+                    Error::CurrentlyNotSupported(format!(
+                        "Address format of descriptor {} currently not supported",
+                        external
+                    ))
+                })?,
+        };
+        let hd_purpose = hd_purpose
+            .or(self.hd_purpose)
+            .unwrap_or_else(|| default_hd_purpose(address_format).unwrap_or_default());
+
+        let frozen_outpoints = FrozenOutpoints::load(self.database.frozen_outpoints_path())?;
+        let silent_payment_keys = match xprv {
+            Some(xprv) => Some(SilentPaymentKeys::derive(
+                &Secp256k1::new(),
+                &xprv,
+                self.network_params.coin_type,
+                self.account_index,
+            )?),
+            None => None,
+        };
 
         let wall = BitcoinWallet {
             wallet: Some(wallet),
-            address_format: self.address_format,
+            address_format,
+            hd_purpose,
+            frozen_outpoints,
+            silent_payment_keys,
+            network_params: self.network_params.clone(),
         };
 
         Ok(wall)
@@ -221,26 +1157,167 @@ impl BitcoinWalletBuilder {
     /// Returns the default HDPurpose based on the address format
     /// Returns an error[Error] if the address format is not currently supported
     pub fn default_hd_purpose(&self) -> Result<HDPurpose, Error> {
-        match self.address_format {
-            AddressType::P2pkh => Ok(HDPurpose::BIP44),
-            AddressType::P2sh => Ok(HDPurpose::BIP49),
-            AddressType::P2wpkh => Ok(HDPurpose::BIP84),
-            other => Err(Error::CurrentlyNotSupported(format!(
-                "Address format {} currently not supported",
-                other
-            ))),
+        default_hd_purpose(self.address_format)
+    }
+
+    /// Discovers the accounts derived from the mnemonic that have a transaction history, which restores wallets created by other software.
+    ///
+    /// Following the [BIP44 account discovery](https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki#account-discovery), the accounts of every supported address format and its HD purpose (BIP44, BIP49, BIP84 and BIP86) are scanned in order from the first account.
+    /// An account is used if one of its first [gap limit][Self::gap_limit] receive addresses has a transaction, and the scan of an HD purpose stops at its first unused account.
+    /// The `blockchain` backend also stops scanning after its own stop gap, which needs to be at least the gap limit (the stop gap of [BitcoinBlockchainConfig::electrum] and [BitcoinBlockchainConfig::esplora] is [DEFAULT_GAP_LIMIT]).
+    ///
+    /// Returns [Error::MissingMnemonicSeed] if neither a mnemonic nor a mnemonic seed is specified.
+    pub async fn discover_accounts<B: WalletSync>(
+        &self,
+        blockchain: &B,
+    ) -> Result<Vec<DiscoveredAccount>, Error> {
+        let xprv = master_xprv(
+            self.mnemonic_seed.as_ref(),
+            self.mnemonic.as_ref(),
+            self.network_params.network,
+        )?
+        .ok_or(Error::MissingMnemonicSeed)?;
+        let mut accounts = Vec::new();
+        for address_format in [
+            AddressType::P2pkh,
+            AddressType::P2sh,
+            AddressType::P2wpkh,
+            AddressType::P2tr,
+        ] {
+            let hd_purpose = default_hd_purpose(address_format)?;
+            for account_index in 0..discovery::ACCOUNT_INDEX_LIMIT {
+                let mut builder = self.clone();
+                builder
+                    .address_format(address_format)
+                    .account_index(account_index);
+                let (external, _) = builder.xprv_descriptors(&xprv, hd_purpose)?;
+                let transaction_count =
+                    discovery::transaction_count(blockchain, &external, self.gap_limit)?;
+                if transaction_count == 0 {
+                    break;
+                }
+                accounts.push(DiscoveredAccount {
+                    hd_purpose,
+                    address_format,
+                    account_index,
+                    transaction_count,
+                });
+            }
         }
+        Ok(accounts)
+    }
+
+    /// Returns the external and internal (change) descriptors for the account of the wallet
+    ///
+    /// The derivation path is m/purpose'/coin_type'/account_index'/change/*, where the coin type is given by the network parameters
+    fn xprv_descriptors(
+        &self,
+        xprv: &ExtendedPrivKey,
+        hd_purpose: HDPurpose,
+    ) -> Result<(String, String), Error> {
+        let key = |change: u32| {
+            format!(
+                "{}/{}/{}'/{}'/{}/*",
+                xprv, hd_purpose, self.network_params.coin_type, self.account_index, change
+            )
+        };
+        Ok((
+            descriptor(self.address_format, &key(0))?,
+            descriptor(self.address_format, &key(1))?,
+        ))
+    }
+}
+
+/// Returns the master private key derived from the mnemonic seed or the mnemonic, the seed takes precedence, None if neither is specified
+pub(crate) fn master_xprv(
+    mnemonic_seed: Option<&Seed>,
+    mnemonic: Option<&Mnemonic>,
+    network: Network,
+) -> Result<Option<ExtendedPrivKey>, Error> {
+    match (mnemonic_seed, mnemonic) {
+        (Some(seed), _) => Ok(Some(
+            ExtendedPrivKey::new_master(network, seed.as_bytes())
+                .map_err(|e| Error::UnableToImportWallet(e.to_string()))?,
+        )),
+        (None, Some(mnemonic)) => {
+            // Generate the extended key
+            let xkey: ExtendedKey = mnemonic
+                .clone()
+                .into_extended_key()
+                .map_err(|e| Error::UnableToImportWallet(e.to_string()))?;
+            // Get xprv from the extended key
+            let xprv = xkey.into_xprv(network).ok_or_else(|| {
+                Error::UnableToImportWallet("The mnemonic has no private key".to_string())
+            })?;
+            Ok(Some(xprv))
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Returns the single key descriptor for the address format
+fn descriptor(address_format: AddressType, key: &str) -> Result<String, Error> {
+    match address_format {
+        AddressType::P2pkh => Ok(format!("pkh({})", key)),
+        AddressType::P2sh => Ok(format!("sh(wpkh({}))", key)),
+        AddressType::P2wpkh => Ok(format!("wpkh({})", key)),
+        AddressType::P2tr => Ok(format!("tr({})", key)),
+        other => Err(Error::CurrentlyNotSupported(format!(
+            "Address format {} currently not supported",
+            other
+        ))),
+    }
+}
+
+/// Returns the default HDPurpose associated with the address format
+fn default_hd_purpose(address_format: AddressType) -> Result<HDPurpose, Error> {
+    match address_format {
+        AddressType::P2pkh => Ok(HDPurpose::BIP44),
+        AddressType::P2sh => Ok(HDPurpose::BIP49),
+        AddressType::P2wpkh => Ok(HDPurpose::BIP84),
+        AddressType::P2tr => Ok(HDPurpose::BIP86),
+        other => Err(Error::CurrentlyNotSupported(format!(
+            "Address format {} currently not supported",
+            other
+        ))),
+    }
+}
+
+impl CryptoWalletBuilder<BitcoinWallet> for BitcoinWalletBuilder {
+    fn new() -> Self {
+        BitcoinWalletBuilder::new()
+    }
+
+    fn mnemonic_seed(&mut self, mnemonic_seed: Seed) -> &mut Self {
+        BitcoinWalletBuilder::mnemonic_seed(self, mnemonic_seed)
+    }
+
+    /// Maps [HDNetworkType::MainNet] to [Network::Bitcoin], [HDNetworkType::Signet] to [Network::Signet] and [HDNetworkType::TestNet] and any other network type to [Network::Testnet]
+    fn hd_network_type(&mut self, network_type: HDNetworkType) -> &mut Self {
+        match network_type {
+            HDNetworkType::MainNet => self.network_type(Network::Bitcoin),
+            HDNetworkType::Signet => self.network_type(Network::Signet),
+            _ => self.network_type(Network::Testnet),
+        }
+    }
+
+    fn build(&self) -> Result<BitcoinWallet, Error> {
+        BitcoinWalletBuilder::build(self)
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
     #[test]
     fn test_default() -> Result<(), Error> {
         let expected_default = BitcoinWallet {
             wallet: None,
             address_format: AddressType::P2wpkh,
+            hd_purpose: HDPurpose::BIP84,
+            frozen_outpoints: FrozenOutpoints::default(),
+            silent_payment_keys: None,
+            network_params: NetworkParams::default(),
         };
         let wallet = BitcoinWallet::default();
         assert_eq!(wallet.address_format, expected_default.address_format);
@@ -260,7 +1337,8 @@ mod tests {
         let default = BitcoinWalletBuilder::default();
         assert_eq!(builder.address_format, default.address_format);
         assert!(builder.mnemonic.is_none());
-        assert_eq!(builder.network_type, default.network_type);
+        assert_eq!(builder.network_params, default.network_params);
+        assert_eq!(builder.database, BitcoinDatabaseConfig::Memory);
         Ok(())
     }
 
@@ -293,7 +1371,704 @@ mod tests {
     fn test_with_network_type() -> Result<(), Error> {
         let mut builder = BitcoinWalletBuilder::default();
         builder.network_type(bdk::bitcoin::Network::Testnet);
-        assert_eq!(builder.network_type, bdk::bitcoin::Network::Testnet);
+        assert_eq!(
+            builder.network_params.network,
+            bdk::bitcoin::Network::Testnet
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_mnemonic_seed() -> Result<(), Error> {
+        let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+        let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+        let seed = Seed::new(mnemonic.to_seed("").to_vec());
+        let from_mnemonic = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        let from_seed = <BitcoinWalletBuilder as CryptoWalletBuilder<BitcoinWallet>>::new()
+            .mnemonic_seed(seed)
+            .hd_network_type(HDNetworkType::TestNet)
+            .build()?;
+        assert_eq!(from_seed.network()?, Network::Testnet);
+        assert_eq!(
+            from_seed.receive_address()?,
+            from_mnemonic.receive_address()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_restores_address_index() -> Result<(), Error> {
+        let path = std::env::temp_dir().join(format!(
+            "walletd_bitcoin_test_open_{}.sqlite",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+        let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+        let mut builder = BitcoinWallet::builder();
+        builder.mnemonic(mnemonic).network_type(Network::Testnet);
+
+        assert!(BitcoinWallet::open(&path, &builder).is_err());
+
+        let first_address = {
+            let wallet = builder
+                .clone()
+                .database(BitcoinDatabaseConfig::Sqlite(path.clone()))
+                .build()?;
+            wallet.next_address()?
+        };
+        assert_eq!(first_address.index, 0);
+
+        let reopened = BitcoinWallet::open(&path, &builder)?;
+        let second_address = reopened.next_address()?;
+        assert_eq!(second_address.index, 1);
+        assert_ne!(second_address.address, first_address.address);
+
+        std::fs::remove_file(&path).unwrap();
+        Ok(())
+    }
+
+    const BIP_TEST_VECTOR_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn bip_test_vector_wallet(
+        address_format: AddressType,
+        network: Network,
+    ) -> Result<BitcoinWallet, Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .address_format(address_format)
+            .network_type(network)
+            .build()
+    }
+
+    #[test]
+    fn test_bip44_p2pkh_vector() -> Result<(), Error> {
+        let wallet = bip_test_vector_wallet(AddressType::P2pkh, Network::Bitcoin)?;
+        assert_eq!(wallet.hd_purpose(), HDPurpose::BIP44);
+        assert_eq!(wallet.network()?, Network::Bitcoin);
+        assert_eq!(
+            wallet.receive_address()?,
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip49_p2sh_p2wpkh_vector() -> Result<(), Error> {
+        let wallet = bip_test_vector_wallet(AddressType::P2sh, Network::Testnet)?;
+        assert_eq!(wallet.hd_purpose(), HDPurpose::BIP49);
+        assert_eq!(wallet.network()?, Network::Testnet);
+        assert_eq!(
+            wallet.receive_address()?,
+            "2Mww8dCYPUpKHofjgcXcBCEGmniw9CoaiD2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip84_p2wpkh_vector() -> Result<(), Error> {
+        let wallet = bip_test_vector_wallet(AddressType::P2wpkh, Network::Bitcoin)?;
+        assert_eq!(wallet.hd_purpose(), HDPurpose::BIP84);
+        assert_eq!(
+            wallet.receive_address()?,
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_signet_wallet() -> Result<(), Error> {
+        let wallet = bip_test_vector_wallet(AddressType::P2wpkh, Network::Signet)?;
+        assert_eq!(wallet.network()?, Network::Signet);
+        assert_eq!(wallet.coin_type_id()?, 1);
+        assert_eq!(
+            wallet.receive_address()?,
+            "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_network_params() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let params = NetworkParams {
+            bech32_hrp: "rt".to_string(),
+            ..NetworkParams::from(Network::Regtest)
+        };
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic.clone())
+            .network_params(params.clone())
+            .build()?;
+        let regtest_wallet = bip_test_vector_wallet(AddressType::P2wpkh, Network::Regtest)?;
+        assert_eq!(wallet.network()?, Network::Regtest);
+        let address = wallet.receive_address()?;
+        assert!(address.starts_with("rt1q"));
+        assert_eq!(
+            params.parse_address(&address)?,
+            regtest_wallet.next_address()?.address
+        );
+        let recipient = [(address.as_str(), BitcoinAmount::from_sat(1000))];
+        assert!(matches!(
+            wallet.create_psbt(&recipient, FeePolicy::Default),
+            Err(Error::InsufficientFunds { .. })
+        ));
+        let regtest_address = regtest_wallet.receive_address()?;
+        assert!(matches!(
+            wallet.create_psbt(
+                &[(regtest_address.as_str(), BitcoinAmount::from_sat(1000))],
+                FeePolicy::Default
+            ),
+            Err(Error::WrongNetwork { .. })
+        ));
+
+        let other_coin_type = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_params(NetworkParams {
+                coin_type: 42,
+                ..params
+            })
+            .build()?;
+        assert_eq!(other_coin_type.coin_type_id()?, 42);
+        assert_ne!(other_coin_type.receive_address()?, address);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip86_p2tr_vector() -> Result<(), Error> {
+        let wallet = bip_test_vector_wallet(AddressType::P2tr, Network::Bitcoin)?;
+        assert_eq!(wallet.hd_purpose(), HDPurpose::BIP86);
+        assert_eq!(
+            wallet.receive_address()?,
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_p2tr_key_path_spend() -> Result<(), Error> {
+        use bdk::bitcoin::secp256k1::{schnorr, Message, Secp256k1, XOnlyPublicKey};
+        use bdk::bitcoin::util::sighash::{Prevouts, SighashCache};
+        use bdk::bitcoin::{PackedLockTime, SchnorrSighashType, Transaction, TxIn, TxOut};
+        use bdk::LocalUtxo;
+
+        let wallet = bip_test_vector_wallet(AddressType::P2tr, Network::Bitcoin)?;
+        let bdk_wallet = wallet.bdk_wallet()?;
+        let address = wallet.next_address()?;
+        let prevout = TxOut {
+            value: 100_000,
+            script_pubkey: address.script_pubkey(),
+        };
+        let utxo = LocalUtxo {
+            outpoint: OutPoint::from_str(
+                "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456:0",
+            )
+            .unwrap(),
+            txout: prevout.clone(),
+            keychain: KeychainKind::External,
+            is_spent: false,
+        };
+        let unsigned_tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: utxo.outpoint,
+                ..Default::default()
+            }],
+            output: vec![TxOut {
+                value: 99_000,
+                script_pubkey: address.script_pubkey(),
+            }],
+        };
+        let mut psbt = Psbt::from_unsigned_tx(unsigned_tx).unwrap();
+        psbt.inputs[0] = bdk_wallet.get_psbt_input(utxo, None, true)?;
+        psbt.inputs[0].witness_utxo = Some(prevout.clone());
+
+        wallet.sign_psbt(&mut psbt)?;
+        assert!(psbt.inputs[0].tap_key_sig.is_some());
+        assert!(wallet.finalize_psbt(&mut psbt)?);
+        let signed_tx = psbt.extract_tx();
+
+        // a key path spend is witnessed by a single 64 byte Schnorr signature
+        let witness: Vec<&[u8]> = signed_tx.input[0].witness.iter().collect();
+        assert_eq!(witness.len(), 1);
+        let signature = schnorr::Signature::from_slice(witness[0]).unwrap();
+        let sighash = SighashCache::new(&signed_tx)
+            .taproot_key_spend_signature_hash(
+                0,
+                &Prevouts::All(std::slice::from_ref(&prevout)),
+                SchnorrSighashType::Default,
+            )
+            .unwrap();
+        let output_key = XOnlyPublicKey::from_slice(&prevout.script_pubkey[2..]).unwrap();
+        Secp256k1::verification_only()
+            .verify_schnorr(
+                &signature,
+                &Message::from_slice(&sighash[..]).unwrap(),
+                &output_key,
+            )
+            .unwrap();
+        // one taproot input and one taproot output
+        assert_eq!(signed_tx.vsize(), 111);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_p2tr_create_psbt_pays_fee_rate() -> Result<(), Error> {
+        let mut wallet = BitcoinWallet::builder()
+            .mnemonic(Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap())
+            .address_format(AddressType::P2tr)
+            .network_type(Network::Testnet)
+            .build()?;
+        wallet.sync(&FundingBlockchain).await?;
+        let address = wallet.next_address()?.to_string();
+
+        for fee_rate in [1.0, 5.0, 12.5] {
+            let (mut psbt, details) = wallet.create_psbt(
+                &[(&address, BitcoinAmount::from_sat(10_000))],
+                FeePolicy::FeeRate(fee_rate),
+            )?;
+            wallet.sign_psbt(&mut psbt)?;
+            assert!(wallet.finalize_psbt(&mut psbt)?);
+            let signed_tx = psbt.extract_tx();
+            assert_eq!(signed_tx.input[0].witness.len(), 1);
+
+            // the fee is estimated before signing, it must cover the signed transaction
+            let fee = details
+                .fee
+                .expect("the fee of a created transaction is known");
+            assert!(
+                fee as f32 >= fee_rate * signed_tx.vsize() as f32,
+                "fee {} is below {} sat/vB for {} vB",
+                fee,
+                fee_rate,
+                signed_tx.vsize()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_address_format() {
+        assert!(matches!(
+            bip_test_vector_wallet(AddressType::P2wsh, Network::Bitcoin),
+            Err(Error::CurrentlyNotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_psbt_requires_resolved_confirmation_target() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        let recipients = [(
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+            BitcoinAmount::from_sat(1000),
+        )];
+        assert!(matches!(
+            wallet.create_psbt(&recipients, FeePolicy::ConfirmationTarget(6)),
+            Err(Error::MissingInfo(_))
+        ));
+        assert!(matches!(
+            wallet.create_psbt(&recipients, FeePolicy::FeeRate(-1.0)),
+            Err(Error::InvalidFeeRate(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_psbt_requires_recipients() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        assert!(matches!(
+            wallet.create_psbt(&[], FeePolicy::Default),
+            Err(Error::MissingInfo(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_drain_psbt_checks_address_network() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        assert!(matches!(
+            wallet.create_drain_psbt(
+                "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+                FeePolicy::Default
+            ),
+            Err(Error::WrongNetwork { .. })
+        ));
+        assert!(matches!(
+            wallet.create_drain_psbt("notanaddress", FeePolicy::Default),
+            Err(Error::InvalidAddress(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_psbt_with_insufficient_funds() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        assert!(matches!(
+            wallet.create_psbt(
+                &[("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", BitcoinAmount::from_sat(10_000))],
+                FeePolicy::Default
+            ),
+            Err(Error::InsufficientFunds {
+                needed,
+                available: 0
+            }) if needed > 10_000
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_psbt_with_frozen_input() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let mut wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        let outpoint = OutPoint::from_str(
+            "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456:1",
+        )
+        .unwrap();
+        let recipients = [(
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+            BitcoinAmount::from_sat(1000),
+        )];
+        assert!(matches!(
+            wallet.create_psbt_with_inputs(&[], &recipients, FeePolicy::Default),
+            Err(Error::MissingInfo(_))
+        ));
+        assert!(wallet.freeze(outpoint)?);
+        assert_eq!(wallet.frozen_outpoints(), vec![outpoint]);
+        assert!(matches!(
+            wallet.create_psbt_with_inputs(&[outpoint], &recipients, FeePolicy::Default),
+            Err(Error::FrozenOutpoint(_))
+        ));
+        assert!(wallet.unfreeze(&outpoint)?);
+        assert!(wallet.frozen_outpoints().is_empty());
+        // the outpoint does not belong to the wallet
+        assert!(wallet
+            .create_psbt_with_inputs(&[outpoint], &recipients, FeePolicy::Default)
+            .is_err());
+        assert!(wallet.list_unspent()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bump_fee_and_cpfp_unknown_transaction() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        let txid =
+            Txid::from_str("5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456")
+                .unwrap();
+        assert!(matches!(
+            wallet.create_bump_fee_psbt(&txid, 5.0),
+            Err(Error::Bdk(bdk::Error::TransactionNotFound))
+        ));
+        assert!(matches!(
+            wallet.create_cpfp_psbt(&txid, 5.0),
+            Err(Error::Bdk(bdk::Error::TransactionNotFound))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_transactions_of_new_wallet() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap();
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(mnemonic)
+            .network_type(Network::Testnet)
+            .build()?;
+        assert!(wallet.transactions(&TransactionQuery::new())?.is_empty());
+        assert!(BitcoinWallet::default()
+            .transactions(&TransactionQuery::new())
+            .is_err());
+        Ok(())
+    }
+
+    const BIP84_ACCOUNT_ZPUB: &str = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+
+    #[test]
+    fn test_watch_only_from_zpub() -> Result<(), Error> {
+        let wallet = BitcoinWallet::builder()
+            .extended_public_key(BIP84_ACCOUNT_ZPUB)
+            .address_format(AddressType::P2pkh)
+            .build()?;
+        assert!(wallet.is_watch_only());
+        assert_eq!(wallet.address_format(), AddressType::P2wpkh);
+        assert_eq!(wallet.hd_purpose(), HDPurpose::BIP84);
+        assert_eq!(
+            wallet.receive_address()?,
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        let mut psbt = Psbt::from_unsigned_tx(bdk::bitcoin::Transaction {
+            version: 2,
+            lock_time: bdk::bitcoin::PackedLockTime::ZERO,
+            input: Vec::new(),
+            output: Vec::new(),
+        })
+        .unwrap();
+        assert!(matches!(wallet.sign_psbt(&mut psbt), Err(Error::WatchOnly)));
+
+        let signer = bip_test_vector_wallet(AddressType::P2wpkh, Network::Bitcoin)?;
+        assert!(!signer.is_watch_only());
+        Ok(())
+    }
+
+    #[test]
+    fn test_watch_only_rejects_multisig_keys() {
+        // the Zpub and Ypub versions of the account key belong to multisig wallets
+        for version in [[0x02, 0xaa, 0x7e, 0xd3], [0x02, 0x95, 0xb4, 0x3f]] {
+            let key = crate::xpub::tests::with_version(BIP84_ACCOUNT_ZPUB, version);
+            assert!(matches!(
+                BitcoinWallet::builder().extended_public_key(&key).build(),
+                Err(Error::UnableToImportWallet(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_watch_only_from_descriptors() -> Result<(), Error> {
+        let xpub = "[73c5da0a/84'/0'/0']xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V";
+        let external = format!("wpkh({}/0/*)", xpub);
+        let internal = format!("wpkh({}/1/*)", xpub);
+        let wallet = BitcoinWallet::builder()
+            .descriptors(&external, Some(&internal))
+            .build()?;
+        assert!(wallet.is_watch_only());
+        assert_eq!(wallet.address_format(), AddressType::P2wpkh);
+        assert_eq!(
+            wallet.receive_address()?,
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        assert!(BitcoinWallet::builder()
+            .extended_public_key(BIP84_ACCOUNT_ZPUB)
+            .network_type(Network::Testnet)
+            .build()
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify_message() -> Result<(), Error> {
+        for address_format in [
+            AddressType::P2pkh,
+            AddressType::P2sh,
+            AddressType::P2wpkh,
+            AddressType::P2tr,
+        ] {
+            let wallet = bip_test_vector_wallet(address_format, Network::Bitcoin)?;
+            let address = wallet.receive_address()?;
+            let signature = wallet.sign_message(&address, "Hello World")?;
+            assert!(wallet.verify_message(&address, "Hello World", &signature)?);
+            assert!(!wallet.verify_message(&address, "Hello World!", &signature)?);
+        }
+
+        let wallet = bip_test_vector_wallet(AddressType::P2wpkh, Network::Bitcoin)?;
+        let address = wallet.receive_address()?;
+        let signature = wallet.sign_message_with_format(
+            &address,
+            "Hello World",
+            MessageSignatureFormat::Bip322Simple,
+        )?;
+        assert!(wallet.verify_message(&address, "Hello World", &signature)?);
+        // an address that was not generated by the wallet
+        assert!(wallet
+            .sign_message("bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l", "Hello World")
+            .is_err());
+
+        let watch_only = BitcoinWallet::builder()
+            .extended_public_key(BIP84_ACCOUNT_ZPUB)
+            .build()?;
+        assert!(matches!(
+            watch_only.sign_message(&address, "Hello World"),
+            Err(Error::WatchOnly)
+        ));
+        assert!(watch_only.verify_message(&address, "Hello World", &signature)?);
+        Ok(())
+    }
+
+    /// Funds the first receive address of the synced wallet with a confirmed output
+    pub(crate) struct FundingBlockchain;
+
+    impl FundingBlockchain {
+        fn funding_transaction(script_pubkey: Script) -> Transaction {
+            use bdk::bitcoin::{PackedLockTime, TxIn, TxOut};
+            Transaction {
+                version: 2,
+                lock_time: PackedLockTime::ZERO,
+                input: vec![TxIn {
+                    previous_output: OutPoint::from_str(
+                        "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456:0",
+                    )
+                    .unwrap(),
+                    ..Default::default()
+                }],
+                output: vec![TxOut {
+                    value: 100_000,
+                    script_pubkey,
+                }],
+            }
+        }
+    }
+
+    impl WalletSync for FundingBlockchain {
+        fn wallet_setup<D: bdk::database::BatchDatabase>(
+            &self,
+            database: &std::cell::RefCell<D>,
+            _progress_update: Box<dyn bdk::blockchain::Progress>,
+        ) -> Result<(), bdk::Error> {
+            let mut database = database.borrow_mut();
+            let script_pubkey = database
+                .get_script_pubkey_from_path(KeychainKind::External, 0)?
+                .unwrap();
+            let transaction = Self::funding_transaction(script_pubkey);
+            let txid = transaction.txid();
+            database.set_utxo(&bdk::LocalUtxo {
+                outpoint: OutPoint::new(txid, 0),
+                txout: transaction.output[0].clone(),
+                keychain: KeychainKind::External,
+                is_spent: false,
+            })?;
+            database.set_tx(&TransactionDetails {
+                transaction: Some(transaction),
+                txid,
+                received: 100_000,
+                sent: 0,
+                fee: None,
+                confirmation_time: Some(bdk::BlockTime {
+                    height: 100,
+                    timestamp: 0,
+                }),
+            })?;
+            Ok(())
+        }
+    }
+
+    impl GetHeight for FundingBlockchain {
+        fn get_height(&self) -> Result<u32, bdk::Error> {
+            Ok(101)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_silent_payment_transfer() -> Result<(), Error> {
+        for address_format in [AddressType::P2wpkh, AddressType::P2tr] {
+            let mut sender = BitcoinWallet::builder()
+                .mnemonic(Mnemonic::parse(BIP_TEST_VECTOR_MNEMONIC).unwrap())
+                .address_format(address_format)
+                .network_type(Network::Testnet)
+                .build()?;
+            sender.sync(&FundingBlockchain).await?;
+            let recipient = BitcoinWallet::builder()
+                .mnemonic(
+                    Mnemonic::parse(
+                        "outer ride neither foil glue number place usage ball shed dry point",
+                    )
+                    .unwrap(),
+                )
+                .network_type(Network::Testnet)
+                .build()?;
+            let address = recipient.silent_payment_address()?;
+            assert!(address.to_string().starts_with("tsp1"));
+            let labeled_address = recipient.silent_payment_address_with_label(7)?;
+            assert_eq!(labeled_address.scan_key, address.scan_key);
+
+            let (mut psbt, details) = sender.create_psbt(
+                &[
+                    (&address.to_string(), BitcoinAmount::from_sat(10_000)),
+                    (
+                        &labeled_address.to_string(),
+                        BitcoinAmount::from_sat(20_000),
+                    ),
+                ],
+                FeePolicy::FeeRate(1.0),
+            )?;
+            sender.sign_psbt(&mut psbt)?;
+            assert!(sender.finalize_psbt(&mut psbt)?);
+            let transaction = psbt.extract_tx();
+            assert_eq!(details.txid, transaction.txid());
+
+            let prevouts = [sender
+                .bdk_wallet()?
+                .get_address(AddressIndex::Peek(0))?
+                .script_pubkey()];
+            let payments =
+                recipient.scan_silent_payment_transaction(&transaction, &prevouts, &[7])?;
+            let mut received: Vec<(u64, Option<u32>)> = payments
+                .iter()
+                .map(|(outpoint, payment)| {
+                    (
+                        transaction.output[outpoint.vout as usize].value,
+                        payment.label,
+                    )
+                })
+                .collect();
+            received.sort();
+            assert_eq!(received, vec![(10_000, None), (20_000, Some(7))]);
+            // the payment to the labeled address is missed without its label
+            assert_eq!(
+                recipient
+                    .scan_silent_payment_transaction(&transaction, &prevouts, &[])?
+                    .len(),
+                1
+            );
+            assert!(sender
+                .scan_silent_payment_transaction(&transaction, &prevouts, &[7])?
+                .is_empty());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_silent_payment_address_checks() -> Result<(), Error> {
+        let wallet = bip_test_vector_wallet(AddressType::P2wpkh, Network::Testnet)?;
+        let mainnet_address = bip_test_vector_wallet(AddressType::P2wpkh, Network::Bitcoin)?
+            .silent_payment_address()?
+            .to_string();
+        assert!(mainnet_address.starts_with("sp1"));
+        assert!(matches!(
+            wallet.create_psbt(
+                &[(&mainnet_address, BitcoinAmount::from_sat(10_000))],
+                FeePolicy::Default
+            ),
+            Err(Error::WrongNetwork { .. })
+        ));
+
+        let watch_only = BitcoinWallet::builder()
+            .extended_public_key(BIP84_ACCOUNT_ZPUB)
+            .build()?;
+        assert!(matches!(
+            watch_only.silent_payment_address(),
+            Err(Error::MissingMnemonicSeed)
+        ));
+        assert!(matches!(
+            watch_only.create_psbt(
+                &[(&mainnet_address, BitcoinAmount::from_sat(10_000))],
+                FeePolicy::Default
+            ),
+            Err(Error::WatchOnly)
+        ));
         Ok(())
     }
 }
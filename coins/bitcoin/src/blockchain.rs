@@ -0,0 +1,154 @@
+use crate::discovery::DEFAULT_GAP_LIMIT;
+use crate::Error;
+use bdk::bitcoin::Network;
+use bdk::blockchain::any::{AnyBlockchain, AnyBlockchainConfig};
+use bdk::blockchain::electrum::ElectrumBlockchainConfig;
+use bdk::blockchain::esplora::EsploraBlockchainConfig;
+use bdk::blockchain::rpc::{Auth, RpcConfig};
+use bdk::blockchain::ConfigurableBlockchain;
+use std::path::PathBuf;
+
+/// The number of times a failed request to an Electrum server is retried
+const ELECTRUM_RETRY: u8 = 3;
+
+/// Specifies the blockchain backend a [BitcoinWallet][crate::BitcoinWallet] syncs with, estimates fees from and broadcasts its transactions through.
+///
+/// The backend is connected with [`BitcoinWallet::connect`][crate::BitcoinWallet::connect], the resulting [AnyBlockchain] is accepted by every method of the wallet taking a blockchain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitcoinBlockchainConfig {
+    /// An Electrum server
+    Electrum {
+        /// The URL of the server starting with `ssl://` or `tcp://` and including the port, such as `ssl://electrum.blockstream.info:60002`
+        url: String,
+        /// The number of consecutive unused addresses after which the sync stops looking for transactions
+        stop_gap: usize,
+    },
+    /// An Esplora HTTP API
+    Esplora {
+        /// The base URL of the API, such as `https://blockstream.info/testnet/api`
+        base_url: String,
+        /// The number of consecutive unused addresses after which the sync stops looking for transactions
+        stop_gap: usize,
+    },
+    /// The RPC interface of a Bitcoin Core node
+    ///
+    /// The addresses of the wallet are imported into a watch-only wallet of the node, which is created if it does not exist.
+    Rpc {
+        /// The URL of the node, such as `http://127.0.0.1:18443` for regtest
+        url: String,
+        /// The authentication used for the RPC calls
+        auth: BitcoinRpcAuth,
+        /// The name of the watch-only wallet of the node, each [BitcoinWallet][crate::BitcoinWallet] needs its own
+        wallet_name: String,
+    },
+}
+
+/// The authentication of the RPC calls to a Bitcoin Core node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitcoinRpcAuth {
+    /// Authenticates with the cookie file written by the node in its data directory, this is the default of Bitcoin Core
+    Cookie(PathBuf),
+    /// Authenticates with the `rpcuser` and `rpcpassword` configured on the node
+    UserPass {
+        /// The RPC user
+        username: String,
+        /// The RPC password
+        password: String,
+    },
+}
+
+impl BitcoinBlockchainConfig {
+    /// Returns the configuration of an Electrum server with a stop gap of [DEFAULT_GAP_LIMIT]
+    pub fn electrum(url: &str) -> Self {
+        Self::Electrum {
+            url: url.to_string(),
+            stop_gap: DEFAULT_GAP_LIMIT as usize,
+        }
+    }
+
+    /// Returns the configuration of an Esplora HTTP API with a stop gap of [DEFAULT_GAP_LIMIT]
+    pub fn esplora(base_url: &str) -> Self {
+        Self::Esplora {
+            base_url: base_url.to_string(),
+            stop_gap: DEFAULT_GAP_LIMIT as usize,
+        }
+    }
+
+    /// Connects to the backend for the `network`
+    ///
+    /// Returns an [error][Error] if the backend cannot be reached, a Bitcoin Core node is also checked to run on the `network`.
+    pub fn connect(&self, network: Network) -> Result<AnyBlockchain, Error> {
+        Ok(AnyBlockchain::from_config(&self.to_any_config(network))?)
+    }
+
+    /// Converts to the blockchain configuration used by bdk
+    pub(crate) fn to_any_config(&self, network: Network) -> AnyBlockchainConfig {
+        match self {
+            Self::Electrum { url, stop_gap } => {
+                AnyBlockchainConfig::Electrum(ElectrumBlockchainConfig {
+                    url: url.clone(),
+                    socks5: None,
+                    retry: ELECTRUM_RETRY,
+                    timeout: None,
+                    stop_gap: *stop_gap,
+                    validate_domain: true,
+                })
+            }
+            Self::Esplora { base_url, stop_gap } => AnyBlockchainConfig::Esplora(
+                EsploraBlockchainConfig::new(base_url.clone(), *stop_gap),
+            ),
+            Self::Rpc {
+                url,
+                auth,
+                wallet_name,
+            } => AnyBlockchainConfig::Rpc(RpcConfig {
+                url: url.clone(),
+                auth: match auth {
+                    BitcoinRpcAuth::Cookie(file) => Auth::Cookie { file: file.clone() },
+                    BitcoinRpcAuth::UserPass { username, password } => Auth::UserPass {
+                        username: username.clone(),
+                        password: password.clone(),
+                    },
+                },
+                network,
+                wallet_name: wallet_name.clone(),
+                sync_params: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_any_config() {
+        match BitcoinBlockchainConfig::esplora("https://blockstream.info/testnet/api")
+            .to_any_config(Network::Testnet)
+        {
+            AnyBlockchainConfig::Esplora(config) => {
+                assert_eq!(config.base_url, "https://blockstream.info/testnet/api");
+                assert_eq!(config.stop_gap, 20);
+            }
+            _ => panic!("expected an Esplora configuration"),
+        }
+        let rpc = BitcoinBlockchainConfig::Rpc {
+            url: "http://127.0.0.1:18443".to_string(),
+            auth: BitcoinRpcAuth::Cookie(PathBuf::from("regtest/.cookie")),
+            wallet_name: "walletd".to_string(),
+        };
+        match rpc.to_any_config(Network::Regtest) {
+            AnyBlockchainConfig::Rpc(config) => {
+                assert_eq!(config.network, Network::Regtest);
+                assert_eq!(
+                    config.auth,
+                    Auth::Cookie {
+                        file: PathBuf::from("regtest/.cookie")
+                    }
+                );
+            }
+            _ => panic!("expected an RPC configuration"),
+        }
+    }
+}
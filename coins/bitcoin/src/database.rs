@@ -0,0 +1,85 @@
+use bdk::database::any::{SledDbConfiguration, SqliteDbConfiguration};
+use bdk::database::AnyDatabaseConfig;
+use std::path::PathBuf;
+
+/// Specifies the database backend used by a [BitcoinWallet][crate::BitcoinWallet] to store its data.
+///
+/// With a persistent backend ([Sqlite][BitcoinDatabaseConfig::Sqlite] or [Sled][BitcoinDatabaseConfig::Sled]) the sync state, the address indices and the transaction history are kept across runs, so the wallet does not have to rescan the blockchain from scratch.
+/// Only public wallet data is stored in the database, the keys are always derived from the mnemonic or seed given to the [BitcoinWalletBuilder][crate::BitcoinWalletBuilder].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum BitcoinDatabaseConfig {
+    /// Keeps the wallet data in memory, nothing is persisted, this is the default
+    #[default]
+    Memory,
+    /// Persists the wallet data to a sqlite database file at the specified path
+    Sqlite(PathBuf),
+    /// Persists the wallet data to a sled key-value database
+    Sled {
+        /// The directory of the sled database
+        path: PathBuf,
+        /// The name of the tree used for the wallet, allows multiple wallets to share one sled database
+        tree_name: String,
+    },
+}
+
+impl BitcoinDatabaseConfig {
+    /// Returns the path of the persistent database, returns None for [BitcoinDatabaseConfig::Memory]
+    pub fn path(&self) -> Option<&PathBuf> {
+        match self {
+            Self::Memory => None,
+            Self::Sqlite(path) => Some(path),
+            Self::Sled { path, .. } => Some(path),
+        }
+    }
+
+    /// Returns the path of the file the frozen outpoints are persisted to, stored next to the database, returns None for [BitcoinDatabaseConfig::Memory]
+    ///
+    /// The file of a sled database is stored beside its directory rather than inside it, which belongs to sled.
+    pub(crate) fn frozen_outpoints_path(&self) -> Option<PathBuf> {
+        let (path, suffix) = match self {
+            Self::Memory => return None,
+            Self::Sqlite(path) => (path, ".frozen".to_string()),
+            Self::Sled { path, tree_name } => (path, format!(".{}.frozen", tree_name)),
+        };
+        let mut file_name = path.file_name()?.to_os_string();
+        file_name.push(suffix);
+        Some(path.with_file_name(file_name))
+    }
+
+    /// Converts to the database configuration used by bdk
+    pub(crate) fn to_any_config(&self) -> AnyDatabaseConfig {
+        match self {
+            Self::Memory => AnyDatabaseConfig::Memory(()),
+            Self::Sqlite(path) => AnyDatabaseConfig::Sqlite(SqliteDbConfiguration {
+                path: path.to_string_lossy().into_owned(),
+            }),
+            Self::Sled { path, tree_name } => AnyDatabaseConfig::Sled(SledDbConfiguration {
+                path: path.to_string_lossy().into_owned(),
+                tree_name: tree_name.clone(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frozen_outpoints_path() {
+        assert_eq!(BitcoinDatabaseConfig::Memory.frozen_outpoints_path(), None);
+        assert_eq!(
+            BitcoinDatabaseConfig::Sqlite(PathBuf::from("data/wallet.sqlite"))
+                .frozen_outpoints_path(),
+            Some(PathBuf::from("data/wallet.sqlite.frozen"))
+        );
+        assert_eq!(
+            BitcoinDatabaseConfig::Sled {
+                path: PathBuf::from("data"),
+                tree_name: "wallet".to_string()
+            }
+            .frozen_outpoints_path(),
+            Some(PathBuf::from("data.wallet.frozen"))
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::Error;
+use bdk::bitcoin::secp256k1::Secp256k1;
+use bdk::bitcoin::AddressType;
+use bdk::blockchain::{noop_progress, WalletSync};
+use bdk::database::{BatchOperations, Database, MemoryDatabase};
+use bdk::descriptor::ExtendedDescriptor;
+use bdk::KeychainKind;
+use std::cell::RefCell;
+use walletd_hd_key::HDPurpose;
+
+/// The default number of consecutive unused addresses after which the scan of an account stops, as recommended by [BIP44](https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki#address-gap-limit)
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// Account indices are hardened in the derivation path, so they are below 2^31
+pub(crate) const ACCOUNT_INDEX_LIMIT: u32 = 1 << 31;
+
+/// An account with a transaction history found by [`BitcoinWalletBuilder::discover_accounts`][crate::BitcoinWalletBuilder::discover_accounts]
+///
+/// The wallet of the account is restored by setting its address format, HD purpose and account index on the builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscoveredAccount {
+    /// The HD purpose of the derivation path of the account
+    pub hd_purpose: HDPurpose,
+    /// The address format of the account
+    pub address_format: AddressType,
+    /// The index of the account in the derivation path m/purpose'/coin_type'/account'
+    pub account_index: u32,
+    /// The number of transactions found on the scanned receive addresses of the account
+    pub transaction_count: usize,
+}
+
+/// Returns the number of transactions of the first `gap_limit` addresses of the external descriptor
+///
+/// The addresses are synced with the `blockchain` backend in a temporary in-memory database, so the scan does not touch the database of any wallet.
+pub(crate) fn transaction_count<B: WalletSync>(
+    blockchain: &B,
+    external_descriptor: &str,
+    gap_limit: u32,
+) -> Result<usize, Error> {
+    let secp = Secp256k1::new();
+    let (descriptor, _) = ExtendedDescriptor::parse_descriptor(&secp, external_descriptor)
+        .map_err(|e| Error::UnableToImportWallet(e.to_string()))?;
+    let mut database = MemoryDatabase::new();
+    for index in 0..gap_limit {
+        database.set_script_pubkey(
+            &descriptor.at_derivation_index(index).script_pubkey(),
+            KeychainKind::External,
+            index,
+        )?;
+    }
+    let database = RefCell::new(database);
+    blockchain.wallet_setup(&database, Box::new(noop_progress()))?;
+    let transaction_count = database.borrow().iter_txs(false)?.len();
+    Ok(transaction_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BitcoinWalletBuilder;
+    use bdk::bitcoin::hashes::Hash;
+    use bdk::bitcoin::{Address, Network, Script, Txid};
+    use bdk::blockchain::Progress;
+    use bdk::database::BatchDatabase;
+    use bdk::keys::bip39::Mnemonic;
+    use bdk::TransactionDetails;
+    use std::str::FromStr;
+
+    /// Reports one transaction for each of the used scripts
+    struct MockBlockchain {
+        used: Vec<Script>,
+    }
+
+    impl WalletSync for MockBlockchain {
+        fn wallet_setup<D: BatchDatabase>(
+            &self,
+            database: &RefCell<D>,
+            _progress_update: Box<dyn Progress>,
+        ) -> Result<(), bdk::Error> {
+            let mut database = database.borrow_mut();
+            for script in database.iter_script_pubkeys(Some(KeychainKind::External))? {
+                if self.used.contains(&script) {
+                    database.set_tx(&TransactionDetails {
+                        transaction: None,
+                        txid: Txid::hash(script.as_bytes()),
+                        received: 1000,
+                        sent: 0,
+                        fee: None,
+                        confirmation_time: None,
+                    })?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn builder() -> BitcoinWalletBuilder {
+        let mut builder = BitcoinWalletBuilder::new();
+        builder.mnemonic(
+            Mnemonic::parse(
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            )
+            .unwrap(),
+        );
+        builder
+    }
+
+    #[tokio::test]
+    async fn test_discover_accounts() -> Result<(), Error> {
+        let address_script = |address: &str| Address::from_str(address).unwrap().script_pubkey();
+        // the sixth receive address of the second BIP84 account
+        let second_account = builder().account_index(1).build()?;
+        for _ in 0..5 {
+            second_account.receive_address()?;
+        }
+        let blockchain = MockBlockchain {
+            used: vec![
+                // the first receive addresses of the first BIP44 and BIP84 accounts
+                address_script("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"),
+                address_script("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"),
+                address_script(&second_account.receive_address()?),
+            ],
+        };
+
+        let accounts = builder().discover_accounts(&blockchain).await?;
+        let found: Vec<_> = accounts
+            .iter()
+            .map(|account| (account.hd_purpose, account.account_index))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (HDPurpose::BIP44, 0),
+                (HDPurpose::BIP84, 0),
+                (HDPurpose::BIP84, 1)
+            ]
+        );
+        assert_eq!(accounts[1].address_format, AddressType::P2wpkh);
+        assert_eq!(accounts[1].transaction_count, 1);
+
+        // the address of the second BIP84 account is beyond a gap limit of 5
+        let accounts = builder()
+            .gap_limit(5)
+            .discover_accounts(&blockchain)
+            .await?;
+        assert_eq!(accounts.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_discover_accounts_requires_mnemonic() {
+        let blockchain = MockBlockchain { used: Vec::new() };
+        assert!(matches!(
+            BitcoinWalletBuilder::new()
+                .network_type(Network::Testnet)
+                .discover_accounts(&blockchain)
+                .await,
+            Err(Error::MissingMnemonicSeed)
+        ));
+    }
+}
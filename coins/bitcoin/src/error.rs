@@ -1,3 +1,4 @@
+use bdk::bitcoin::Network;
 use thiserror::Error;
 
 /// Custom error type for this crate.
@@ -9,9 +10,17 @@ pub enum Error {
     /// Error getting fee map
     #[error("Did not get fee map")]
     MissingFeeMap,
-    /// Error due to insufficent funds
-    #[error("Insufficent funds")]
-    InsufficientFunds(String),
+    /// Error due to a fee rate that is negative, zero, NaN or infinite
+    #[error("Invalid fee rate: {0} sat/vB")]
+    InvalidFeeRate(f32),
+    /// Error due to insufficent funds to pay the recipients and the fee
+    #[error("Insufficient funds: {needed} satoshi needed, {available} satoshi available")]
+    InsufficientFunds {
+        /// The amount needed in satoshi, including the fee
+        needed: u64,
+        /// The amount available in satoshi
+        available: u64,
+    },
     /// Missing master HD key
     #[error("No master HD key set")]
     MissingMasterHDKey,
@@ -54,4 +63,47 @@ pub enum Error {
     /// Error due to overflow
     #[error("Overflow error: {0}")]
     Overflow(String),
+    /// Error handling a partially signed bitcoin transaction (PSBT)
+    #[error("PSBT error: {0}")]
+    Psbt(String),
+    /// Error from the bdk crate
+    #[error("Error from bdk: {0}")]
+    Bdk(#[source] bdk::Error),
+    /// Error due to trying to spend a frozen outpoint
+    #[error("Outpoint is frozen: {0}")]
+    FrozenOutpoint(String),
+    /// Error reading or writing a file
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Error due to trying to sign with a watch-only wallet
+    #[error("The wallet is watch-only and cannot sign transactions")]
+    WatchOnly,
+    /// Error signing or verifying a message
+    #[error("Message signature error: {0}")]
+    MessageSignature(String),
+    /// Error parsing an address
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    /// Error due to an address of another network
+    #[error("Address {address} is not valid for network {network}")]
+    WrongNetwork {
+        /// The address
+        address: String,
+        /// The network the address was expected to be valid for
+        network: Network,
+    },
+    /// Error syncing the wallet with the blockchain
+    #[error("Error syncing the wallet: {0}")]
+    Sync(String),
+}
+
+impl From<bdk::Error> for Error {
+    fn from(error: bdk::Error) -> Self {
+        match error {
+            bdk::Error::InsufficientFunds { needed, available } => {
+                Error::InsufficientFunds { needed, available }
+            }
+            other => Error::Bdk(other),
+        }
+    }
 }
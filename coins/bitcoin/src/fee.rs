@@ -0,0 +1,100 @@
+use crate::{BitcoinAmount, Error};
+use bdk::blockchain::Blockchain;
+use std::collections::BTreeMap;
+
+/// The confirmation targets (in blocks) queried by [`BitcoinWallet::estimate_fee`][crate::BitcoinWallet::estimate_fee]
+pub const DEFAULT_CONFIRMATION_TARGETS: [usize; 6] = [1, 2, 3, 6, 12, 144];
+
+/// Maps a confirmation target (in blocks) to the estimated fee rate in satoshi per virtual byte (sat/vB)
+pub type FeeMap = BTreeMap<usize, f32>;
+
+/// Specifies how the fee of a Bitcoin transaction is determined
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum FeePolicy {
+    /// Uses the default fee rate of the underlying wallet library (1 sat/vB)
+    #[default]
+    Default,
+    /// Uses the specified fee rate in satoshi per virtual byte (sat/vB)
+    FeeRate(f32),
+    /// Uses the specified absolute fee
+    AbsoluteFee(BitcoinAmount),
+    /// Uses the fee rate estimated by the blockchain backend for the transaction to confirm within the specified number of blocks
+    ConfirmationTarget(usize),
+}
+
+impl FeePolicy {
+    /// Resolves a [FeePolicy::ConfirmationTarget] to a [FeePolicy::FeeRate] using the fee estimate of the blockchain backend, other policies are returned unchanged
+    ///
+    /// Returns [Error::MissingFeeMap] if the blockchain backend does not provide an estimate for the target and [Error::InvalidFeeRate] if a [FeePolicy::FeeRate] is negative, zero, NaN or infinite
+    pub fn resolve<B: Blockchain>(self, blockchain: &B) -> Result<Self, Error> {
+        match self {
+            FeePolicy::ConfirmationTarget(target) => {
+                let fee_rate = blockchain.estimate_fee(target)?.as_sat_per_vb();
+                if is_valid_fee_rate(fee_rate) {
+                    Ok(FeePolicy::FeeRate(fee_rate))
+                } else {
+                    Err(Error::MissingFeeMap)
+                }
+            }
+            FeePolicy::FeeRate(fee_rate) => Ok(FeePolicy::FeeRate(check_fee_rate(fee_rate)?)),
+            other => Ok(other),
+        }
+    }
+}
+
+/// Backends report a negative or zero fee rate when they have no estimate for a target
+fn is_valid_fee_rate(fee_rate: f32) -> bool {
+    fee_rate.is_finite() && fee_rate > 0.0
+}
+
+/// Returns [Error::InvalidFeeRate] unless the fee rate is finite and positive
+pub(crate) fn check_fee_rate(fee_rate: f32) -> Result<f32, Error> {
+    if is_valid_fee_rate(fee_rate) {
+        Ok(fee_rate)
+    } else {
+        Err(Error::InvalidFeeRate(fee_rate))
+    }
+}
+
+/// Queries the blockchain backend for the fee rate estimate of each of the `targets`, targets without a valid estimate are left out of the [FeeMap]
+///
+/// Returns [Error::MissingFeeMap] if none of the targets has a valid estimate
+pub(crate) fn estimate_fee_map<B: Blockchain>(
+    blockchain: &B,
+    targets: &[usize],
+) -> Result<FeeMap, Error> {
+    let mut fee_map = FeeMap::new();
+    for target in targets {
+        let fee_rate = blockchain.estimate_fee(*target)?.as_sat_per_vb();
+        if is_valid_fee_rate(fee_rate) {
+            fee_map.insert(*target, fee_rate);
+        }
+    }
+    if fee_map.is_empty() {
+        return Err(Error::MissingFeeMap);
+    }
+    Ok(fee_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk::blockchain::EsploraBlockchain;
+
+    #[test]
+    fn test_resolve_fee_rate() -> Result<(), Error> {
+        // a fee rate is resolved without querying the backend
+        let blockchain = EsploraBlockchain::new("http://127.0.0.1:1", 20);
+        assert_eq!(
+            FeePolicy::FeeRate(2.5).resolve(&blockchain)?,
+            FeePolicy::FeeRate(2.5)
+        );
+        for fee_rate in [-1.0, 0.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            assert!(matches!(
+                FeePolicy::FeeRate(fee_rate).resolve(&blockchain),
+                Err(Error::InvalidFeeRate(_))
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use bdk::bitcoin::blockdata::script::Instruction;
+use bdk::bitcoin::{Address, Network, PublicKey, Script, TxIn, Txid};
+
+/// The direction of a transaction from the point of view of the wallet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionDirection {
+    /// The wallet received funds from someone else
+    Received,
+    /// The wallet sent funds to someone else
+    Sent,
+    /// The wallet sent funds to its own addresses only, for example to consolidate outputs
+    SelfTransfer,
+}
+
+/// A transaction of a [BitcoinWallet][crate::BitcoinWallet] as listed by [`BitcoinWallet::transactions`][crate::BitcoinWallet::transactions]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitcoinTransaction {
+    /// The transaction id
+    pub txid: Txid,
+    /// Whether the wallet received or sent funds with the transaction
+    pub direction: TransactionDirection,
+    /// The sum of the wallet's inputs spent by the transaction in satoshi
+    pub sent: u64,
+    /// The sum of the transaction outputs paying to the wallet in satoshi
+    pub received: u64,
+    /// The fee paid by the transaction in satoshi, only known if all the inputs belong to the wallet
+    pub fee: Option<u64>,
+    /// The height of the block the transaction was confirmed in, None if it is unconfirmed
+    pub confirmation_height: Option<u32>,
+    /// The timestamp (in seconds since the Unix epoch) of the block the transaction was confirmed in, None if it is unconfirmed
+    pub confirmation_time: Option<u64>,
+    /// The addresses of the other parties of the transaction
+    ///
+    /// For sent transactions these are the recipients, for received transactions the sender addresses that can be recovered from the inputs (P2PKH, P2SH-P2WPKH and P2WPKH inputs), which may be empty
+    pub counterparties: Vec<String>,
+}
+
+impl BitcoinTransaction {
+    /// Returns the change of the wallet balance due to the transaction in satoshi, negative for sent transactions
+    pub fn net_amount(&self) -> i64 {
+        self.received as i64 - self.sent as i64
+    }
+
+    /// Returns true if the transaction is confirmed
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmation_height.is_some()
+    }
+}
+
+/// Filters and paginates the transactions listed by [`BitcoinWallet::transactions`][crate::BitcoinWallet::transactions]
+///
+/// The transactions are ordered newest first, unconfirmed transactions come before the confirmed ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionQuery {
+    since: Option<u64>,
+    until: Option<u64>,
+    offset: usize,
+    limit: Option<usize>,
+}
+
+impl TransactionQuery {
+    /// Returns a query listing all the transactions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only lists transactions confirmed at or after the timestamp (in seconds since the Unix epoch), unconfirmed transactions are still listed
+    pub fn since(&mut self, timestamp: u64) -> &mut Self {
+        self.since = Some(timestamp);
+        self
+    }
+
+    /// Only lists transactions confirmed at or before the timestamp (in seconds since the Unix epoch), unconfirmed transactions are left out
+    pub fn until(&mut self, timestamp: u64) -> &mut Self {
+        self.until = Some(timestamp);
+        self
+    }
+
+    /// Skips the first `offset` matching transactions
+    pub fn offset(&mut self, offset: usize) -> &mut Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Lists at most `limit` transactions
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns true if a transaction with the confirmation time is in the date range of the query
+    pub(crate) fn matches(&self, confirmation_time: Option<u64>) -> bool {
+        match confirmation_time {
+            Some(time) => {
+                !matches!(self.since, Some(since) if time < since)
+                    && !matches!(self.until, Some(until) if time > until)
+            }
+            None => self.until.is_none(),
+        }
+    }
+
+    /// Orders the transactions newest first and applies the date range and pagination of the query
+    pub(crate) fn apply(
+        &self,
+        mut transactions: Vec<BitcoinTransaction>,
+    ) -> Vec<BitcoinTransaction> {
+        transactions.retain(|tx| self.matches(tx.confirmation_time));
+        transactions.sort_by(|a, b| {
+            let height = |tx: &BitcoinTransaction| tx.confirmation_height.unwrap_or(u32::MAX);
+            height(b).cmp(&height(a)).then_with(|| a.txid.cmp(&b.txid))
+        });
+        transactions
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+/// Recovers the address spent by an input from its script sig and witness, for P2PKH, P2SH-P2WPKH and P2WPKH inputs
+pub(crate) fn input_address(input: &TxIn, network: Network) -> Option<Address> {
+    let pushes: Vec<&[u8]> = input
+        .script_sig
+        .instructions()
+        .map(|instruction| match instruction {
+            Ok(Instruction::PushBytes(bytes)) => Some(bytes),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+    let witness: Vec<&[u8]> = input.witness.iter().collect();
+    match (pushes.as_slice(), witness.as_slice()) {
+        // P2WPKH: empty script sig, witness of signature and public key
+        ([], [_, public_key]) => {
+            Address::p2wpkh(&PublicKey::from_slice(public_key).ok()?, network).ok()
+        }
+        // P2SH-P2WPKH: script sig pushing the redeem script, witness of signature and public key
+        ([redeem_script], [_, _]) => {
+            Address::p2sh(&Script::from(redeem_script.to_vec()), network).ok()
+        }
+        // P2PKH: script sig of signature and public key, no witness
+        ([_, public_key], []) => Some(Address::p2pkh(
+            &PublicKey::from_slice(public_key).ok()?,
+            network,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn transaction(byte: u8, confirmation: Option<(u32, u64)>) -> BitcoinTransaction {
+        BitcoinTransaction {
+            txid: Txid::from_str(&format!("{:02x}", byte).repeat(32)).unwrap(),
+            direction: TransactionDirection::Received,
+            sent: 0,
+            received: 1000,
+            fee: None,
+            confirmation_height: confirmation.map(|(height, _)| height),
+            confirmation_time: confirmation.map(|(_, time)| time),
+            counterparties: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_query_orders_newest_first() {
+        let transactions = vec![
+            transaction(1, Some((100, 1_000))),
+            transaction(2, None),
+            transaction(3, Some((200, 2_000))),
+        ];
+        let listed = TransactionQuery::new().apply(transactions);
+        let heights: Vec<_> = listed.iter().map(|tx| tx.confirmation_height).collect();
+        assert_eq!(heights, vec![None, Some(200), Some(100)]);
+    }
+
+    #[test]
+    fn test_query_date_range_and_pagination() {
+        let transactions = vec![
+            transaction(1, Some((100, 1_000))),
+            transaction(2, None),
+            transaction(3, Some((200, 2_000))),
+            transaction(4, Some((300, 3_000))),
+        ];
+        let listed = TransactionQuery::new()
+            .since(1_500)
+            .until(3_000)
+            .apply(transactions.clone());
+        let heights: Vec<_> = listed.iter().map(|tx| tx.confirmation_height).collect();
+        assert_eq!(heights, vec![Some(300), Some(200)]);
+
+        let listed = TransactionQuery::new()
+            .since(1_500)
+            .offset(1)
+            .limit(2)
+            .apply(transactions);
+        let heights: Vec<_> = listed.iter().map(|tx| tx.confirmation_height).collect();
+        assert_eq!(heights, vec![Some(300), Some(200)]);
+    }
+
+    #[test]
+    fn test_net_amount() {
+        let mut tx = transaction(1, None);
+        assert_eq!(tx.net_amount(), 1000);
+        tx.sent = 5000;
+        assert_eq!(tx.net_amount(), -4000);
+        assert!(!tx.is_confirmed());
+    }
+}
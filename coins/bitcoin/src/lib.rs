@@ -41,7 +41,54 @@
 //! let blockchain = ElectrumBlockchain::from(client);
 //! btc_wallet.sync(&blockchain).await?;
 //! let balance = btc_wallet.balance().await?;
-//! println!("bitcoin wallet balance: {} satoshi", balance.confirmed);
+//! println!("bitcoin wallet balance: {}", balance.confirmed);
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Choose a Blockchain Backend
+//!
+//! A [BitcoinBlockchainConfig] selects an Electrum server, an Esplora HTTP API or the RPC interface of a Bitcoin Core node, [`BitcoinWallet::connect`] connects to it for the network of the wallet.
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use walletd_bitcoin::BitcoinRpcAuth;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! async fn choose_backend() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let config = match std::env::var("BITCOIN_BACKEND").as_deref() {
+//!     Ok("esplora") => BitcoinBlockchainConfig::esplora("https://blockstream.info/testnet/api"),
+//!     Ok("rpc") => BitcoinBlockchainConfig::Rpc {
+//!         url: "http://127.0.0.1:18332".to_string(),
+//!         auth: BitcoinRpcAuth::Cookie("/home/user/.bitcoin/testnet3/.cookie".into()),
+//!         wallet_name: "walletd".to_string(),
+//!     },
+//!     _ => BitcoinBlockchainConfig::electrum("ssl://electrum.blockstream.info:60002"),
+//! };
+//! let blockchain = btc_wallet.connect(&config)?;
+//! btc_wallet.sync(&blockchain).await?;
+//! println!("bitcoin wallet balance: {}", btc_wallet.balance().await?.confirmed);
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Use Signet or a Private Network
+//!
+//! Every [Network][bdk::bitcoin::Network] is supported, including the signet. A private chain with its own address prefixes or coin type is described by [NetworkParams], based on the network it follows.
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! async fn private_network() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let signet_wallet = BitcoinWallet::builder().mnemonic(mnemonic.clone()).network_type(Network::Signet).build()?;
+//! println!("signet receive address: {}", signet_wallet.receive_address()?);
+//! let params = NetworkParams { bech32_hrp: "rt".to_string(), ..NetworkParams::from(Network::Regtest) };
+//! let private_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_params(params).build()?;
+//! println!("private network receive address: {}", private_wallet.receive_address()?);
 //! Ok(())
 //! }
 //! ```
@@ -61,11 +108,339 @@
 //! Ok(())
 //! }
 //! ```
+//!
+//! ### Taproot Wallet
+//!
+//! Setting the address format to [AddressType::P2tr][bdk::bitcoin::AddressType::P2tr] builds a taproot wallet using the [BIP86](https://github.com/bitcoin/bips/blob/master/bip-0086.mediawiki) derivation path m/86'/coin_type'/0'.
+//! Its outputs are spent through the key path with Schnorr signatures, and the fee of its transactions accounts for the smaller size of taproot inputs.
+//! ```
+//! use bdk::bitcoin::{AddressType, Network};
+//! use bdk::keys::bip39::Mnemonic;
+//! use walletd_bitcoin::prelude::*;
+//! fn taproot_wallet() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).address_format(AddressType::P2tr).network_type(Network::Testnet).build()?;
+//! assert!(btc_wallet.receive_address()?.starts_with("tb1p"));
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Watch-only Wallet
+//!
+//! A wallet built from an account-level extended public key (or from output descriptors with public keys only) holds no private keys.
+//! It can sync, check its balance, generate addresses and create PSBTs, which a wallet built from the mnemonic signs.
+//! ```
+//! use walletd_bitcoin::prelude::*;
+//! fn watch_only_wallet() -> Result<(), walletd_bitcoin::Error> {
+//! let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+//! let btc_wallet = BitcoinWallet::builder().extended_public_key(zpub).build()?;
+//! assert!(btc_wallet.is_watch_only());
+//! println!("next receive address: {}", btc_wallet.receive_address()?);
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Multisig Wallet
+//!
+//! A [MultisigWalletBuilder] builds a wallet shared with cosigners from our own mnemonic, the account extended public keys of the cosigners and a threshold.
+//! Each cosigner signs the PSBTs of the wallet, the partially signed PSBTs are merged with [psbt::combine] and the transaction is finalized once enough signatures are present.
+//! ```no_run
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use walletd_bitcoin::prelude::*;
+//! use walletd_bitcoin::psbt;
+//! fn multisig_wallet(cosigner_xpubs: [&str; 2], cosigner_psbt: &str) -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut builder = MultisigWalletBuilder::new();
+//! builder.mnemonic(mnemonic).network_type(Network::Testnet).threshold(2);
+//! println!("share with the cosigners: {}", builder.account_xpub()?);
+//! for cosigner_xpub in cosigner_xpubs {
+//!     builder.cosigner(cosigner_xpub);
+//! }
+//! let btc_wallet = builder.build()?;
+//! println!("shared receive address: {}", btc_wallet.receive_address()?);
+//!
+//! let recipients = [("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", BitcoinAmount::from_sat(5000))];
+//! let (mut our_psbt, _details) = btc_wallet.create_psbt(&recipients, FeePolicy::FeeRate(2.0))?;
+//! btc_wallet.sign_psbt(&mut our_psbt)?;
+//! // the same PSBT, signed by a cosigner
+//! let cosigner_psbt = psbt::from_base64(cosigner_psbt)?;
+//! let mut combined = psbt::combine(&[our_psbt, cosigner_psbt])?;
+//! assert!(btc_wallet.finalize_psbt(&mut combined)?);
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Discover the Accounts of a Mnemonic
+//!
+//! [`BitcoinWalletBuilder::discover_accounts`] scans the accounts of the BIP44, BIP49, BIP84 and BIP86 derivation paths for a transaction history, which restores a wallet created by other software.
+//! The scan of an account stops after [gap limit][BitcoinWalletBuilder::gap_limit] consecutive unused addresses, [DEFAULT_GAP_LIMIT] by default.
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use bdk::blockchain::ElectrumBlockchain;
+//! use bdk::electrum_client::Client;
+//! async fn discover_accounts() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let blockchain = ElectrumBlockchain::from(Client::new("ssl://electrum.blockstream.info:60002").unwrap());
+//! let mut builder = BitcoinWallet::builder();
+//! builder.mnemonic(mnemonic).network_type(Network::Testnet).gap_limit(20);
+//! for account in builder.discover_accounts(&blockchain).await? {
+//!     let mut btc_wallet = builder.clone().address_format(account.address_format).hd_purpose(account.hd_purpose).account_index(account.account_index).build()?;
+//!     btc_wallet.sync(&blockchain).await?;
+//!     println!("account {} of {:?} holds {}", account.account_index, account.hd_purpose, btc_wallet.balance().await?.confirmed);
+//! }
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Persist the Wallet Data
+//!
+//! By default the wallet data is kept in memory and every new [BitcoinWallet] needs a full sync with the blockchain.
+//! Specifying a persistent [BitcoinDatabaseConfig] keeps the sync state, address indices and transaction history across runs, and [`BitcoinWallet::open`] restores a wallet from an existing sqlite database.
+//! ```no_run
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use walletd_bitcoin::prelude::*;
+//! fn persist_btc_wallet() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut builder = BitcoinWallet::builder();
+//! builder.mnemonic(mnemonic).network_type(Network::Testnet);
+//! let btc_wallet = builder.clone().database(BitcoinDatabaseConfig::Sqlite("wallet.sqlite".into())).build()?;
+//! println!("next receive address: {}", btc_wallet.receive_address()?);
+//! drop(btc_wallet);
+//! // later, possibly after a restart
+//! let btc_wallet = BitcoinWallet::open("wallet.sqlite", &builder)?;
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Send Batch Payments and Sweep the Wallet
+//!
+//! [`BitcoinWallet::transfer`] pays any number of recipients in a single transaction and [`BitcoinWallet::drain`] sends all the funds of the wallet to one address, subtracting the fee from the swept amount.
+//! Both return the [TransactionDetails][bdk::TransactionDetails] of the broadcast transaction.
+//! The amounts are [BitcoinAmount]s, which are parsed exactly from a decimal string in BTC, mBTC, bits or satoshi.
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use bdk::blockchain::ElectrumBlockchain;
+//! use bdk::electrum_client::Client;
+//! async fn batch_and_sweep() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let blockchain = ElectrumBlockchain::from(Client::new("ssl://electrum.blockstream.info:60002").unwrap());
+//! btc_wallet.sync(&blockchain).await?;
+//!
+//! let recipients = [
+//!     ("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", BitcoinAmount::from_btc("0.00005")?),
+//!     ("tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7", "70 bits".parse()?),
+//! ];
+//! let details = btc_wallet.transfer(&blockchain, &recipients, FeePolicy::ConfirmationTarget(6)).await?;
+//! println!("batch transaction {} paid a fee of {:?} satoshi", details.txid, details.fee);
+//!
+//! btc_wallet.sync(&blockchain).await?;
+//! let details = btc_wallet.drain(&blockchain, "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", FeePolicy::FeeRate(2.0)).await?;
+//! println!("swept {} satoshi including a fee of {:?} satoshi", details.sent, details.fee);
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### List the Transaction History
+//!
+//! [`BitcoinWallet::transactions`] lists the [transactions][BitcoinTransaction] of the synced wallet with their direction, amounts, fee, confirmation and counterparties.
+//! A [TransactionQuery] restricts the list to a date range and paginates it.
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use bdk::blockchain::ElectrumBlockchain;
+//! use bdk::electrum_client::Client;
+//! async fn activity_feed() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let blockchain = ElectrumBlockchain::from(Client::new("ssl://electrum.blockstream.info:60002").unwrap());
+//! btc_wallet.sync(&blockchain).await?;
+//!
+//! // the second page of 20 transactions since the start of 2023
+//! let transactions = btc_wallet.transactions(TransactionQuery::new().since(1_672_531_200).offset(20).limit(20))?;
+//! for tx in transactions {
+//!     println!("{:?} {} satoshi in {} to/from {:?}", tx.direction, tx.net_amount(), tx.txid, tx.counterparties);
+//! }
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Pay a BIP21 URI
+//!
+//! A [PaymentUri] parses and builds `bitcoin:` URIs, [`BitcoinWallet::transfer_to_uri`] pays one after checking its address against the network of the wallet.
+//! ```no_run
+//! use std::str::FromStr;
+//! use walletd_bitcoin::prelude::*;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use bdk::blockchain::ElectrumBlockchain;
+//! use bdk::electrum_client::Client;
+//! async fn pay_uri() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let blockchain = ElectrumBlockchain::from(Client::new("ssl://electrum.blockstream.info:60002").unwrap());
+//! btc_wallet.sync(&blockchain).await?;
+//!
+//! let payment_uri = PaymentUri::from_str("bitcoin:tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx?amount=0.0005&label=Shop")?;
+//! btc_wallet.transfer_to_uri(&blockchain, &payment_uri, FeePolicy::ConfirmationTarget(6)).await?;
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Sign and Verify a Message
+//!
+//! [`BitcoinWallet::sign_message`] proves ownership of an address of the wallet by signing a message with its key.
+//! P2PKH, P2SH-P2WPKH and P2WPKH addresses use the legacy [BIP137](https://github.com/bitcoin/bips/blob/master/bip-0137.mediawiki) format understood by most wallets, P2TR addresses use the simple [BIP322](https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki) format, see [MessageSignatureFormat].
+//! ```
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use walletd_bitcoin::prelude::*;
+//! fn sign_message() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let address = btc_wallet.receive_address()?;
+//! let signature = btc_wallet.sign_message_with_format(&address, "I own this address", MessageSignatureFormat::Bip322Simple)?;
+//! assert!(btc_wallet.verify_message(&address, "I own this address", &signature)?);
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Receive and Send Silent Payments
+//!
+//! [`BitcoinWallet::silent_payment_address`] returns a static [BIP352](https://github.com/bitcoin/bips/blob/master/bip-0352.mediawiki) address ([SilentPaymentAddress]) that can be published, every payment to it lands on a new taproot output.
+//! A silent payment address is accepted as a recipient by [`BitcoinWallet::transfer`], and the payments to the wallet are found by scanning the transactions with [`BitcoinWallet::scan_silent_payment_transaction`].
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use bdk::bitcoin::{Network, Transaction, Script};
+//! use bdk::keys::bip39::Mnemonic;
+//! use bdk::blockchain::AnyBlockchain;
+//! async fn silent_payments(blockchain: &AnyBlockchain, transaction: &Transaction, prevouts: &[Script]) -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let address = btc_wallet.silent_payment_address()?;
+//! println!("silent payment address: {}", address);
+//! btc_wallet.transfer(blockchain, &[("tsp1qqgste7k9hx0qftg6qmwlkqtwuy6cycyavzmzj85c6qdfhjdpdjtdgqjuexzk6murw56suy3e0rd2cgqvycxttddwsvgxe2usfpxumr70xc3wk4yh", BitcoinAmount::from_sat(10_000))], FeePolicy::Default).await?;
+//! for (outpoint, payment) in btc_wallet.scan_silent_payment_transaction(transaction, prevouts, &[])? {
+//!     println!("received {} with output key {}", outpoint, payment.output_key);
+//! }
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Coin Control
+//!
+//! [`BitcoinWallet::list_unspent`] lists the unspent outputs ([Utxo]) of the wallet.
+//! Frozen outputs are never selected as inputs, and [`BitcoinWallet::transfer_with_inputs`] spends only the inputs chosen by the caller.
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use bdk::blockchain::ElectrumBlockchain;
+//! use bdk::electrum_client::Client;
+//! async fn coin_control() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let blockchain = ElectrumBlockchain::from(Client::new("ssl://electrum.blockstream.info:60002").unwrap());
+//! btc_wallet.sync(&blockchain).await?;
+//!
+//! let utxos = btc_wallet.list_unspent()?;
+//! for utxo in &utxos {
+//!     println!("{} holds {} satoshi, confirmed at {:?}", utxo.outpoint, utxo.value, utxo.confirmation_height);
+//! }
+//! // keep the first output out of any automatic coin selection
+//! btc_wallet.freeze(utxos[0].outpoint)?;
+//! // spend the other outputs explicitly
+//! let inputs: Vec<_> = utxos[1..].iter().map(|utxo| utxo.outpoint).collect();
+//! let recipients = [("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", BitcoinAmount::from_sat(5000))];
+//! btc_wallet.transfer_with_inputs(&blockchain, &inputs, &recipients, FeePolicy::FeeRate(2.0)).await?;
+//! Ok(())
+//! }
+//! ```
+//!
+//! ### Create, Sign and Broadcast a PSBT in Separate Steps
+//!
+//! [`BitcoinWallet::transfer`] creates, signs and broadcasts a transaction in one step.
+//! The same can be done in separate steps with a [PSBT][psbt::Psbt], which can be exported with the [psbt] module functions and signed on another (possibly air-gapped) instance built from the same mnemonic.
+//! ```no_run
+//! use walletd_bitcoin::prelude::*;
+//! use walletd_bitcoin::psbt;
+//! use bdk::bitcoin::Network;
+//! use bdk::keys::bip39::Mnemonic;
+//! use bdk::blockchain::ElectrumBlockchain;
+//! use bdk::electrum_client::Client;
+//! async fn psbt_workflow() -> Result<(), walletd_bitcoin::Error> {
+//! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let mut btc_wallet = BitcoinWallet::builder().mnemonic(mnemonic.clone()).network_type(Network::Testnet).build()?;
+//! let blockchain = ElectrumBlockchain::from(Client::new("ssl://electrum.blockstream.info:60002").unwrap());
+//! btc_wallet.sync(&blockchain).await?;
+//!
+//! let recipients = [("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", BitcoinAmount::from_sat(5000))];
+//! let (psbt, details) = btc_wallet.create_psbt(&recipients, FeePolicy::FeeRate(2.0))?;
+//! let exported = psbt::to_base64(&psbt);
+//!
+//! // on the signing instance
+//! let signer = BitcoinWallet::builder().mnemonic(mnemonic).network_type(Network::Testnet).build()?;
+//! let mut psbt = psbt::from_base64(&exported)?;
+//! signer.sign_psbt(&mut psbt)?;
+//! let signed = psbt::to_base64(&psbt);
+//!
+//! // back on the online instance
+//! let mut psbt = psbt::from_base64(&signed)?;
+//! btc_wallet.finalize_psbt(&mut psbt)?;
+//! let txid = btc_wallet.broadcast(&blockchain, &psbt).await?;
+//! println!("broadcast transaction {} paying a fee of {:?} satoshi", txid, details.fee);
+//! Ok(())
+//! }
+//! ```
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+mod bip21;
+pub use bip21::PaymentUri;
+mod bitcoin_amount;
+pub use bitcoin_amount::{BitcoinAmount, BitcoinBalance, BitcoinDenomination};
 mod bitcoin_wallet;
 pub use bitcoin_wallet::{BitcoinWallet, BitcoinWalletBuilder};
+mod blockchain;
+pub use blockchain::{BitcoinBlockchainConfig, BitcoinRpcAuth};
+mod database;
+pub use database::BitcoinDatabaseConfig;
+mod discovery;
+pub use discovery::{DiscoveredAccount, DEFAULT_GAP_LIMIT};
+mod fee;
+mod history;
+mod message;
+pub use message::MessageSignatureFormat;
+mod multisig;
+mod network;
+pub use fee::{FeeMap, FeePolicy, DEFAULT_CONFIRMATION_TARGETS};
+pub use history::{BitcoinTransaction, TransactionDirection, TransactionQuery};
+pub use multisig::MultisigWalletBuilder;
+pub use network::NetworkParams;
+pub mod psbt;
+pub mod silent_payment;
+pub use silent_payment::{SilentPayment, SilentPaymentAddress};
+mod utxo;
+pub use utxo::Utxo;
+mod xpub;
 
 #[doc(hidden)]
 mod error;
@@ -0,0 +1,494 @@
+use crate::Error;
+use bdk::bitcoin::blockdata::opcodes;
+use bdk::bitcoin::blockdata::script::Builder;
+use bdk::bitcoin::consensus::encode;
+use bdk::bitcoin::hashes::{sha256, Hash, HashEngine};
+use bdk::bitcoin::secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+use bdk::bitcoin::secp256k1::{
+    KeyPair, Message, Secp256k1, SecretKey, Signing, Verification, XOnlyPublicKey,
+};
+use bdk::bitcoin::util::misc::signed_msg_hash;
+use bdk::bitcoin::util::schnorr::TapTweak;
+use bdk::bitcoin::util::sighash::{Prevouts, SighashCache};
+use bdk::bitcoin::{
+    Address, AddressType, EcdsaSig, EcdsaSighashType, OutPoint, PackedLockTime, PublicKey,
+    SchnorrSig, SchnorrSighashType, Script, Sequence, Transaction, TxIn, TxOut, Witness,
+};
+
+/// The tag of the hash of a message signed with BIP322
+const BIP322_TAG: &[u8] = b"BIP0322-signed-message";
+
+/// The format of a signed message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageSignatureFormat {
+    /// The legacy [BIP137](https://github.com/bitcoin/bips/blob/master/bip-0137.mediawiki) format for P2PKH, P2SH-P2WPKH and P2WPKH addresses, as produced by the "Sign Message" feature of most wallets
+    Bip137,
+    /// The "simple" [BIP322](https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki) format for P2SH-P2WPKH, P2WPKH and P2TR addresses
+    Bip322Simple,
+}
+
+impl MessageSignatureFormat {
+    /// Returns the format used by default for the address, [BIP322][MessageSignatureFormat::Bip322Simple] for P2TR addresses and [BIP137][MessageSignatureFormat::Bip137] otherwise
+    ///
+    /// Returns an [error][Error] if messages cannot be signed for the address type
+    pub fn default_for(address: &Address) -> Result<Self, Error> {
+        match address.address_type() {
+            Some(AddressType::P2pkh | AddressType::P2sh | AddressType::P2wpkh) => Ok(Self::Bip137),
+            Some(AddressType::P2tr) => Ok(Self::Bip322Simple),
+            _ => Err(unsupported_address(address)),
+        }
+    }
+}
+
+/// Signs the message for the address with the secret key in the specified format, returns the base64 encoded signature
+pub(crate) fn sign<C: Signing + Verification>(
+    secp: &Secp256k1<C>,
+    secret_key: &SecretKey,
+    address: &Address,
+    message: &str,
+    format: MessageSignatureFormat,
+) -> Result<String, Error> {
+    match format {
+        MessageSignatureFormat::Bip137 => sign_bip137(secp, secret_key, address, message),
+        MessageSignatureFormat::Bip322Simple => {
+            sign_bip322_simple(secp, secret_key, address, message)
+        }
+    }
+}
+
+/// Verifies the base64 encoded signature of the message for the address, the format of the signature is detected from its content
+///
+/// Returns false if the signature is well-formed but was not made by the key of the address, returns an [error][Error] if the signature is malformed.
+pub(crate) fn verify<C: Verification>(
+    secp: &Secp256k1<C>,
+    address: &Address,
+    message: &str,
+    signature: &str,
+) -> Result<bool, Error> {
+    let bytes = base64::decode(signature.trim())
+        .map_err(|e| Error::MessageSignature(format!("Invalid base64 signature: {}", e)))?;
+    if bytes.len() == 65 && (27..=42).contains(&bytes[0]) {
+        verify_bip137(secp, address, message, &bytes)
+    } else {
+        let witness: Witness = encode::deserialize(&bytes)
+            .map_err(|e| Error::MessageSignature(format!("Invalid BIP322 signature: {}", e)))?;
+        verify_bip322_simple(secp, address, message, &witness.to_vec())
+    }
+}
+
+/// Returns true if the address is the address of the secret key for its address type
+pub(crate) fn is_key_of<C: Signing + Verification>(
+    secp: &Secp256k1<C>,
+    secret_key: &SecretKey,
+    address: &Address,
+) -> bool {
+    let public_key = PublicKey::new(secret_key.public_key(secp));
+    let key_address = match address.address_type() {
+        Some(AddressType::P2pkh) => Some(Address::p2pkh(&public_key, address.network)),
+        Some(AddressType::P2sh) => Address::p2shwpkh(&public_key, address.network).ok(),
+        Some(AddressType::P2wpkh) => Address::p2wpkh(&public_key, address.network).ok(),
+        Some(AddressType::P2tr) => Some(Address::p2tr(
+            secp,
+            XOnlyPublicKey::from(public_key.inner),
+            None,
+            address.network,
+        )),
+        _ => None,
+    };
+    key_address.is_some_and(|key_address| key_address.script_pubkey() == address.script_pubkey())
+}
+
+/// Signs the message with a recoverable signature whose header byte encodes the address type
+fn sign_bip137<C: Signing>(
+    secp: &Secp256k1<C>,
+    secret_key: &SecretKey,
+    address: &Address,
+    message: &str,
+) -> Result<String, Error> {
+    let header_offset = match address.address_type() {
+        Some(AddressType::P2pkh) => 31,
+        Some(AddressType::P2sh) => 35,
+        Some(AddressType::P2wpkh) => 39,
+        _ => {
+            return Err(Error::MessageSignature(format!(
+                "BIP137 signatures are not supported for address {}",
+                address
+            )))
+        }
+    };
+    let msg = message_to_sign(&signed_msg_hash(message)[..])?;
+    let (recovery_id, compact) = secp
+        .sign_ecdsa_recoverable(&msg, secret_key)
+        .serialize_compact();
+    let mut serialized = [0u8; 65];
+    serialized[0] = header_offset + recovery_id.to_i32() as u8;
+    serialized[1..].copy_from_slice(&compact);
+    Ok(base64::encode(serialized))
+}
+
+/// Recovers the public key from the signature and checks that it matches the address
+///
+/// Like most wallets, the header byte only determines whether the key is compressed, so a signature made for the P2PKH address of a key is also accepted for its P2SH-P2WPKH and P2WPKH addresses.
+fn verify_bip137<C: Verification>(
+    secp: &Secp256k1<C>,
+    address: &Address,
+    message: &str,
+    signature: &[u8],
+) -> Result<bool, Error> {
+    let header = signature[0] - 27;
+    let recovery_id = RecoveryId::from_i32(i32::from(header % 4))
+        .map_err(|e| Error::MessageSignature(e.to_string()))?;
+    let signature = RecoverableSignature::from_compact(&signature[1..], recovery_id)
+        .map_err(|e| Error::MessageSignature(e.to_string()))?;
+    let msg = message_to_sign(&signed_msg_hash(message)[..])?;
+    let public_key = match secp.recover_ecdsa(&msg, &signature) {
+        Ok(public_key) => PublicKey {
+            compressed: header >= 4,
+            inner: public_key,
+        },
+        Err(_) => return Ok(false),
+    };
+    let mut key_addresses = vec![Address::p2pkh(&public_key, address.network)];
+    if public_key.compressed {
+        key_addresses.extend(Address::p2shwpkh(&public_key, address.network));
+        key_addresses.extend(Address::p2wpkh(&public_key, address.network));
+    }
+    Ok(key_addresses
+        .iter()
+        .any(|key_address| key_address.script_pubkey() == address.script_pubkey()))
+}
+
+/// Signs the virtual transaction spending the message commitment to the address and returns its witness
+fn sign_bip322_simple<C: Signing + Verification>(
+    secp: &Secp256k1<C>,
+    secret_key: &SecretKey,
+    address: &Address,
+    message: &str,
+) -> Result<String, Error> {
+    let public_key = PublicKey::new(secret_key.public_key(secp));
+    let to_spend = to_spend(address, message);
+    let to_sign = to_sign(&to_spend, bip322_script_sig(address, &public_key)?);
+    let mut sighash_cache = SighashCache::new(&to_sign);
+    let witness = match address.address_type() {
+        Some(AddressType::P2sh | AddressType::P2wpkh) => {
+            let sighash = sighash_cache
+                .segwit_signature_hash(
+                    0,
+                    &Script::new_p2pkh(&public_key.pubkey_hash()),
+                    0,
+                    EcdsaSighashType::All,
+                )
+                .map_err(|e| Error::MessageSignature(e.to_string()))?;
+            let signature =
+                EcdsaSig::sighash_all(secp.sign_ecdsa(&message_to_sign(&sighash)?, secret_key));
+            Witness::from_vec(vec![signature.to_vec(), public_key.to_bytes()])
+        }
+        Some(AddressType::P2tr) => {
+            let keypair = KeyPair::from_secret_key(secp, secret_key)
+                .tap_tweak(secp, None)
+                .to_inner();
+            let sighash = sighash_cache
+                .taproot_key_spend_signature_hash(
+                    0,
+                    &Prevouts::All(&to_spend.output),
+                    SchnorrSighashType::Default,
+                )
+                .map_err(|e| Error::MessageSignature(e.to_string()))?;
+            let signature = SchnorrSig {
+                sig: secp.sign_schnorr_no_aux_rand(&message_to_sign(&sighash)?, &keypair),
+                hash_ty: SchnorrSighashType::Default,
+            };
+            Witness::from_vec(vec![signature.to_vec()])
+        }
+        _ => return Err(unsupported_bip322_address(address)),
+    };
+    Ok(base64::encode(encode::serialize(&witness)))
+}
+
+/// Checks the witness of the virtual transaction spending the message commitment to the address
+fn verify_bip322_simple<C: Verification>(
+    secp: &Secp256k1<C>,
+    address: &Address,
+    message: &str,
+    witness: &[Vec<u8>],
+) -> Result<bool, Error> {
+    let to_spend = to_spend(address, message);
+    match address.address_type() {
+        Some(AddressType::P2sh | AddressType::P2wpkh) => {
+            let (signature, public_key) = match witness {
+                [signature, public_key] => {
+                    match (
+                        EcdsaSig::from_slice(signature),
+                        PublicKey::from_slice(public_key),
+                    ) {
+                        (Ok(signature), Ok(public_key)) => (signature, public_key),
+                        _ => return Ok(false),
+                    }
+                }
+                _ => return Ok(false),
+            };
+            let key_address = match address.address_type() {
+                Some(AddressType::P2sh) => Address::p2shwpkh(&public_key, address.network),
+                _ => Address::p2wpkh(&public_key, address.network),
+            };
+            if key_address.ok().as_ref() != Some(address) {
+                return Ok(false);
+            }
+            let to_sign = to_sign(&to_spend, bip322_script_sig(address, &public_key)?);
+            let sighash = SighashCache::new(&to_sign)
+                .segwit_signature_hash(
+                    0,
+                    &Script::new_p2pkh(&public_key.pubkey_hash()),
+                    0,
+                    signature.hash_ty,
+                )
+                .map_err(|e| Error::MessageSignature(e.to_string()))?;
+            Ok(secp
+                .verify_ecdsa(
+                    &message_to_sign(&sighash)?,
+                    &signature.sig,
+                    &public_key.inner,
+                )
+                .is_ok())
+        }
+        Some(AddressType::P2tr) => {
+            let signature = match witness {
+                [signature] => match SchnorrSig::from_slice(signature) {
+                    Ok(signature) => signature,
+                    Err(_) => return Ok(false),
+                },
+                _ => return Ok(false),
+            };
+            let output_key = XOnlyPublicKey::from_slice(&address.script_pubkey()[2..])
+                .map_err(|e| Error::MessageSignature(e.to_string()))?;
+            let to_sign = to_sign(&to_spend, Script::new());
+            let sighash = SighashCache::new(&to_sign)
+                .taproot_key_spend_signature_hash(
+                    0,
+                    &Prevouts::All(&to_spend.output),
+                    signature.hash_ty,
+                )
+                .map_err(|e| Error::MessageSignature(e.to_string()))?;
+            Ok(secp
+                .verify_schnorr(&signature.sig, &message_to_sign(&sighash)?, &output_key)
+                .is_ok())
+        }
+        _ => Err(unsupported_bip322_address(address)),
+    }
+}
+
+/// Returns the script_sig of the input of `to_sign` for the address, which holds the P2WPKH redeem script of the key for a P2SH-P2WPKH address and is empty otherwise
+fn bip322_script_sig(address: &Address, public_key: &PublicKey) -> Result<Script, Error> {
+    match address.address_type() {
+        Some(AddressType::P2sh) => {
+            let wpubkey_hash = public_key
+                .wpubkey_hash()
+                .ok_or_else(|| Error::MessageSignature("Uncompressed public key".to_string()))?;
+            Ok(Builder::new()
+                .push_slice(Script::new_v0_p2wpkh(&wpubkey_hash).as_bytes())
+                .into_script())
+        }
+        _ => Ok(Script::new()),
+    }
+}
+
+/// Returns the tagged hash of the message committed to by a BIP322 signature
+fn bip322_message_hash(message: &str) -> sha256::Hash {
+    let tag = sha256::Hash::hash(BIP322_TAG);
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag[..]);
+    engine.input(&tag[..]);
+    engine.input(message.as_bytes());
+    sha256::Hash::from_engine(engine)
+}
+
+/// Returns the BIP322 virtual transaction committing to the message and paying the address
+fn to_spend(address: &Address, message: &str) -> Transaction {
+    Transaction {
+        version: 0,
+        lock_time: PackedLockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Builder::new()
+                .push_int(0)
+                .push_slice(&bip322_message_hash(message)[..])
+                .into_script(),
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: 0,
+            script_pubkey: address.script_pubkey(),
+        }],
+    }
+}
+
+/// Returns the BIP322 virtual transaction spending the output of `to_spend` with the `script_sig`, its witness is the signature
+fn to_sign(to_spend: &Transaction, script_sig: Script) -> Transaction {
+    Transaction {
+        version: 0,
+        lock_time: PackedLockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::new(to_spend.txid(), 0),
+            script_sig,
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: 0,
+            script_pubkey: Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .into_script(),
+        }],
+    }
+}
+
+/// Converts a 32 byte hash to a message that can be signed
+fn message_to_sign(hash: &[u8]) -> Result<Message, Error> {
+    Message::from_slice(hash).map_err(|e| Error::MessageSignature(e.to_string()))
+}
+
+/// Returns the error for an address type messages cannot be signed for
+fn unsupported_address(address: &Address) -> Error {
+    Error::CurrentlyNotSupported(format!(
+        "Signing messages for address {} is currently not supported",
+        address
+    ))
+}
+
+/// Returns the error for an address type BIP322 simple signatures are not supported for
+fn unsupported_bip322_address(address: &Address) -> Error {
+    Error::CurrentlyNotSupported(format!(
+        "BIP322 simple signatures are currently not supported for address {}",
+        address
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk::bitcoin::util::misc::MessageSignature;
+    use bdk::bitcoin::{Network, PrivateKey};
+    use std::str::FromStr;
+
+    const PRIVATE_KEY: &str = "L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k";
+    const P2WPKH_ADDRESS: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+    const P2TR_ADDRESS: &str = "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3";
+
+    fn secret_key() -> SecretKey {
+        PrivateKey::from_wif(PRIVATE_KEY).unwrap().inner
+    }
+
+    #[test]
+    fn test_bip322_message_hash() {
+        assert_eq!(
+            bip322_message_hash("").to_string(),
+            "c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"
+        );
+        assert_eq!(
+            bip322_message_hash("Hello World").to_string(),
+            "f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"
+        );
+    }
+
+    #[test]
+    fn test_verify_bip322_vectors() -> Result<(), Error> {
+        let secp = Secp256k1::verification_only();
+        let address = Address::from_str(P2WPKH_ADDRESS).unwrap();
+        assert!(verify(
+            &secp,
+            &address,
+            "",
+            "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI="
+        )?);
+        let hello_world = "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+        assert!(verify(&secp, &address, "Hello World", hello_world)?);
+        assert!(!verify(&secp, &address, "Hello World!", hello_world)?);
+        let taproot_address = Address::from_str(P2TR_ADDRESS).unwrap();
+        assert!(verify(
+            &secp,
+            &taproot_address,
+            "Hello World",
+            "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ=="
+        )?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_and_verify() -> Result<(), Error> {
+        let secp = Secp256k1::new();
+        let secret_key = secret_key();
+        let public_key = PublicKey::new(secret_key.public_key(&secp));
+        let addresses = [
+            Address::p2pkh(&public_key, Network::Bitcoin),
+            Address::p2shwpkh(&public_key, Network::Bitcoin).unwrap(),
+            Address::p2wpkh(&public_key, Network::Bitcoin).unwrap(),
+            Address::from_str(P2TR_ADDRESS).unwrap(),
+        ];
+        for address in &addresses {
+            assert!(is_key_of(&secp, &secret_key, address));
+            let format = MessageSignatureFormat::default_for(address)?;
+            let signature = sign(&secp, &secret_key, address, "Hello World", format)?;
+            assert!(verify(&secp, address, "Hello World", &signature)?);
+            assert!(!verify(&secp, address, "Goodbye World", &signature)?);
+        }
+        for address in &addresses[1..3] {
+            let signature = sign(
+                &secp,
+                &secret_key,
+                address,
+                "Hello World",
+                MessageSignatureFormat::Bip322Simple,
+            )?;
+            assert!(verify(&secp, address, "Hello World", &signature)?);
+            assert!(!verify(&secp, address, "Goodbye World", &signature)?);
+        }
+        // the witness of a P2WPKH signature does not match the P2SH-P2WPKH address of another key
+        let signature = sign(
+            &secp,
+            &secret_key,
+            &addresses[2],
+            "Hello World",
+            MessageSignatureFormat::Bip322Simple,
+        )?;
+        let other_key = Address::p2shwpkh(
+            &PublicKey::new(SecretKey::from_slice(&[1; 32]).unwrap().public_key(&secp)),
+            Network::Bitcoin,
+        )
+        .unwrap();
+        assert!(!verify(&secp, &other_key, "Hello World", &signature)?);
+        assert!(matches!(
+            sign(
+                &secp,
+                &secret_key,
+                &addresses[0],
+                "Hello World",
+                MessageSignatureFormat::Bip322Simple
+            ),
+            Err(Error::CurrentlyNotSupported(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip137_compatible_with_legacy_verification() -> Result<(), Error> {
+        let secp = Secp256k1::new();
+        let secret_key = secret_key();
+        let address = Address::p2pkh(
+            &PublicKey::new(secret_key.public_key(&secp)),
+            Network::Bitcoin,
+        );
+        let signature = sign(
+            &secp,
+            &secret_key,
+            &address,
+            "Hello World",
+            MessageSignatureFormat::Bip137,
+        )?;
+        let legacy = MessageSignature::from_base64(&signature).unwrap();
+        assert!(legacy
+            .is_signed_by_address(&secp, &address, signed_msg_hash("Hello World"))
+            .unwrap());
+        Ok(())
+    }
+}
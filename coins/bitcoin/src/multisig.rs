@@ -0,0 +1,370 @@
+use crate::bitcoin_wallet::master_xprv;
+use crate::xpub::{AccountXpub, ScriptKind};
+use crate::{BitcoinDatabaseConfig, BitcoinWallet, BitcoinWalletBuilder, Error, NetworkParams};
+use bdk::bitcoin::secp256k1::Secp256k1;
+use bdk::bitcoin::util::bip32::{DerivationPath, ExtendedPrivKey, ExtendedPubKey};
+use bdk::bitcoin::{AddressType, Network};
+use bdk::keys::bip39::Mnemonic;
+use std::str::FromStr;
+use walletd_coin_core::Seed;
+use walletd_hd_key::HDPurpose;
+
+/// The maximum number of keys of a multisig wallet, the limit of a sortedmulti descriptor in P2SH
+const MAX_KEYS: usize = 15;
+
+/// Builder for a multisig [BitcoinWallet] shared with cosigners, such as a 2-of-3 treasury wallet.
+///
+/// The wallet is built from our own mnemonic and the account extended public keys of the cosigners, following the [BIP48](https://github.com/bitcoin/bips/blob/master/bip-0048.mediawiki) derivation path m/48'/coin_type'/0'/script_type'.
+/// Every cosigner building the wallet with the same keys and threshold gets the same sortedmulti descriptors and therefore the same receive addresses.
+/// Each cosigner signs the PSBTs of the wallet with [`BitcoinWallet::sign_psbt`], the partially signed PSBTs are merged with [`psbt::combine`][crate::psbt::combine] and finalized once the threshold is reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigWalletBuilder {
+    /// The address format of the wallet, [AddressType::P2wsh] (the default) or [AddressType::P2sh] for P2SH-P2WSH
+    address_format: AddressType,
+    /// The number of signatures needed to spend from the wallet
+    threshold: Option<usize>,
+    /// The account extended public keys of the cosigners
+    cosigners: Vec<String>,
+    /// Our own mnemonic
+    mnemonic: Option<Mnemonic>,
+    /// Our own mnemonic seed, takes precedence over the mnemonic if both are specified
+    mnemonic_seed: Option<Seed>,
+    /// The parameters of the network, the default network is Network::Bitcoin
+    network_params: NetworkParams,
+    /// The database used to store the wallet data, the default is an in-memory database
+    database: BitcoinDatabaseConfig,
+}
+
+impl Default for MultisigWalletBuilder {
+    fn default() -> Self {
+        Self {
+            address_format: AddressType::P2wsh,
+            threshold: None,
+            cosigners: Vec::new(),
+            mnemonic: None,
+            mnemonic_seed: None,
+            network_params: NetworkParams::default(),
+            database: BitcoinDatabaseConfig::Memory,
+        }
+    }
+}
+
+impl MultisigWalletBuilder {
+    /// Generates a new MultisigWalletBuilder with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows specification of our own mnemonic
+    pub fn mnemonic(&mut self, mnemonic: Mnemonic) -> &mut Self {
+        self.mnemonic = Some(mnemonic);
+        self
+    }
+
+    /// Allows specification of our own mnemonic seed, the seed takes precedence over a mnemonic if both are specified
+    pub fn mnemonic_seed(&mut self, mnemonic_seed: Seed) -> &mut Self {
+        self.mnemonic_seed = Some(mnemonic_seed);
+        self
+    }
+
+    /// Adds the account extended public key of a cosigner, as returned by [`account_xpub`][Self::account_xpub] on the builder of the cosigner
+    ///
+    /// Accepts xpub and Zpub keys for P2WSH or Ypub keys for P2SH-P2WSH (tpub, Vpub and Upub on the test networks), preceded by the key origin such as `[73c5da0a/48'/0'/0'/2']`.
+    pub fn cosigner(&mut self, extended_public_key: &str) -> &mut Self {
+        self.cosigners.push(extended_public_key.to_string());
+        self
+    }
+
+    /// Allows specification of the number of signatures needed to spend from the wallet
+    pub fn threshold(&mut self, threshold: usize) -> &mut Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Allows specification of the address format, [AddressType::P2wsh] (the default) or [AddressType::P2sh] for P2SH-P2WSH
+    pub fn address_format(&mut self, address_format: AddressType) -> &mut Self {
+        self.address_format = address_format;
+        self
+    }
+
+    /// Allows specification of the network type for the wallet, the default is Network::Bitcoin
+    pub fn network_type(&mut self, network_type: Network) -> &mut Self {
+        self.network_params = network_type.into();
+        self
+    }
+
+    /// Allows specification of the parameters of a private network, see [`BitcoinWalletBuilder::network_params`]
+    pub fn network_params(&mut self, network_params: NetworkParams) -> &mut Self {
+        self.network_params = network_params;
+        self
+    }
+
+    /// Allows specification of the database used to store the wallet data, the default is [BitcoinDatabaseConfig::Memory]
+    pub fn database(&mut self, database: BitcoinDatabaseConfig) -> &mut Self {
+        self.database = database;
+        self
+    }
+
+    /// Returns our own account extended public key with its key origin, to be shared with the cosigners and added with [`cosigner`][Self::cosigner] on their builders
+    pub fn account_xpub(&self) -> Result<String, Error> {
+        let secp = Secp256k1::new();
+        let xprv = self.xprv()?;
+        let account_path = self.account_path()?;
+        let derivation_path = DerivationPath::from_str(&format!("m/{}", account_path))
+            .map_err(|e| Error::FromStr(e.to_string()))?;
+        let account_xprv = xprv
+            .derive_priv(&secp, &derivation_path)
+            .map_err(|e| Error::UnableToImportWallet(e.to_string()))?;
+        Ok(format!(
+            "[{}/{}]{}",
+            xprv.fingerprint(&secp),
+            account_path,
+            ExtendedPubKey::from_priv(&secp, &account_xprv)
+        ))
+    }
+
+    /// Builds the multisig wallet with the specified options
+    ///
+    /// Returns an [error][Error] if the threshold is not between 1 and the number of keys, if there is no cosigner or if a cosigner key does not match the network or address format
+    pub fn build(&self) -> Result<BitcoinWallet, Error> {
+        let xprv = self.xprv()?;
+        let account_path = self.account_path()?;
+        if self.cosigners.is_empty() {
+            return Err(Error::MissingInfo("No cosigner specified".to_string()));
+        }
+        let threshold = self
+            .threshold
+            .ok_or_else(|| Error::MissingInfo("No threshold specified".to_string()))?;
+        let key_count = self.cosigners.len() + 1;
+        if key_count > MAX_KEYS {
+            return Err(Error::CurrentlyNotSupported(format!(
+                "Multisig wallets with more than {} keys",
+                MAX_KEYS
+            )));
+        }
+        if threshold == 0 || threshold > key_count {
+            return Err(Error::UnableToImportWallet(format!(
+                "Threshold {} is not valid for {} keys",
+                threshold, key_count
+            )));
+        }
+        let mut cosigners = Vec::new();
+        for cosigner in &self.cosigners {
+            let account = AccountXpub::parse(cosigner, self.network_params.network)?;
+            // single-sig keys and the keys of the other multisig address format are rejected
+            let script_kind = account.script_kind;
+            if script_kind != ScriptKind::Unspecified
+                && (!script_kind.is_multisig()
+                    || script_kind.address_format() != Some(self.address_format))
+            {
+                return Err(Error::UnableToImportWallet(format!(
+                    "Cosigner key {} does not match the address format {}",
+                    cosigner, self.address_format
+                )));
+            }
+            cosigners.push(account);
+        }
+
+        let descriptor = |change: u32| {
+            let mut keys = vec![format!("{}/{}/{}/*", xprv, account_path, change)];
+            keys.extend(
+                cosigners
+                    .iter()
+                    .map(|account| account.descriptor_key(change)),
+            );
+            let multi = format!("sortedmulti({},{})", threshold, keys.join(","));
+            match self.address_format {
+                AddressType::P2sh => format!("sh(wsh({}))", multi),
+                _ => format!("wsh({})", multi),
+            }
+        };
+        BitcoinWalletBuilder::new()
+            .descriptors(&descriptor(0), Some(&descriptor(1)))
+            .hd_purpose(HDPurpose::BIP48)
+            .network_params(self.network_params.clone())
+            .database(self.database.clone())
+            .build()
+    }
+
+    /// Returns our own master private key
+    fn xprv(&self) -> Result<ExtendedPrivKey, Error> {
+        master_xprv(
+            self.mnemonic_seed.as_ref(),
+            self.mnemonic.as_ref(),
+            self.network_params.network,
+        )?
+        .ok_or(Error::MissingMnemonicSeed)
+    }
+
+    /// Returns the BIP48 account derivation path without the leading m: 48'/coin_type'/0'/script_type'
+    ///
+    /// The script type is 2' for P2WSH and 1' for P2SH-P2WSH
+    fn account_path(&self) -> Result<String, Error> {
+        let coin_type = self.network_params.coin_type;
+        let script_type = match self.address_format {
+            AddressType::P2wsh => 2,
+            AddressType::P2sh => 1,
+            other => {
+                return Err(Error::CurrentlyNotSupported(format!(
+                    "Address format {} currently not supported for multisig wallets",
+                    other
+                )))
+            }
+        };
+        Ok(format!(
+            "{}/{}'/0'/{}'",
+            HDPurpose::BIP48,
+            coin_type,
+            script_type
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitcoin_wallet::tests::FundingBlockchain;
+    use crate::{psbt, BitcoinAmount, FeePolicy};
+
+    const MNEMONICS: [&str; 3] = [
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "outer ride neither foil glue number place usage ball shed dry point",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    ];
+
+    fn builder(own: usize, address_format: AddressType) -> Result<MultisigWalletBuilder, Error> {
+        let account_xpub = |index: usize| -> Result<String, Error> {
+            MultisigWalletBuilder::new()
+                .mnemonic(Mnemonic::parse(MNEMONICS[index]).unwrap())
+                .address_format(address_format)
+                .network_type(Network::Testnet)
+                .account_xpub()
+        };
+        let mut builder = MultisigWalletBuilder::new();
+        builder
+            .mnemonic(Mnemonic::parse(MNEMONICS[own]).unwrap())
+            .address_format(address_format)
+            .network_type(Network::Testnet)
+            .threshold(2);
+        for cosigner in (0..MNEMONICS.len()).filter(|index| *index != own) {
+            builder.cosigner(&account_xpub(cosigner)?);
+        }
+        Ok(builder)
+    }
+
+    #[test]
+    fn test_account_xpub() -> Result<(), Error> {
+        let account_xpub = MultisigWalletBuilder::new()
+            .mnemonic(Mnemonic::parse(MNEMONICS[0]).unwrap())
+            .account_xpub()?;
+        assert!(account_xpub.starts_with("[73c5da0a/48'/0'/0'/2']xpub"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cosigners_share_addresses() -> Result<(), Error> {
+        for address_format in [AddressType::P2wsh, AddressType::P2sh] {
+            let wallets = (0..MNEMONICS.len())
+                .map(|own| builder(own, address_format)?.build())
+                .collect::<Result<Vec<_>, Error>>()?;
+            let addresses = wallets
+                .iter()
+                .map(|wallet| wallet.receive_address())
+                .collect::<Result<Vec<_>, Error>>()?;
+            assert!(addresses.iter().all(|address| *address == addresses[0]));
+            for wallet in &wallets {
+                assert_eq!(wallet.address_format(), address_format);
+                assert_eq!(wallet.hd_purpose(), HDPurpose::BIP48);
+                assert!(!wallet.is_watch_only());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_mismatched_cosigner_keys() -> Result<(), Error> {
+        let cosigner = builder(1, AddressType::P2wsh)?.account_xpub()?;
+        let (_, tpub) = cosigner.split_once(']').unwrap();
+        // vpub and upub are single-sig keys, Vpub is a P2WSH key and Upub is a P2SH-P2WSH key
+        for (version, address_format) in [
+            ([0x04, 0x5f, 0x1c, 0xf6], AddressType::P2wsh),
+            ([0x04, 0x4a, 0x52, 0x62], AddressType::P2sh),
+            ([0x02, 0x57, 0x54, 0x83], AddressType::P2sh),
+            ([0x02, 0x42, 0x89, 0xef], AddressType::P2wsh),
+        ] {
+            let mut builder = builder(0, address_format)?;
+            builder.cosigner(&crate::xpub::tests::with_version(tpub, version));
+            assert!(matches!(
+                builder.build(),
+                Err(Error::UnableToImportWallet(_))
+            ));
+        }
+        // a Vpub matching the address format is accepted
+        let mut builder = MultisigWalletBuilder::new();
+        builder
+            .mnemonic(Mnemonic::parse(MNEMONICS[0]).unwrap())
+            .network_type(Network::Testnet)
+            .threshold(1)
+            .cosigner(&crate::xpub::tests::with_version(
+                tpub,
+                [0x02, 0x57, 0x54, 0x83],
+            ));
+        assert!(builder.build().is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_two_of_three_signing() -> Result<(), Error> {
+        for address_format in [AddressType::P2wsh, AddressType::P2sh] {
+            let mut wallets = (0..MNEMONICS.len())
+                .map(|own| builder(own, address_format)?.build())
+                .collect::<Result<Vec<_>, Error>>()?;
+            for wallet in &mut wallets {
+                wallet.sync(&FundingBlockchain).await?;
+            }
+            let (unsigned, _) = wallets[0].create_psbt(
+                &[(
+                    "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+                    BitcoinAmount::from_sat(10_000),
+                )],
+                FeePolicy::FeeRate(1.0),
+            )?;
+            let mut signed = Vec::new();
+            for wallet in &wallets[..2] {
+                let mut psbt = unsigned.clone();
+                wallet.sign_psbt(&mut psbt)?;
+                signed.push(psbt);
+            }
+
+            // a single signature does not reach the threshold
+            let mut single = signed[0].clone();
+            assert!(!wallets[2].finalize_psbt(&mut single)?);
+
+            let mut combined = psbt::combine(&signed)?;
+            assert!(wallets[2].finalize_psbt(&mut combined)?);
+            assert!(psbt::is_finalized(&combined));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_threshold() -> Result<(), Error> {
+        assert!(builder(0, AddressType::P2wsh)?
+            .threshold(4)
+            .build()
+            .is_err());
+        assert!(builder(0, AddressType::P2wsh)?
+            .threshold(0)
+            .build()
+            .is_err());
+        let mut without_cosigners = MultisigWalletBuilder::new();
+        without_cosigners
+            .mnemonic(Mnemonic::parse(MNEMONICS[0]).unwrap())
+            .threshold(1);
+        assert!(matches!(
+            without_cosigners.build(),
+            Err(Error::MissingInfo(_))
+        ));
+        Ok(())
+    }
+}
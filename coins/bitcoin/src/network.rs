@@ -0,0 +1,186 @@
+use crate::Error;
+use bdk::bitcoin::bech32;
+use bdk::bitcoin::util::address::AddressEncoding;
+use bdk::bitcoin::util::base58;
+use bdk::bitcoin::{Address, Network};
+use std::str::FromStr;
+use walletd_hd_key::slip44;
+
+/// The version byte of P2PKH addresses on the main network
+const MAINNET_P2PKH_PREFIX: u8 = 0x00;
+/// The version byte of P2SH addresses on the main network
+const MAINNET_P2SH_PREFIX: u8 = 0x05;
+/// The version byte of P2PKH addresses on the test networks
+const TESTNET_P2PKH_PREFIX: u8 = 0x6f;
+/// The version byte of P2SH addresses on the test networks
+const TESTNET_P2SH_PREFIX: u8 = 0xc4;
+
+/// The parameters of the network of a [BitcoinWallet][crate::BitcoinWallet]: the coin type of its derivation paths and the encoding of its addresses
+///
+/// The parameters of [Network::Bitcoin], [Network::Testnet], [Network::Signet] and [Network::Regtest] are returned by [From<Network>][NetworkParams::from].
+/// A private chain with its own address prefixes is supported by changing the parameters of the network it is based on, which still determines the extended key versions and the blockchain backend settings:
+/// ```
+/// use walletd_bitcoin::NetworkParams;
+/// use bdk::bitcoin::Network;
+/// let params = NetworkParams {
+///     bech32_hrp: "rt".to_string(),
+///     ..NetworkParams::from(Network::Regtest)
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkParams {
+    /// The network the chain is based on
+    pub network: Network,
+    /// The coin type of the derivation paths, 0 for [Network::Bitcoin] and 1 for the test networks
+    pub coin_type: u32,
+    /// The human-readable part of the segwit addresses, such as `bc` for [Network::Bitcoin]
+    pub bech32_hrp: String,
+    /// The version byte of the P2PKH addresses
+    pub p2pkh_prefix: u8,
+    /// The version byte of the P2SH addresses
+    pub p2sh_prefix: u8,
+}
+
+impl From<Network> for NetworkParams {
+    fn from(network: Network) -> Self {
+        let (coin_type, bech32_hrp) = match network {
+            Network::Bitcoin => (slip44::Coin::Bitcoin.id(), "bc"),
+            Network::Testnet | Network::Signet => (slip44::Coin::Testnet.id(), "tb"),
+            Network::Regtest => (slip44::Coin::Testnet.id(), "bcrt"),
+        };
+        let (p2pkh_prefix, p2sh_prefix) = match network {
+            Network::Bitcoin => (MAINNET_P2PKH_PREFIX, MAINNET_P2SH_PREFIX),
+            _ => (TESTNET_P2PKH_PREFIX, TESTNET_P2SH_PREFIX),
+        };
+        Self {
+            network,
+            coin_type,
+            bech32_hrp: bech32_hrp.to_string(),
+            p2pkh_prefix,
+            p2sh_prefix,
+        }
+    }
+}
+
+impl Default for NetworkParams {
+    fn default() -> Self {
+        Network::Bitcoin.into()
+    }
+}
+
+impl NetworkParams {
+    /// Encodes the address with the prefixes of the network
+    pub fn address_to_string(&self, address: &Address) -> String {
+        AddressEncoding {
+            payload: &address.payload,
+            p2pkh_prefix: self.p2pkh_prefix,
+            p2sh_prefix: self.p2sh_prefix,
+            bech32_hrp: &self.bech32_hrp,
+        }
+        .to_string()
+    }
+
+    /// Parses an address encoded with the prefixes of the network
+    ///
+    /// Returns [Error::WrongNetwork] for an address of another network and [Error::InvalidAddress] if the address is not valid.
+    pub fn parse_address(&self, address: &str) -> Result<Address, Error> {
+        let invalid = |e: String| Error::InvalidAddress(format!("{}: {}", address, e));
+        // the payload is validated by re-encoding it with the prefixes of the main network
+        let mainnet_address = match bech32::decode(address) {
+            Ok((hrp, data, variant)) => {
+                if hrp != self.bech32_hrp.to_lowercase() {
+                    return Err(self.wrong_network_or_invalid(address));
+                }
+                bech32::encode("bc", data, variant).map_err(|e| invalid(e.to_string()))?
+            }
+            Err(_) => {
+                let mut data = base58::from_check(address).map_err(|e| invalid(e.to_string()))?;
+                match data.first() {
+                    Some(prefix) if *prefix == self.p2pkh_prefix => data[0] = MAINNET_P2PKH_PREFIX,
+                    Some(prefix) if *prefix == self.p2sh_prefix => data[0] = MAINNET_P2SH_PREFIX,
+                    _ => return Err(self.wrong_network_or_invalid(address)),
+                }
+                base58::check_encode_slice(&data)
+            }
+        };
+        let mut parsed = Address::from_str(&mainnet_address).map_err(|e| invalid(e.to_string()))?;
+        parsed.network = self.network;
+        Ok(parsed)
+    }
+
+    /// Returns [Error::WrongNetwork] if the address is valid on one of the standard networks, [Error::InvalidAddress] otherwise
+    fn wrong_network_or_invalid(&self, address: &str) -> Error {
+        match Address::from_str(address) {
+            Ok(_) => Error::WrongNetwork {
+                address: address.to_string(),
+                network: self.network,
+            },
+            Err(e) => Error::InvalidAddress(format!("{}: {}", address, e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTNET_ADDRESS: &str = "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx";
+    const TESTNET_LEGACY_ADDRESS: &str = "mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn";
+
+    #[test]
+    fn test_standard_networks() -> Result<(), Error> {
+        let signet = NetworkParams::from(Network::Signet);
+        assert_eq!(signet.coin_type, 1);
+        let address = signet.parse_address(TESTNET_ADDRESS)?;
+        assert_eq!(address.network, Network::Signet);
+        assert_eq!(signet.address_to_string(&address), TESTNET_ADDRESS);
+        assert_eq!(
+            NetworkParams::from(Network::Regtest)
+                .parse_address(TESTNET_LEGACY_ADDRESS)?
+                .to_string(),
+            TESTNET_LEGACY_ADDRESS
+        );
+        assert!(matches!(
+            NetworkParams::from(Network::Regtest).parse_address(TESTNET_ADDRESS),
+            Err(Error::WrongNetwork { .. })
+        ));
+        assert!(matches!(
+            NetworkParams::from(Network::Bitcoin).parse_address(TESTNET_LEGACY_ADDRESS),
+            Err(Error::WrongNetwork { .. })
+        ));
+        assert_eq!(NetworkParams::default().coin_type, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_network() -> Result<(), Error> {
+        let params = NetworkParams {
+            bech32_hrp: "rt".to_string(),
+            p2pkh_prefix: 0x3c,
+            ..NetworkParams::from(Network::Regtest)
+        };
+        let address =
+            NetworkParams::from(Network::Regtest).parse_address(TESTNET_LEGACY_ADDRESS)?;
+        let encoded = params.address_to_string(&address);
+        assert!(encoded.starts_with('R'));
+        assert_eq!(params.parse_address(&encoded)?, address);
+
+        let segwit_address =
+            NetworkParams::from(Network::Testnet).parse_address(TESTNET_ADDRESS)?;
+        let encoded = params.address_to_string(&segwit_address);
+        assert!(encoded.starts_with("rt1q"));
+        let parsed = params.parse_address(&encoded)?;
+        assert_eq!(parsed.payload, segwit_address.payload);
+        assert_eq!(parsed.network, Network::Regtest);
+
+        assert!(matches!(
+            params.parse_address(TESTNET_LEGACY_ADDRESS),
+            Err(Error::WrongNetwork { .. })
+        ));
+        assert!(matches!(
+            params.parse_address("rt1qnotanaddress"),
+            Err(Error::InvalidAddress(_))
+        ));
+        Ok(())
+    }
+}
@@ -5,4 +5,10 @@
 //! use walletd_bitcoin::prelude::*;
 //! ```
 
-pub use crate::{BitcoinWallet, BitcoinWalletBuilder};
+pub use crate::psbt::Psbt;
+pub use crate::{
+    BitcoinAmount, BitcoinBalance, BitcoinBlockchainConfig, BitcoinDatabaseConfig,
+    BitcoinDenomination, BitcoinTransaction, BitcoinWallet, BitcoinWalletBuilder,
+    DiscoveredAccount, FeePolicy, MessageSignatureFormat, MultisigWalletBuilder, NetworkParams,
+    PaymentUri, SilentPayment, SilentPaymentAddress, TransactionDirection, TransactionQuery, Utxo,
+};
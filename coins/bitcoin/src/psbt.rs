@@ -0,0 +1,137 @@
+//! Helpers to import and export Partially Signed Bitcoin Transactions ([BIP174](https://github.com/bitcoin/bips/blob/master/bip-0174.mediawiki)).
+//!
+//! A PSBT created with [`BitcoinWallet::create_psbt`][crate::BitcoinWallet::create_psbt] can be exported as base64 or as raw bytes, moved to another machine (for example an air-gapped signer), signed there and imported back.
+//! The PSBTs of a multisig transaction signed separately by each cosigner are merged with [combine].
+
+use crate::Error;
+use bdk::bitcoin::consensus::encode::{deserialize, serialize};
+pub use bdk::bitcoin::util::psbt::PartiallySignedTransaction as Psbt;
+use std::str::FromStr;
+
+/// Exports the PSBT as a base64 string
+pub fn to_base64(psbt: &Psbt) -> String {
+    psbt.to_string()
+}
+
+/// Imports a PSBT from a base64 string
+pub fn from_base64(psbt_base64: &str) -> Result<Psbt, Error> {
+    Psbt::from_str(psbt_base64.trim()).map_err(|e| Error::Psbt(e.to_string()))
+}
+
+/// Exports the PSBT in its binary format
+pub fn to_bytes(psbt: &Psbt) -> Vec<u8> {
+    serialize(psbt)
+}
+
+/// Imports a PSBT from its binary format
+pub fn from_bytes(psbt_bytes: &[u8]) -> Result<Psbt, Error> {
+    deserialize(psbt_bytes).map_err(|e| Error::Psbt(e.to_string()))
+}
+
+/// Returns true if every input of the PSBT has a final script signature or witness, meaning the transaction can be extracted and broadcast
+pub fn is_finalized(psbt: &Psbt) -> bool {
+    psbt.inputs
+        .iter()
+        .all(|input| input.final_script_sig.is_some() || input.final_script_witness.is_some())
+}
+
+/// Combines PSBTs of the same transaction, for example signed by different cosigners of a multisig wallet, into one PSBT holding all of their signatures (the BIP174 combiner role)
+///
+/// Returns an [error][Error] if no PSBT is given or if the PSBTs are not for the same transaction.
+pub fn combine(psbts: &[Psbt]) -> Result<Psbt, Error> {
+    let (first, others) = psbts
+        .split_first()
+        .ok_or_else(|| Error::Psbt("No PSBT to combine".to_string()))?;
+    let mut combined = first.clone();
+    for psbt in others {
+        combined
+            .combine(psbt.clone())
+            .map_err(|e| Error::Psbt(e.to_string()))?;
+    }
+    Ok(combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk::bitcoin::util::psbt::raw;
+    use bdk::bitcoin::{OutPoint, PackedLockTime, Script, Transaction, TxIn, TxOut};
+
+    fn unsigned_psbt() -> Psbt {
+        let unsigned_tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime(0),
+            input: vec![TxIn {
+                previous_output: OutPoint::from_str(
+                    "f61b1742ca13176464adb3cb66050c00787bb3a4eead37e985f2df1e37718126:0",
+                )
+                .unwrap(),
+                ..Default::default()
+            }],
+            output: vec![TxOut {
+                value: 99_000,
+                script_pubkey: Script::new_op_return(b"walletd"),
+            }],
+        };
+        Psbt::from_unsigned_tx(unsigned_tx).unwrap()
+    }
+
+    #[test]
+    fn test_base64_round_trip() -> Result<(), Error> {
+        let psbt = unsigned_psbt();
+        let exported = to_base64(&psbt);
+        assert!(exported.starts_with("cHNidP8"));
+        assert_eq!(from_base64(&exported)?, psbt);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_round_trip() -> Result<(), Error> {
+        let psbt = unsigned_psbt();
+        let exported = to_bytes(&psbt);
+        assert_eq!(&exported[..5], b"psbt\xff");
+        assert_eq!(from_bytes(&exported)?, psbt);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_psbt() {
+        assert!(matches!(from_base64("not a psbt"), Err(Error::Psbt(_))));
+        assert!(matches!(from_bytes(&[0u8; 4]), Err(Error::Psbt(_))));
+    }
+
+    #[test]
+    fn test_unsigned_psbt_is_not_finalized() {
+        assert!(!is_finalized(&unsigned_psbt()));
+    }
+
+    #[test]
+    fn test_combine() -> Result<(), Error> {
+        // the unknown fields stand in for the signatures added by each cosigner
+        let mut first = unsigned_psbt();
+        let first_key = raw::Key {
+            type_value: 0xf0,
+            key: vec![1],
+        };
+        first.inputs[0].unknown.insert(first_key.clone(), vec![1]);
+        let mut second = unsigned_psbt();
+        let second_key = raw::Key {
+            type_value: 0xf0,
+            key: vec![2],
+        };
+        second.inputs[0].unknown.insert(second_key.clone(), vec![2]);
+
+        let combined = combine(&[first, second])?;
+        assert!(combined.inputs[0].unknown.contains_key(&first_key));
+        assert!(combined.inputs[0].unknown.contains_key(&second_key));
+
+        let mut other_tx = unsigned_psbt();
+        other_tx.unsigned_tx.output[0].value = 50_000;
+        assert!(matches!(
+            combine(&[unsigned_psbt(), other_tx]),
+            Err(Error::Psbt(_))
+        ));
+        assert!(matches!(combine(&[]), Err(Error::Psbt(_))));
+        Ok(())
+    }
+}
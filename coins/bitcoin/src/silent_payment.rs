@@ -0,0 +1,662 @@
+//! Provides [BIP352](https://github.com/bitcoin/bips/blob/master/bip-0352.mediawiki) silent payments, which let a wallet publish a single static address while every payment to it lands on a fresh taproot output.
+//!
+//! The sender derives the output key from the private keys of its inputs and the public keys of the [address][SilentPaymentAddress].
+//! The recipient finds its outputs by combining its scan key with the [tweak data][tweak_data] of the transaction inputs, see [`BitcoinWallet::scan_silent_payments`][crate::BitcoinWallet::scan_silent_payments].
+
+use crate::Error;
+use bdk::bitcoin::bech32::{self, FromBase32, ToBase32, Variant};
+use bdk::bitcoin::blockdata::opcodes;
+use bdk::bitcoin::blockdata::script::{Builder, Instruction};
+use bdk::bitcoin::consensus::encode;
+use bdk::bitcoin::hashes::{hash160, sha256, Hash, HashEngine};
+use bdk::bitcoin::secp256k1::{
+    Parity, PublicKey, Scalar, Secp256k1, SecretKey, Signing, Verification, XOnlyPublicKey,
+};
+use bdk::bitcoin::util::bip32::{DerivationPath, ExtendedPrivKey};
+use bdk::bitcoin::{Network, OutPoint, Script, Transaction, TxIn};
+use std::fmt;
+use std::str::FromStr;
+
+/// The human-readable part of silent payment addresses on [Network::Bitcoin]
+const MAINNET_HRP: &str = "sp";
+/// The human-readable part of silent payment addresses on the test networks
+const TESTNET_HRP: &str = "tsp";
+/// The label reserved for the change of the wallet, which is always scanned for
+pub const CHANGE_LABEL: u32 = 0;
+/// The purpose of the derivation path of the scan and spend keys
+const PURPOSE: u32 = 352;
+/// The x coordinate of the NUMS point H used as internal key of taproot outputs without a key path, inputs spending them are skipped
+const NUMS_H: [u8; 32] = [
+    0x50, 0x92, 0x9b, 0x74, 0xc1, 0xa0, 0x49, 0x54, 0xb7, 0x8b, 0x4b, 0x60, 0x35, 0xe9, 0x7a, 0x5e,
+    0x07, 0x8a, 0x5a, 0x0f, 0x28, 0xec, 0x96, 0xd5, 0x47, 0xbf, 0xee, 0x9a, 0xce, 0x80, 0x3a, 0xc0,
+];
+
+/// A silent payment address, the bech32m encoding of a scan public key and a spend public key
+///
+/// Parsed with [FromStr] and built with [Display][fmt::Display], addresses start with `sp1` on [Network::Bitcoin] and `tsp1` on the test networks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SilentPaymentAddress {
+    /// The public key the sender derives the shared secret with
+    pub scan_key: PublicKey,
+    /// The public key the outputs are derived from, which includes the label of a labeled address
+    pub spend_key: PublicKey,
+    /// The network of the address, [Network::Testnet] stands for all the test networks
+    pub network: Network,
+}
+
+impl SilentPaymentAddress {
+    /// Returns true if the address can be paid on the `network`, `tsp1` addresses are valid on all the test networks
+    pub fn is_valid_for_network(&self, network: Network) -> bool {
+        (self.network == Network::Bitcoin) == (network == Network::Bitcoin)
+    }
+}
+
+impl FromStr for SilentPaymentAddress {
+    type Err = Error;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| Error::InvalidAddress(format!("{}: {}", address, reason));
+        let (hrp, data, variant) = bech32::decode(address).map_err(|e| invalid(&e.to_string()))?;
+        let network = match hrp.as_str() {
+            MAINNET_HRP => Network::Bitcoin,
+            TESTNET_HRP => Network::Testnet,
+            _ => return Err(invalid("not a silent payment address")),
+        };
+        if variant != Variant::Bech32m {
+            return Err(invalid("silent payment addresses are encoded with bech32m"));
+        }
+        let (version, payload) = data
+            .split_first()
+            .ok_or_else(|| invalid("missing version"))?;
+        let payload = Vec::<u8>::from_base32(payload).map_err(|e| invalid(&e.to_string()))?;
+        // version 0 has a payload of exactly 66 bytes, future versions may append data after it
+        let payload = match version.to_u8() {
+            0 if payload.len() == 66 => &payload[..],
+            1..=30 if payload.len() >= 66 => &payload[..66],
+            31 => return Err(invalid("unsupported version 31")),
+            _ => return Err(invalid("invalid payload length")),
+        };
+        let key = |bytes: &[u8]| PublicKey::from_slice(bytes).map_err(|e| invalid(&e.to_string()));
+        Ok(Self {
+            scan_key: key(&payload[..33])?,
+            spend_key: key(&payload[33..])?,
+            network,
+        })
+    }
+}
+
+impl fmt::Display for SilentPaymentAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hrp = match self.network {
+            Network::Bitcoin => MAINNET_HRP,
+            _ => TESTNET_HRP,
+        };
+        let mut payload = self.scan_key.serialize().to_vec();
+        payload.extend_from_slice(&self.spend_key.serialize());
+        let mut data = vec![bech32::u5::try_from_u8(0).map_err(|_| fmt::Error)?];
+        data.extend(payload.to_base32());
+        let address = bech32::encode(hrp, data, Variant::Bech32m).map_err(|_| fmt::Error)?;
+        f.write_str(&address)
+    }
+}
+
+/// An output of a transaction paying the wallet, found by scanning for silent payments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SilentPayment {
+    /// The taproot output key of the output
+    pub output_key: XOnlyPublicKey,
+    /// The tweak added to the spend private key to get the private key of the output key
+    pub tweak: SecretKey,
+    /// The label of the address paid, None for the unlabeled address
+    pub label: Option<u32>,
+}
+
+/// The scan and spend private keys of a wallet receiving silent payments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SilentPaymentKeys {
+    scan_secret: SecretKey,
+    spend_secret: SecretKey,
+    network: Network,
+}
+
+impl SilentPaymentKeys {
+    /// Returns the keys of the account derived from the master private key, the scan key at m/352'/coin_type'/account'/1'/0 and the spend key at m/352'/coin_type'/account'/0'/0
+    pub(crate) fn derive<C: Signing>(
+        secp: &Secp256k1<C>,
+        master_xprv: &ExtendedPrivKey,
+        coin_type: u32,
+        account_index: u32,
+    ) -> Result<Self, Error> {
+        let derive = |branch: u32| -> Result<SecretKey, Error> {
+            let path = DerivationPath::from_str(&format!(
+                "m/{}'/{}'/{}'/{}'/0",
+                PURPOSE, coin_type, account_index, branch
+            ))
+            .map_err(|e| Error::UnableToImportWallet(e.to_string()))?;
+            Ok(master_xprv
+                .derive_priv(secp, &path)
+                .map_err(|e| Error::UnableToImportWallet(e.to_string()))?
+                .private_key)
+        };
+        Ok(Self {
+            scan_secret: derive(1)?,
+            spend_secret: derive(0)?,
+            network: master_xprv.network,
+        })
+    }
+
+    /// Returns the unlabeled address
+    pub(crate) fn address<C: Signing>(&self, secp: &Secp256k1<C>) -> SilentPaymentAddress {
+        SilentPaymentAddress {
+            scan_key: self.scan_secret.public_key(secp),
+            spend_key: self.spend_secret.public_key(secp),
+            network: match self.network {
+                Network::Bitcoin => Network::Bitcoin,
+                _ => Network::Testnet,
+            },
+        }
+    }
+
+    /// Returns the address with the `label`, which the wallet needs to scan for to find the payments to it
+    pub(crate) fn labeled_address<C: Signing + Verification>(
+        &self,
+        secp: &Secp256k1<C>,
+        label: u32,
+    ) -> Result<SilentPaymentAddress, Error> {
+        let mut address = self.address(secp);
+        address.spend_key = address
+            .spend_key
+            .add_exp_tweak(secp, &Scalar::from(self.label_tweak(label)?))
+            .map_err(|e| Error::InvalidAddress(e.to_string()))?;
+        Ok(address)
+    }
+
+    /// Returns the outputs paying the wallet among the taproot `output_keys` of a transaction with the `tweak_data`, checking the outputs for the `labels` and the [change label][CHANGE_LABEL]
+    pub(crate) fn scan<C: Signing + Verification>(
+        &self,
+        secp: &Secp256k1<C>,
+        tweak_data: &PublicKey,
+        output_keys: &[XOnlyPublicKey],
+        labels: &[u32],
+    ) -> Result<Vec<SilentPayment>, Error> {
+        let shared_secret = tweak_data
+            .mul_tweak(secp, &Scalar::from(self.scan_secret))
+            .map_err(|e| Error::MissingData(e.to_string()))?;
+        let spend_key = self.spend_secret.public_key(secp);
+        let label_tweaks = std::iter::once(&CHANGE_LABEL)
+            .chain(labels.iter().filter(|label| **label != CHANGE_LABEL))
+            .map(|label| {
+                let tweak = self.label_tweak(*label)?;
+                Ok((tweak.public_key(secp), (*label, tweak)))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut remaining = output_keys.to_vec();
+        let mut payments = Vec::new();
+        // the outputs to the same recipient are numbered by k, the scan stops at the first k without an output
+        for k in 0u32.. {
+            let tweak = shared_secret_tweak(&shared_secret, k)?;
+            let candidate = spend_key
+                .add_exp_tweak(secp, &Scalar::from(tweak))
+                .map_err(|e| Error::MissingData(e.to_string()))?;
+            let found = remaining
+                .iter()
+                .enumerate()
+                .find_map(|(index, output_key)| {
+                    if candidate.x_only_public_key().0 == *output_key {
+                        return Some((index, tweak, None));
+                    }
+                    // the difference between a labeled output and the candidate is the label point, for either parity of the output
+                    let output = PublicKey::from_x_only_public_key(*output_key, Parity::Even);
+                    [output, output.negate(secp)].iter().find_map(|output| {
+                        let label_point = output.combine(&candidate.negate(secp)).ok()?;
+                        let (_, (label, label_tweak)) = label_tweaks
+                            .iter()
+                            .find(|(point, _)| *point == label_point)?;
+                        let tweak = tweak.add_tweak(&Scalar::from(*label_tweak)).ok()?;
+                        Some((index, tweak, Some(*label)))
+                    })
+                });
+            match found {
+                Some((index, tweak, label)) => payments.push(SilentPayment {
+                    output_key: remaining.remove(index),
+                    tweak,
+                    label,
+                }),
+                None => break,
+            }
+        }
+        Ok(payments)
+    }
+
+    /// Returns the tweak of the spend key for the `label`
+    fn label_tweak(&self, label: u32) -> Result<SecretKey, Error> {
+        let hash = tagged_hash(
+            b"BIP0352/Label",
+            &[&self.scan_secret.secret_bytes(), &label.to_be_bytes()],
+        );
+        SecretKey::from_slice(&hash).map_err(|e| Error::MissingData(e.to_string()))
+    }
+}
+
+/// Returns the tweak data of a transaction, the sum of the public keys of its eligible inputs multiplied by the input hash, which the recipients need to scan its outputs
+///
+/// The `prevouts` are the script pubkeys of the outputs spent by the inputs of the `transaction`, in the same order.
+/// Returns None if no input of the transaction is eligible for silent payments, only P2PKH, P2SH-P2WPKH, P2WPKH and P2TR inputs with compressed keys are.
+pub fn tweak_data(
+    transaction: &Transaction,
+    prevouts: &[Script],
+) -> Result<Option<PublicKey>, Error> {
+    if transaction.input.len() != prevouts.len() {
+        return Err(Error::MissingData(format!(
+            "Expected {} prevouts, got {}",
+            transaction.input.len(),
+            prevouts.len()
+        )));
+    }
+    let public_keys: Vec<PublicKey> = transaction
+        .input
+        .iter()
+        .zip(prevouts)
+        .filter_map(|(input, prevout)| input_public_key(input, prevout))
+        .collect();
+    if public_keys.is_empty() {
+        return Ok(None);
+    }
+    // the keys of the inputs can cancel out, such a transaction has no silent payments
+    let Ok(public_key) = PublicKey::combine_keys(&public_keys.iter().collect::<Vec<_>>()) else {
+        return Ok(None);
+    };
+    let outpoints: Vec<OutPoint> = transaction
+        .input
+        .iter()
+        .map(|input| input.previous_output)
+        .collect();
+    let input_hash = input_hash(&outpoints, &public_key)?;
+    let tweak_data = public_key
+        .mul_tweak(&Secp256k1::verification_only(), &input_hash)
+        .map_err(|e| Error::MissingData(e.to_string()))?;
+    Ok(Some(tweak_data))
+}
+
+/// Returns the output keys paying the `recipients`, in the same order, for a transaction spending the `outpoints`
+///
+/// The `input_keys` are the private keys of the inputs eligible for silent payments, flagged true for the tweaked keys of taproot inputs.
+pub(crate) fn output_keys<C: Signing + Verification>(
+    secp: &Secp256k1<C>,
+    input_keys: &[(SecretKey, bool)],
+    outpoints: &[OutPoint],
+    recipients: &[SilentPaymentAddress],
+) -> Result<Vec<XOnlyPublicKey>, Error> {
+    let mut keys = input_keys.iter().map(|(secret_key, is_taproot)| {
+        // taproot keys are committed to with an even y coordinate
+        if *is_taproot && secret_key.public_key(secp).x_only_public_key().1 == Parity::Odd {
+            secret_key.negate()
+        } else {
+            *secret_key
+        }
+    });
+    let no_eligible_input = || {
+        Error::MissingData(
+            "No input of the transaction is eligible for silent payments".to_string(),
+        )
+    };
+    let first = keys.next().ok_or_else(no_eligible_input)?;
+    let secret_key = keys
+        .try_fold(first, |sum, key| sum.add_tweak(&Scalar::from(key)))
+        .map_err(|_| no_eligible_input())?;
+    let input_hash = input_hash(outpoints, &secret_key.public_key(secp))?;
+    let secret_key = secret_key
+        .mul_tweak(&input_hash)
+        .map_err(|e| Error::MissingData(e.to_string()))?;
+
+    let mut output_keys = vec![None; recipients.len()];
+    // the outputs to the recipients sharing a scan key are numbered by k
+    let mut scan_keys: Vec<PublicKey> = Vec::new();
+    for recipient in recipients {
+        if !scan_keys.contains(&recipient.scan_key) {
+            scan_keys.push(recipient.scan_key);
+        }
+    }
+    for scan_key in scan_keys {
+        let shared_secret = scan_key
+            .mul_tweak(secp, &Scalar::from(secret_key))
+            .map_err(|e| Error::MissingData(e.to_string()))?;
+        let group = recipients
+            .iter()
+            .enumerate()
+            .filter(|(_, recipient)| recipient.scan_key == scan_key);
+        for (k, (index, recipient)) in (0u32..).zip(group) {
+            let tweak = shared_secret_tweak(&shared_secret, k)?;
+            let output_key = recipient
+                .spend_key
+                .add_exp_tweak(secp, &Scalar::from(tweak))
+                .map_err(|e| Error::MissingData(e.to_string()))?;
+            output_keys[index] = Some(output_key.x_only_public_key().0);
+        }
+    }
+    Ok(output_keys.into_iter().flatten().collect())
+}
+
+/// Returns true if the address looks like a silent payment address rather than a regular address
+pub(crate) fn is_silent_payment_address(address: &str) -> bool {
+    let address = address.to_lowercase();
+    address.starts_with("sp1") || address.starts_with("tsp1")
+}
+
+/// Returns a P2TR script pubkey standing in for the output of the `index`-th silent payment recipient until the inputs of the transaction are selected
+pub(crate) fn placeholder_script(index: usize) -> Script {
+    let mut output_key = [0u8; 32];
+    output_key[24..].copy_from_slice(&(index as u64).to_be_bytes());
+    Builder::new()
+        .push_opcode(opcodes::all::OP_PUSHNUM_1)
+        .push_slice(&output_key)
+        .into_script()
+}
+
+/// Returns the public key of an input eligible for silent payments, taproot keys are returned with an even y coordinate
+fn input_public_key(input: &TxIn, prevout: &Script) -> Option<PublicKey> {
+    let compressed_key = |bytes: &[u8]| match bytes.len() {
+        33 => PublicKey::from_slice(bytes).ok(),
+        _ => None,
+    };
+    if prevout.is_p2pkh() {
+        // the key is the last 33 bytes of the script sig hashing to the key hash, which also covers malleated script sigs
+        let key_hash = &prevout.as_bytes()[3..23];
+        let script_sig = input.script_sig.as_bytes();
+        (33..=script_sig.len())
+            .rev()
+            .map(|end| &script_sig[end - 33..end])
+            .find(|bytes| hash160::Hash::hash(bytes).as_inner() == key_hash)
+            .and_then(compressed_key)
+    } else if prevout.is_p2sh() {
+        // only P2SH-P2WPKH, whose script sig is a single push of the witness program
+        let mut instructions = input.script_sig.instructions();
+        match (instructions.next(), instructions.next()) {
+            (Some(Ok(Instruction::PushBytes(redeem_script))), None)
+                if Script::from(redeem_script.to_vec()).is_v0_p2wpkh() =>
+            {
+                input.witness.last().and_then(compressed_key)
+            }
+            _ => None,
+        }
+    } else if prevout.is_v0_p2wpkh() {
+        input.witness.last().and_then(compressed_key)
+    } else if prevout.is_v1_p2tr() {
+        let mut stack: Vec<&[u8]> = input.witness.iter().collect();
+        if stack.len() > 1 && stack.last().is_some_and(|item| item.first() == Some(&0x50)) {
+            // drops the annex
+            stack.pop();
+        }
+        if stack.is_empty() {
+            return None;
+        }
+        if stack.len() > 1 {
+            // a script path spend of an output without key path
+            let control_block = stack.last()?;
+            if control_block.get(1..33) == Some(&NUMS_H[..]) {
+                return None;
+            }
+        }
+        let output_key = XOnlyPublicKey::from_slice(&prevout.as_bytes()[2..]).ok()?;
+        Some(PublicKey::from_x_only_public_key(output_key, Parity::Even))
+    } else {
+        None
+    }
+}
+
+/// Returns the input hash committing to the smallest outpoint of the transaction and the sum of the input public keys
+fn input_hash(outpoints: &[OutPoint], public_key: &PublicKey) -> Result<Scalar, Error> {
+    let smallest_outpoint = outpoints
+        .iter()
+        .map(encode::serialize)
+        .min()
+        .ok_or_else(|| Error::MissingData("The transaction has no inputs".to_string()))?;
+    let hash = tagged_hash(
+        b"BIP0352/Inputs",
+        &[&smallest_outpoint, &public_key.serialize()],
+    );
+    Scalar::from_be_bytes(hash).map_err(|e| Error::MissingData(e.to_string()))
+}
+
+/// Returns the tweak of the k-th output to the recipient of the shared secret
+fn shared_secret_tweak(shared_secret: &PublicKey, k: u32) -> Result<SecretKey, Error> {
+    let hash = tagged_hash(
+        b"BIP0352/SharedSecret",
+        &[&shared_secret.serialize(), &k.to_be_bytes()],
+    );
+    SecretKey::from_slice(&hash).map_err(|e| Error::MissingData(e.to_string()))
+}
+
+/// Returns the BIP340 tagged hash of the concatenated data
+fn tagged_hash(tag: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let tag = sha256::Hash::hash(tag);
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag);
+    engine.input(&tag);
+    for data in data {
+        engine.input(data);
+    }
+    sha256::Hash::from_engine(engine).into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk::bitcoin::hashes::hex::{FromHex, ToHex};
+    use bdk::bitcoin::{PackedLockTime, Txid, Witness};
+    use serde_json::Value;
+
+    /// The official test vectors of BIP352
+    const TEST_VECTORS: &str =
+        include_str!("../tests/data/bip352_send_and_receive_test_vectors.json");
+
+    fn test_vectors() -> Vec<Value> {
+        serde_json::from_str::<Value>(TEST_VECTORS)
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .clone()
+    }
+
+    fn hex(value: &Value) -> Vec<u8> {
+        Vec::from_hex(value.as_str().unwrap()).unwrap()
+    }
+
+    fn strings(value: &Value) -> Vec<String> {
+        value
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    /// Returns the transaction spending the inputs of a test vector along with the script pubkeys of their prevouts
+    fn transaction(vin: &Value) -> (Transaction, Vec<Script>) {
+        let mut inputs = Vec::new();
+        let mut prevouts = Vec::new();
+        for input in vin.as_array().unwrap() {
+            let witness = hex(&input["txinwitness"]);
+            inputs.push(TxIn {
+                previous_output: OutPoint::new(
+                    Txid::from_str(input["txid"].as_str().unwrap()).unwrap(),
+                    input["vout"].as_u64().unwrap() as u32,
+                ),
+                script_sig: Script::from(hex(&input["scriptSig"])),
+                witness: match witness.is_empty() {
+                    true => Witness::default(),
+                    false => encode::deserialize(&witness).unwrap(),
+                },
+                ..Default::default()
+            });
+            prevouts.push(Script::from(hex(&input["prevout"]["scriptPubKey"]["hex"])));
+        }
+        let transaction = Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: inputs,
+            output: Vec::new(),
+        };
+        (transaction, prevouts)
+    }
+
+    #[test]
+    fn test_sending_vectors() {
+        let secp = Secp256k1::new();
+        for vector in test_vectors() {
+            for sending in vector["sending"].as_array().unwrap() {
+                let given = &sending["given"];
+                let (transaction, prevouts) = transaction(&given["vin"]);
+                // the sender only uses the keys of the eligible inputs
+                let input_keys: Vec<(SecretKey, bool)> = transaction
+                    .input
+                    .iter()
+                    .zip(&prevouts)
+                    .zip(given["vin"].as_array().unwrap())
+                    .filter(|((input, prevout), _)| input_public_key(input, prevout).is_some())
+                    .map(|((_, prevout), vin)| {
+                        let secret_key = SecretKey::from_slice(&hex(&vin["private_key"])).unwrap();
+                        (secret_key, prevout.is_v1_p2tr())
+                    })
+                    .collect();
+                let outpoints: Vec<OutPoint> = transaction
+                    .input
+                    .iter()
+                    .map(|input| input.previous_output)
+                    .collect();
+                let recipients: Vec<SilentPaymentAddress> = strings(&given["recipients"])
+                    .iter()
+                    .map(|address| SilentPaymentAddress::from_str(address).unwrap())
+                    .collect();
+
+                let outputs: Vec<String> =
+                    match output_keys(&secp, &input_keys, &outpoints, &recipients) {
+                        Ok(output_keys) => output_keys.iter().map(|key| key.to_string()).collect(),
+                        Err(_) => Vec::new(),
+                    };
+                // the expected outputs list every valid output when the order of the recipients is ambiguous
+                let expected = &sending["expected"];
+                assert_eq!(
+                    outputs.len() as u64,
+                    expected["n_outputs"].as_u64().unwrap()
+                );
+                let valid = strings(&expected["outputs"]);
+                assert!(
+                    outputs.iter().all(|output| valid.contains(output)),
+                    "{}",
+                    vector["comment"]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_receiving_vectors() {
+        let secp = Secp256k1::new();
+        for vector in test_vectors() {
+            for receiving in vector["receiving"].as_array().unwrap() {
+                let given = &receiving["given"];
+                let expected = &receiving["expected"];
+                let secret_key =
+                    |name: &str| SecretKey::from_slice(&hex(&given["key_material"][name])).unwrap();
+                let keys = SilentPaymentKeys {
+                    scan_secret: secret_key("scan_priv_key"),
+                    spend_secret: secret_key("spend_priv_key"),
+                    network: Network::Bitcoin,
+                };
+                let labels: Vec<u32> = given["labels"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|label| label.as_u64().unwrap() as u32)
+                    .collect();
+
+                let mut addresses = vec![keys.address(&secp).to_string()];
+                for label in &labels {
+                    addresses.push(keys.labeled_address(&secp, *label).unwrap().to_string());
+                }
+                assert_eq!(addresses, strings(&expected["addresses"]));
+
+                let (transaction, prevouts) = transaction(&given["vin"]);
+                let output_keys: Vec<XOnlyPublicKey> = strings(&given["outputs"])
+                    .iter()
+                    .map(|key| XOnlyPublicKey::from_str(key).unwrap())
+                    .collect();
+                let payments = match tweak_data(&transaction, &prevouts).unwrap() {
+                    Some(tweak_data) => keys
+                        .scan(&secp, &tweak_data, &output_keys, &labels)
+                        .unwrap(),
+                    None => Vec::new(),
+                };
+                let found: Vec<(String, String)> = payments
+                    .iter()
+                    .map(|payment| {
+                        (
+                            payment.output_key.to_string(),
+                            payment.tweak.secret_bytes().to_hex(),
+                        )
+                    })
+                    .collect();
+                let valid: Vec<(String, String)> = expected["outputs"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|output| {
+                        (
+                            output["pub_key"].as_str().unwrap().to_string(),
+                            output["priv_key_tweak"].as_str().unwrap().to_string(),
+                        )
+                    })
+                    .collect();
+                assert_eq!(
+                    found.len() as u64,
+                    expected["n_outputs"].as_u64().unwrap(),
+                    "{}",
+                    vector["comment"]
+                );
+                assert!(
+                    found.iter().all(|payment| valid.contains(payment)),
+                    "{}",
+                    vector["comment"]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_address_encoding() -> Result<(), Error> {
+        let encoded = "sp1qqgste7k9hx0qftg6qmwlkqtwuy6cycyavzmzj85c6qdfhjdpdjtdgqjuexzk6murw56suy3e0rd2cgqvycxttddwsvgxe2usfpxumr70xc9pkqwv";
+        let address = SilentPaymentAddress::from_str(encoded)?;
+        assert_eq!(address.network, Network::Bitcoin);
+        assert_eq!(address.to_string(), encoded);
+        assert!(address.is_valid_for_network(Network::Bitcoin));
+        assert!(!address.is_valid_for_network(Network::Signet));
+
+        let testnet = SilentPaymentAddress {
+            network: Network::Regtest,
+            ..address
+        };
+        assert!(testnet.to_string().starts_with("tsp1"));
+        assert_eq!(
+            SilentPaymentAddress::from_str(&testnet.to_string())?.network,
+            Network::Testnet
+        );
+
+        for invalid in [
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+            &encoded[..encoded.len() - 1],
+            "sp1qqgste7k9hx0qftg6qmwlkqtwuy6cycyavzmzj85c6qdfhjdpdjtdgqjuexzk6murw56suy3e0rd2cgqvycxttddwsvgxe2usfpxumr70xc9pkqwq",
+        ] {
+            assert!(matches!(
+                SilentPaymentAddress::from_str(invalid),
+                Err(Error::InvalidAddress(_))
+            ));
+        }
+        assert!(is_silent_payment_address(encoded));
+        assert!(!is_silent_payment_address(
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::Error;
+use bdk::bitcoin::OutPoint;
+use bdk::KeychainKind;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// An unspent transaction output (UTXO) owned by a [BitcoinWallet][crate::BitcoinWallet]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utxo {
+    /// The reference to the output (txid and output index)
+    pub outpoint: OutPoint,
+    /// The value of the output in satoshi
+    pub value: u64,
+    /// Whether the output belongs to the external (receive) or the internal (change) keychain
+    pub keychain: KeychainKind,
+    /// The height of the block the output was confirmed in, None if it is unconfirmed
+    pub confirmation_height: Option<u32>,
+    /// Whether the output is frozen, frozen outputs are never selected as inputs of a transaction
+    pub is_frozen: bool,
+}
+
+/// The set of frozen outpoints of a wallet, persisted to a file next to the wallet database if it has one
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FrozenOutpoints {
+    outpoints: BTreeSet<OutPoint>,
+    path: Option<PathBuf>,
+}
+
+impl FrozenOutpoints {
+    /// Loads the frozen outpoints persisted at `path`, there are none if the file does not exist yet
+    ///
+    /// The file holds one outpoint per line in the `txid:vout` format
+    pub(crate) fn load(path: Option<PathBuf>) -> Result<Self, Error> {
+        let mut outpoints = BTreeSet::new();
+        if let Some(path) = path.as_ref().filter(|path| path.exists()) {
+            for line in fs::read_to_string(path)?.lines() {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let outpoint =
+                    OutPoint::from_str(line).map_err(|e| Error::FromStr(e.to_string()))?;
+                outpoints.insert(outpoint);
+            }
+        }
+        Ok(Self { outpoints, path })
+    }
+
+    /// Returns true if the outpoint is frozen
+    pub(crate) fn contains(&self, outpoint: &OutPoint) -> bool {
+        self.outpoints.contains(outpoint)
+    }
+
+    /// Returns the frozen outpoints
+    pub(crate) fn outpoints(&self) -> Vec<OutPoint> {
+        self.outpoints.iter().cloned().collect()
+    }
+
+    /// Freezes the outpoint, returns false if it was already frozen
+    pub(crate) fn insert(&mut self, outpoint: OutPoint) -> Result<bool, Error> {
+        let inserted = self.outpoints.insert(outpoint);
+        if inserted {
+            self.save()?;
+        }
+        Ok(inserted)
+    }
+
+    /// Unfreezes the outpoint, returns false if it was not frozen
+    pub(crate) fn remove(&mut self, outpoint: &OutPoint) -> Result<bool, Error> {
+        let removed = self.outpoints.remove(outpoint);
+        if removed {
+            self.save()?;
+        }
+        Ok(removed)
+    }
+
+    /// Writes the frozen outpoints to the file, if the wallet has a persistent database
+    ///
+    /// The outpoints are written to a temporary file which then replaces the file, so a crash while saving never leaves a truncated file.
+    fn save(&self) -> Result<(), Error> {
+        if let Some(path) = &self.path {
+            let contents: String = self
+                .outpoints
+                .iter()
+                .map(|outpoint| format!("{}\n", outpoint))
+                .collect();
+            let mut tmp_file_name = path.file_name().unwrap_or_default().to_os_string();
+            tmp_file_name.push(".tmp");
+            let tmp_path = path.with_file_name(tmp_file_name);
+            let mut file = fs::File::create(&tmp_path)?;
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()?;
+            fs::rename(&tmp_path, path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPOINT: &str = "5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456:1";
+
+    #[test]
+    fn test_frozen_outpoints_persist() -> Result<(), Error> {
+        let path = std::env::temp_dir().join(format!(
+            "walletd_bitcoin_test_frozen_{}.frozen",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        let outpoint = OutPoint::from_str(OUTPOINT).unwrap();
+
+        let mut frozen = FrozenOutpoints::load(Some(path.clone()))?;
+        assert!(!frozen.contains(&outpoint));
+        assert!(frozen.insert(outpoint)?);
+        assert!(!frozen.insert(outpoint)?);
+
+        let mut reloaded = FrozenOutpoints::load(Some(path.clone()))?;
+        assert!(reloaded.contains(&outpoint));
+        assert!(reloaded.remove(&outpoint)?);
+        assert!(!FrozenOutpoints::load(Some(path.clone()))?.contains(&outpoint));
+        // the temporary file was renamed over the file
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        assert!(!PathBuf::from(tmp_path).exists());
+
+        fs::remove_file(&path).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_frozen_outpoints_in_memory() -> Result<(), Error> {
+        let outpoint = OutPoint::from_str(OUTPOINT).unwrap();
+        let mut frozen = FrozenOutpoints::load(None)?;
+        assert!(frozen.insert(outpoint)?);
+        assert_eq!(frozen.outpoints(), vec![outpoint]);
+        assert!(frozen.remove(&outpoint)?);
+        assert!(!frozen.remove(&outpoint)?);
+        Ok(())
+    }
+}
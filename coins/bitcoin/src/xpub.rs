@@ -0,0 +1,187 @@
+use crate::Error;
+use bdk::bitcoin::util::base58;
+use bdk::bitcoin::util::bip32::ExtendedPubKey;
+use bdk::bitcoin::{AddressType, Network};
+
+/// The version bytes of xpub keys
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
+/// The version bytes of tpub keys
+const TPUB_VERSION: [u8; 4] = [0x04, 0x35, 0x87, 0xcf];
+
+/// The version bytes of the extended public key formats, along with whether they belong to the main network and the script kind they imply
+const VERSIONS: [([u8; 4], bool, ScriptKind); 10] = [
+    (XPUB_VERSION, true, ScriptKind::Unspecified),
+    ([0x04, 0x9d, 0x7c, 0xb2], true, ScriptKind::P2shP2wpkh),
+    ([0x04, 0xb2, 0x47, 0x46], true, ScriptKind::P2wpkh),
+    ([0x02, 0x95, 0xb4, 0x3f], true, ScriptKind::P2shP2wsh),
+    ([0x02, 0xaa, 0x7e, 0xd3], true, ScriptKind::P2wsh),
+    (TPUB_VERSION, false, ScriptKind::Unspecified),
+    ([0x04, 0x4a, 0x52, 0x62], false, ScriptKind::P2shP2wpkh),
+    ([0x04, 0x5f, 0x1c, 0xf6], false, ScriptKind::P2wpkh),
+    ([0x02, 0x42, 0x89, 0xef], false, ScriptKind::P2shP2wsh),
+    ([0x02, 0x57, 0x54, 0x83], false, ScriptKind::P2wsh),
+];
+
+/// The kind of script implied by the version of an extended public key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScriptKind {
+    /// xpub and tpub, which do not imply a script
+    Unspecified,
+    /// ypub and upub, single-sig P2SH-P2WPKH (BIP49)
+    P2shP2wpkh,
+    /// zpub and vpub, single-sig P2WPKH (BIP84)
+    P2wpkh,
+    /// Ypub and Upub, multisig P2SH-P2WSH (BIP48)
+    P2shP2wsh,
+    /// Zpub and Vpub, multisig P2WSH (BIP48)
+    P2wsh,
+}
+
+impl ScriptKind {
+    /// Returns whether the script kind is a multisig one
+    pub(crate) fn is_multisig(&self) -> bool {
+        matches!(self, Self::P2shP2wsh | Self::P2wsh)
+    }
+
+    /// Returns the address format of the script kind, None if it is unspecified
+    pub(crate) fn address_format(&self) -> Option<AddressType> {
+        match self {
+            Self::Unspecified => None,
+            Self::P2shP2wpkh | Self::P2shP2wsh => Some(AddressType::P2sh),
+            Self::P2wpkh => Some(AddressType::P2wpkh),
+            Self::P2wsh => Some(AddressType::P2wsh),
+        }
+    }
+}
+
+/// An account-level extended public key, optionally with its key origin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AccountXpub {
+    /// The key origin in the `[fingerprint/path]` format, lets a wallet holding the master private key sign the PSBTs created from this key
+    origin: Option<String>,
+    xpub: ExtendedPubKey,
+    /// The script kind implied by the version of the key
+    pub(crate) script_kind: ScriptKind,
+}
+
+impl AccountXpub {
+    /// Parses an xpub, ypub, zpub, Ypub or Zpub (or their testnet versions tpub, upub, vpub, Upub and Vpub), optionally preceded by its key origin such as `[73c5da0a/84'/0'/0']`
+    ///
+    /// Returns an [error][Error] if the key is invalid or does not belong to the `network`
+    pub(crate) fn parse(key: &str, network: Network) -> Result<Self, Error> {
+        let key = key.trim();
+        let (origin, encoded) = match key.strip_prefix('[') {
+            Some(rest) => {
+                let (origin, encoded) = rest.split_once(']').ok_or_else(|| {
+                    Error::FromStr(format!("Invalid key origin in extended public key {}", key))
+                })?;
+                (Some(format!("[{}]", origin)), encoded)
+            }
+            None => (None, key),
+        };
+        let mut data = base58::from_check(encoded).map_err(|e| Error::FromStr(e.to_string()))?;
+        if data.len() < 4 {
+            return Err(Error::FromStr(format!(
+                "Invalid extended public key {}",
+                encoded
+            )));
+        }
+        let (is_mainnet, script_kind) = VERSIONS
+            .iter()
+            .find(|(version, _, _)| data[..4] == version[..])
+            .map(|(_, is_mainnet, script_kind)| (*is_mainnet, *script_kind))
+            .ok_or_else(|| {
+                Error::FromStr(format!("Unknown extended public key version {}", encoded))
+            })?;
+        if is_mainnet != (network == Network::Bitcoin) {
+            return Err(Error::UnableToImportWallet(format!(
+                "Extended public key {} is not valid for network {}",
+                encoded, network
+            )));
+        }
+        // bip32 only knows the xpub and tpub versions
+        let xpub_version = if is_mainnet {
+            XPUB_VERSION
+        } else {
+            TPUB_VERSION
+        };
+        data[..4].copy_from_slice(&xpub_version);
+        let xpub = ExtendedPubKey::decode(&data).map_err(|e| Error::FromStr(e.to_string()))?;
+        Ok(Self {
+            origin,
+            xpub,
+            script_kind,
+        })
+    }
+
+    /// Returns the descriptor key deriving the addresses of the external (0) or internal (1) chain of the account
+    pub(crate) fn descriptor_key(&self, change: u32) -> String {
+        format!(
+            "{}{}/{}/*",
+            self.origin.as_deref().unwrap_or_default(),
+            self.xpub,
+            change
+        )
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    // BIP84 account 0 of the "abandon ... about" test mnemonic
+    const ZPUB: &str = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+    const XPUB: &str = "xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V";
+
+    /// Re-encodes an extended public key with other version bytes
+    pub(crate) fn with_version(key: &str, version: [u8; 4]) -> String {
+        let mut data = base58::from_check(key).unwrap();
+        data[..4].copy_from_slice(&version);
+        base58::check_encode_slice(&data)
+    }
+
+    #[test]
+    fn test_parse_zpub() -> Result<(), Error> {
+        let account = AccountXpub::parse(ZPUB, Network::Bitcoin)?;
+        assert_eq!(account.script_kind, ScriptKind::P2wpkh);
+        assert_eq!(
+            account.script_kind.address_format(),
+            Some(AddressType::P2wpkh)
+        );
+        assert_eq!(account.descriptor_key(0), format!("{}/0/*", XPUB));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_with_origin() -> Result<(), Error> {
+        let key = format!("[73c5da0a/84'/0'/0']{}", XPUB);
+        let account = AccountXpub::parse(&key, Network::Bitcoin)?;
+        assert_eq!(account.script_kind, ScriptKind::Unspecified);
+        assert_eq!(account.descriptor_key(1), format!("{}/1/*", key));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_multisig_versions() -> Result<(), Error> {
+        for (version, script_kind) in [
+            ([0x02, 0x95, 0xb4, 0x3f], ScriptKind::P2shP2wsh),
+            ([0x02, 0xaa, 0x7e, 0xd3], ScriptKind::P2wsh),
+        ] {
+            let account = AccountXpub::parse(&with_version(XPUB, version), Network::Bitcoin)?;
+            assert_eq!(account.script_kind, script_kind);
+            assert!(account.script_kind.is_multisig());
+            assert_eq!(account.descriptor_key(0), format!("{}/0/*", XPUB));
+        }
+        assert!(!ScriptKind::P2shP2wpkh.is_multisig());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_wrong_network() {
+        assert!(matches!(
+            AccountXpub::parse(ZPUB, Network::Testnet),
+            Err(Error::UnableToImportWallet(_))
+        ));
+        assert!(AccountXpub::parse("zpub123", Network::Bitcoin).is_err());
+    }
+}
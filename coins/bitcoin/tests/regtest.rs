@@ -0,0 +1,149 @@
+//! End-to-end tests of [BitcoinWallet] against a local `bitcoind -regtest` node, which run without any network access.
+//!
+//! Each test starts its own node from the executable in the `BITCOIND_EXE` environment variable or the `bitcoind` found in the `PATH`.
+//! The tests are ignored by default, run them with `cargo test --test regtest -- --ignored`; they fail when no executable is available.
+
+use bdk::bitcoin::{Address, Amount, Network};
+use bdk::blockchain::AnyBlockchain;
+use bdk::keys::bip39::Mnemonic;
+use bitcoind::bitcoincore_rpc::{Client, RpcApi};
+use bitcoind::BitcoinD;
+use std::str::FromStr;
+use walletd_bitcoin::prelude::*;
+use walletd_bitcoin::{BitcoinRpcAuth, Error};
+
+const MNEMONICS: [&str; 2] = [
+    "outer ride neither foil glue number place usage ball shed dry point",
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+];
+
+/// A regtest node with a funded wallet used to mine blocks and fund the tested wallets
+struct Regtest {
+    node: BitcoinD,
+    miner: Client,
+    mining_address: Address,
+}
+
+impl Regtest {
+    /// Starts a node and mines 101 blocks so the reward of the first block is spendable, panics if no bitcoind executable is available
+    fn start() -> Self {
+        let exe = bitcoind::exe_path().expect("no bitcoind executable found");
+        let node = BitcoinD::new(exe).unwrap();
+        let miner = node.create_wallet("miner").unwrap();
+        let mining_address = miner.get_new_address(None, None).unwrap();
+        let regtest = Self {
+            node,
+            miner,
+            mining_address,
+        };
+        regtest.mine(101);
+        regtest
+    }
+
+    /// Mines blocks confirming the transactions of the mempool
+    fn mine(&self, blocks: u64) {
+        self.miner
+            .generate_to_address(blocks, &self.mining_address)
+            .unwrap();
+    }
+
+    /// Sends `amount` satoshi from the mining wallet to the address
+    fn fund(&self, address: &str, amount: u64) {
+        self.miner
+            .send_to_address(
+                &Address::from_str(address).unwrap(),
+                Amount::from_sat(amount),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+    }
+
+    /// Returns a wallet built from one of the test mnemonics and the RPC backend syncing it through its own watch-only wallet of the node
+    fn wallet(&self, index: usize) -> Result<(BitcoinWallet, AnyBlockchain), Error> {
+        let wallet = BitcoinWallet::builder()
+            .mnemonic(Mnemonic::parse(MNEMONICS[index]).unwrap())
+            .network_type(Network::Regtest)
+            .build()?;
+        let blockchain = wallet.connect(&BitcoinBlockchainConfig::Rpc {
+            url: self.node.rpc_url(),
+            auth: BitcoinRpcAuth::Cookie(self.node.params.cookie_file.clone()),
+            wallet_name: format!("walletd-{}", index),
+        })?;
+        Ok((wallet, blockchain))
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires a bitcoind executable"]
+async fn test_sync_and_transfer() -> Result<(), Error> {
+    let regtest = Regtest::start();
+    let (mut sender, sender_blockchain) = regtest.wallet(0)?;
+    let (mut recipient, recipient_blockchain) = regtest.wallet(1)?;
+    regtest.fund(&sender.receive_address()?, 100_000);
+    regtest.mine(1);
+
+    sender.sync(&sender_blockchain).await?;
+    assert_eq!(sender.balance().await?.confirmed.sat(), 100_000);
+
+    let recipient_address = recipient.receive_address()?;
+    let details = sender
+        .transfer(
+            &sender_blockchain,
+            &[(&recipient_address, BitcoinAmount::from_sat(30_000))],
+            FeePolicy::FeeRate(2.0),
+        )
+        .await?;
+    regtest.mine(1);
+
+    recipient.sync(&recipient_blockchain).await?;
+    assert_eq!(recipient.balance().await?.confirmed.sat(), 30_000);
+    sender.sync(&sender_blockchain).await?;
+    let fee = details.fee.unwrap();
+    assert_eq!(sender.balance().await?.confirmed.sat(), 70_000 - fee);
+
+    let transactions = sender.transactions(&TransactionQuery::new())?;
+    assert_eq!(transactions.len(), 2);
+    assert_eq!(transactions[0].txid, details.txid);
+    assert_eq!(transactions[0].direction, TransactionDirection::Sent);
+    assert!(transactions[0].is_confirmed());
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires a bitcoind executable"]
+async fn test_bump_fee() -> Result<(), Error> {
+    let regtest = Regtest::start();
+    let (mut wallet, blockchain) = regtest.wallet(0)?;
+    regtest.fund(&wallet.receive_address()?, 100_000);
+    regtest.mine(1);
+    wallet.sync(&blockchain).await?;
+
+    let recipient = regtest.mining_address.to_string();
+    let original = wallet
+        .transfer(
+            &blockchain,
+            &[(&recipient, BitcoinAmount::from_sat(20_000))],
+            FeePolicy::FeeRate(1.0),
+        )
+        .await?;
+    wallet.sync(&blockchain).await?;
+    let replacement = wallet.bump_fee(&blockchain, &original.txid, 5.0).await?;
+    assert!(replacement.fee.unwrap() > original.fee.unwrap());
+    regtest.mine(1);
+
+    wallet.sync(&blockchain).await?;
+    assert!(wallet
+        .transactions(&TransactionQuery::new())?
+        .iter()
+        .any(|transaction| transaction.txid == replacement.txid && transaction.is_confirmed()));
+    assert_eq!(
+        wallet.balance().await?.confirmed.sat(),
+        80_000 - replacement.fee.unwrap()
+    );
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use walletd_hd_key::{HDNetworkType, Seed};
+
+/// Provides a common interface for the wallets of the different cryptocurrencies supported by walletD.
+///
+/// The futures returned by the async methods are not required to be [Send] as some of the underlying wallet implementations (such as the bdk wallet used for Bitcoin) are not [Sync].
+#[async_trait(?Send)]
+pub trait CryptoWallet: Sized {
+    /// The error type returned by the wallet
+    type ErrorType: std::error::Error + Send + Sync + 'static;
+    /// The type used to represent an amount of the cryptocurrency
+    type CryptoAmount;
+    /// The type used to specify the address format of the wallet
+    type AddressFormat;
+    /// The network type the wallet is associated with
+    type NetworkType;
+    /// The blockchain client used to connect the wallet to the blockchain
+    type BlockchainClient;
+    /// The builder used to create the wallet
+    type WalletBuilder: CryptoWalletBuilder<Self>;
+
+    /// Returns the builder for the wallet with the default options
+    fn builder() -> Self::WalletBuilder;
+
+    /// Returns the balance of the wallet
+    async fn balance(
+        &self,
+        blockchain_client: &Self::BlockchainClient,
+    ) -> Result<Self::CryptoAmount, Self::ErrorType>;
+
+    /// Returns the next receive address of the wallet as a string
+    fn receive_address(&self) -> Result<String, Self::ErrorType>;
+
+    /// Builds, signs and broadcasts a transaction sending `send_amount` to `to_address`, returns the transaction id (hash) as a string
+    async fn transfer(
+        &self,
+        blockchain_client: &Self::BlockchainClient,
+        send_amount: Self::CryptoAmount,
+        to_address: &str,
+    ) -> Result<String, Self::ErrorType>;
+
+    /// Syncs the wallet with the blockchain
+    async fn sync(
+        &mut self,
+        blockchain_client: &Self::BlockchainClient,
+    ) -> Result<(), Self::ErrorType>;
+
+    /// Returns the network the wallet is associated with
+    fn network(&self) -> Result<Self::NetworkType, Self::ErrorType>;
+
+    /// Returns the address format used by the wallet
+    fn address_format(&self) -> Self::AddressFormat;
+}
+
+/// Provides a common interface for building a [CryptoWallet] from a mnemonic seed.
+pub trait CryptoWalletBuilder<T: CryptoWallet>: Sized {
+    /// Returns a new builder with the default options
+    fn new() -> Self;
+
+    /// Specifies the mnemonic seed the wallet is derived from
+    fn mnemonic_seed(&mut self, mnemonic_seed: Seed) -> &mut Self;
+
+    /// Specifies the network type of the wallet using the [HDNetworkType]
+    ///
+    /// [HDNetworkType::MainNet] maps to the main network of the cryptocurrency and [HDNetworkType::TestNet] maps to its default test network,
+    /// [HDNetworkType::Signet] maps to the signet of the cryptocurrency or to its default test network if it has none
+    fn hd_network_type(&mut self, network_type: HDNetworkType) -> &mut Self;
+
+    /// Builds the wallet with the specified options
+    fn build(&self) -> Result<T, T::ErrorType>;
+}
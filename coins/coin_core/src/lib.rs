@@ -0,0 +1,34 @@
+//! # WalletD Coin Core
+//!
+//! Provides the common traits that each of the walletD coin libraries implements so that wallets for different cryptocurrencies can be handled through one interface.
+//!
+//! ## Quickstart Guide
+//!
+//! The [CryptoWallet] trait describes the functionality shared by every wallet: checking the balance, getting a receive address, sending a transfer, syncing with the blockchain and reporting the network.
+//! Each implementation specifies its own amount, address format, network and blockchain client types through associated types.
+//!
+//! The [CryptoWalletBuilder] trait lets a wallet be built generically from a [Seed] and a [HDNetworkType], which is how [`walletd::KeyPair`](https://docs.rs/walletd) derives wallets of any supported coin.
+//!
+//! ```
+//! use walletd_coin_core::prelude::*;
+//!
+//! async fn print_balance<T: CryptoWallet>(
+//!     wallet: &T,
+//!     client: &T::BlockchainClient,
+//! ) -> Result<(), T::ErrorType>
+//! where
+//!     T::CryptoAmount: std::fmt::Debug,
+//! {
+//!     println!("receive address: {}", wallet.receive_address()?);
+//!     println!("balance: {:?}", wallet.balance(client).await?);
+//!     Ok(())
+//! }
+//! ```
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+mod crypto_wallet;
+pub use crypto_wallet::{CryptoWallet, CryptoWalletBuilder};
+
+pub use walletd_hd_key::{HDNetworkType, Seed};
+pub mod prelude;
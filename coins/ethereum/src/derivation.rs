@@ -0,0 +1,109 @@
+use crate::Error;
+use bdk::bitcoin::util::bip32::{ChildNumber, DerivationPath};
+use core::fmt;
+
+/// The purpose of the BIP44 derivation paths
+const PURPOSE: u32 = 44;
+/// The SLIP-44 coin type of Ethereum
+const COIN_TYPE: u32 = 60;
+
+/// Represents the scheme of the derivation path of an [EthereumWallet][crate::EthereumWallet], which differs between the wallets funds are imported from
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EthereumDerivationScheme {
+    #[default]
+    /// m/44'/60'/account_index'/0/address_index, the BIP44 path used by MetaMask, Trezor and most other wallets
+    /// This is the default scheme for this enum
+    Bip44,
+    /// m/44'/60'/account_index'/0/0, the path of Ledger Live which derives a single address per account
+    LedgerLive,
+    /// m/44'/60'/0'/address_index, the path of the legacy MyEtherWallet and the Ledger Chrome app
+    LegacyMew,
+}
+
+impl EthereumDerivationScheme {
+    /// Returns the derivation path of the account and address indices, the index not used by the scheme is ignored
+    ///
+    /// Returns [Error::InvalidIndex] if an index used by the scheme is not below 2^31.
+    pub fn derivation_path(
+        &self,
+        account_index: u32,
+        address_index: u32,
+    ) -> Result<DerivationPath, Error> {
+        let hardened =
+            |index| ChildNumber::from_hardened_idx(index).map_err(|_| Error::InvalidIndex(index));
+        let normal =
+            |index| ChildNumber::from_normal_idx(index).map_err(|_| Error::InvalidIndex(index));
+        let path = match self {
+            Self::Bip44 => vec![
+                hardened(PURPOSE)?,
+                hardened(COIN_TYPE)?,
+                hardened(account_index)?,
+                normal(0)?,
+                normal(address_index)?,
+            ],
+            Self::LedgerLive => vec![
+                hardened(PURPOSE)?,
+                hardened(COIN_TYPE)?,
+                hardened(account_index)?,
+                normal(0)?,
+                normal(0)?,
+            ],
+            Self::LegacyMew => vec![
+                hardened(PURPOSE)?,
+                hardened(COIN_TYPE)?,
+                hardened(0)?,
+                normal(address_index)?,
+            ],
+        };
+        Ok(path.into())
+    }
+
+    /// Returns whether the scheme derives successive wallets by incrementing the account index rather than the address index
+    pub fn increments_account_index(&self) -> bool {
+        matches!(self, Self::LedgerLive)
+    }
+}
+
+impl fmt::Display for EthereumDerivationScheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EthereumDerivationScheme::Bip44 => write!(f, "BIP44"),
+            EthereumDerivationScheme::LedgerLive => write!(f, "Ledger Live"),
+            EthereumDerivationScheme::LegacyMew => write!(f, "Legacy MEW"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derivation_paths() -> Result<(), Error> {
+        let path = |scheme: EthereumDerivationScheme, account_index, address_index| {
+            scheme
+                .derivation_path(account_index, address_index)
+                .map(|path| path.to_string())
+        };
+        assert_eq!(path(Default::default(), 0, 0)?, "m/44'/60'/0'/0/0");
+        assert_eq!(
+            path(EthereumDerivationScheme::Bip44, 2, 7)?,
+            "m/44'/60'/2'/0/7"
+        );
+        assert_eq!(
+            path(EthereumDerivationScheme::LedgerLive, 3, 7)?,
+            "m/44'/60'/3'/0/0"
+        );
+        assert_eq!(
+            path(EthereumDerivationScheme::LegacyMew, 3, 7)?,
+            "m/44'/60'/0'/7"
+        );
+        assert!(matches!(
+            path(EthereumDerivationScheme::Bip44, 1 << 31, 0),
+            Err(Error::InvalidIndex(_))
+        ));
+        // the account index is not part of the legacy MEW path
+        assert!(path(EthereumDerivationScheme::LegacyMew, 1 << 31, 0).is_ok());
+        Ok(())
+    }
+}
@@ -45,4 +45,43 @@ pub enum Error {
     /// Error due to overflow
     #[error("Overflow error: {0}")]
     Overflow(String),
+    /// An index of a derivation path is too large to be a child number
+    #[error("Invalid derivation index {0}, it must be below 2^31")]
+    InvalidIndex(u32),
+    /// Error when calling a smart contract
+    #[error("Contract call failed: {0}")]
+    Contract(String),
+    /// A token amount does not have the decimals of the token
+    #[error("The token has {expected} decimals but the amount has {found} decimals")]
+    TokenDecimals {
+        /// The decimals of the token
+        expected: u8,
+        /// The decimals of the amount
+        found: u8,
+    },
+    /// Error when signing or recovering the signer of a message
+    #[error("Signature error: {0}")]
+    Signature(String),
+    /// Error returned by the JSON-RPC provider
+    #[error("Provider error: {0}")]
+    Provider(String),
+    /// The provider is connected to another chain than the one the wallet was built for
+    #[error("The wallet is configured for chain id {expected} but the provider is connected to chain id {found}")]
+    WrongChainId {
+        /// The chain id of the wallet
+        expected: u64,
+        /// The chain id of the provider
+        found: u64,
+    },
+    /// Error when signing or broadcasting a transaction
+    #[error("Failed to send transaction: {0}")]
+    SendTransaction(String),
+    /// The priority fee per gas of a transaction is above its maximum fee per gas
+    #[error("The priority fee per gas of {priority_fee} wei is above the maximum fee per gas of {max_fee} wei")]
+    PriorityFeeAboveMaxFee {
+        /// The maximum fee per gas in wei
+        max_fee: ethers::types::U256,
+        /// The priority fee per gas in wei
+        priority_fee: ethers::types::U256,
+    },
 }
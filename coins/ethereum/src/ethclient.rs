@@ -1,16 +1,36 @@
+use crate::nft::{erc1155_token_uri, ERC165_INTERFACE_ID, INVALID_INTERFACE_ID};
+use crate::transaction::{FEE_HISTORY_BLOCKS, FEE_HISTORY_REWARD_PERCENTILE};
+use crate::Eip1559Fees;
 use crate::Error;
 use crate::EthereumAmount;
+use crate::NftStandard;
+use crate::TokenAmount;
 
 use ethers::prelude::*;
 use ethers::types::Address;
 
 use std::sync::Arc;
 
+/// The metadata of an ERC-20 token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenMetadata {
+    /// The name of the token
+    pub name: String,
+    /// The symbol of the token, such as `USDC`
+    pub symbol: String,
+    /// The number of decimals of the token, which the balances are divided by 10^decimals to display
+    pub decimals: u8,
+}
+
 /// A blockchain connector for Ethereum which contains a [`instance of ethers`](https://github.com/gakonst/ethers-rs) using a HTTP transport.
 pub struct EthClient {}
 
 // Creates Rust bindings for the ERC20 ABI
 abigen!(ERC20, "./abi/erc20_abi.json");
+// Creates Rust bindings for the ERC-165, ERC-721 and ERC-1155 ABIs
+abigen!(ERC165, "./abi/erc165_abi.json");
+abigen!(ERC721, "./abi/erc721_abi.json");
+abigen!(ERC1155, "./abi/erc1155_abi.json");
 
 #[allow(unused)]
 impl EthClient {
@@ -86,44 +106,215 @@ impl EthClient {
         }
     }
 
-    /// Given a specified smart contract (ERC20) instance, determine the
-    /// token balance for a given address.
-    async fn balance_of_smart_contract(
-        provider: Arc<Provider<Http>>,
-        address: ethers::types::Address,
-    ) -> Result<String, Error> {
-        let contract_instance = ERC20::new(address, Arc::clone(&provider));
-        let balance = &contract_instance.balance_of(address).call().await.unwrap();
-        Ok(balance.to_string())
+    /// Returns the balance of ERC-20 tokens of the owner as a [TokenAmount] with the decimals of the token.
+    pub async fn token_balance(
+        provider: &Provider<Http>,
+        token: Address,
+        owner: Address,
+    ) -> Result<TokenAmount, Error> {
+        let contract = Self::erc20(provider, token);
+        let decimals = Self::token_decimals(provider, token).await?;
+        let balance = contract
+            .balance_of(owner)
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))?;
+        Ok(TokenAmount::from_base_units(balance, decimals))
+    }
+
+    /// Returns the amount of ERC-20 tokens of the owner the spender is allowed to transfer.
+    pub async fn token_allowance(
+        provider: &Provider<Http>,
+        token: Address,
+        owner: Address,
+        spender: Address,
+    ) -> Result<TokenAmount, Error> {
+        let contract = Self::erc20(provider, token);
+        let decimals = Self::token_decimals(provider, token).await?;
+        let allowance = contract
+            .allowance(owner, spender)
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))?;
+        Ok(TokenAmount::from_base_units(allowance, decimals))
+    }
+
+    /// Returns the total supply of an ERC-20 token.
+    pub async fn token_total_supply(
+        provider: &Provider<Http>,
+        token: Address,
+    ) -> Result<TokenAmount, Error> {
+        let contract = Self::erc20(provider, token);
+        let decimals = Self::token_decimals(provider, token).await?;
+        let total_supply = contract
+            .total_supply()
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))?;
+        Ok(TokenAmount::from_base_units(total_supply, decimals))
+    }
+
+    /// Returns the number of decimals of an ERC-20 token.
+    pub async fn token_decimals(provider: &Provider<Http>, token: Address) -> Result<u8, Error> {
+        Self::erc20(provider, token)
+            .decimals()
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))
+    }
+
+    /// Returns the name of an ERC-20 token.
+    pub async fn token_name(provider: &Provider<Http>, token: Address) -> Result<String, Error> {
+        Self::erc20(provider, token)
+            .name()
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))
+    }
+
+    /// Returns the symbol of an ERC-20 token.
+    pub async fn token_symbol(provider: &Provider<Http>, token: Address) -> Result<String, Error> {
+        Self::erc20(provider, token)
+            .symbol()
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))
+    }
+
+    /// Returns the name, symbol and decimals of an ERC-20 token.
+    pub async fn token_metadata(
+        provider: &Provider<Http>,
+        token: Address,
+    ) -> Result<TokenMetadata, Error> {
+        Ok(TokenMetadata {
+            name: Self::token_name(provider, token).await?,
+            symbol: Self::token_symbol(provider, token).await?,
+            decimals: Self::token_decimals(provider, token).await?,
+        })
+    }
+
+    /// Returns the bindings of the ERC-20 token contract at the address
+    pub(crate) fn erc20(provider: &Provider<Http>, token: Address) -> ERC20<Provider<Http>> {
+        ERC20::new(token, Arc::new(provider.clone()))
+    }
+
+    /// Returns whether the contract supports the interface, following the ERC-165 detection procedure.
+    ///
+    /// A contract which does not implement ERC-165 supports no interface.
+    pub async fn supports_interface(
+        provider: &Provider<Http>,
+        contract: Address,
+        interface_id: [u8; 4],
+    ) -> Result<bool, Error> {
+        let erc165 = ERC165::new(contract, Arc::new(provider.clone()));
+        for (id, expected) in [(ERC165_INTERFACE_ID, true), (INVALID_INTERFACE_ID, false)] {
+            match erc165.supports_interface(id).call().await {
+                Ok(supported) if supported == expected => {}
+                Ok(_) => return Ok(false),
+                Err(e) => return Self::unsupported_interface(e),
+            }
+        }
+        match erc165.supports_interface(interface_id).call().await {
+            Ok(supported) => Ok(supported),
+            Err(e) => Self::unsupported_interface(e),
+        }
     }
 
-    async fn allowance(
-        provider: Arc<Provider<Http>>,
-        address: ethers::types::Address,
+    /// Returns the NFT standard the contract implements according to ERC-165, or None if it implements neither ERC-721 nor ERC-1155.
+    pub async fn nft_standard(
+        provider: &Provider<Http>,
+        contract: Address,
+    ) -> Result<Option<NftStandard>, Error> {
+        for standard in [NftStandard::Erc721, NftStandard::Erc1155] {
+            if Self::supports_interface(provider, contract, standard.interface_id()).await? {
+                return Ok(Some(standard));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the owner of an ERC-721 token.
+    pub async fn erc721_owner_of(
+        provider: &Provider<Http>,
+        contract: Address,
+        token_id: U256,
+    ) -> Result<Address, Error> {
+        Self::erc721(provider, contract)
+            .owner_of(token_id)
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))
+    }
+
+    /// Returns the URI of the metadata of an ERC-721 token.
+    pub async fn erc721_token_uri(
+        provider: &Provider<Http>,
+        contract: Address,
+        token_id: U256,
     ) -> Result<String, Error> {
-        let contract_instance = ERC20::new(address, Arc::clone(&provider));
-        let balance = &contract_instance.balance_of(address).call().await.unwrap();
-        Ok(balance.to_string())
+        Self::erc721(provider, contract)
+            .token_uri(token_id)
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))
     }
 
-    /// Given a specified contract instance, determine the total supply of
-    /// tokens
-    async fn total_supply(
-        provider: Arc<Provider<Http>>,
-        address: ethers::types::Address,
-    ) -> Result<U256, Error> {
-        let contract_instance = ERC20::new(address, Arc::clone(&provider));
-        let total_supply = &contract_instance.total_supply().call().await.unwrap();
-        Ok(*total_supply)
+    /// Returns the balances of ERC-1155 tokens, the balance of the id at each index for the owner at the same index.
+    pub async fn erc1155_balance_of_batch(
+        provider: &Provider<Http>,
+        contract: Address,
+        owners: &[Address],
+        ids: &[U256],
+    ) -> Result<Vec<U256>, Error> {
+        if owners.len() != ids.len() {
+            return Err(Error::Contract(format!(
+                "balanceOfBatch needs as many owners as ids, got {} owners and {} ids",
+                owners.len(),
+                ids.len()
+            )));
+        }
+        Self::erc1155(provider, contract)
+            .balance_of_batch(owners.to_vec(), ids.to_vec())
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))
     }
 
-    async fn get_token_name(
-        provider: Arc<Provider<Http>>,
-        address: ethers::types::Address,
+    /// Returns the URI of the metadata of an ERC-1155 token, with the `{id}` placeholder replaced by the id.
+    pub async fn erc1155_uri(
+        provider: &Provider<Http>,
+        contract: Address,
+        id: U256,
     ) -> Result<String, Error> {
-        let contract_instance = ERC20::new(address, Arc::clone(&provider));
-        let token_name = &contract_instance.name().call().await.unwrap();
-        Ok(token_name.to_string())
+        let uri = Self::erc1155(provider, contract)
+            .uri(id)
+            .call()
+            .await
+            .map_err(|e| Error::Contract(e.to_string()))?;
+        Ok(erc1155_token_uri(&uri, id))
+    }
+
+    /// Returns the bindings of the ERC-721 contract at the address
+    pub(crate) fn erc721(provider: &Provider<Http>, contract: Address) -> ERC721<Provider<Http>> {
+        ERC721::new(contract, Arc::new(provider.clone()))
+    }
+
+    /// Returns the bindings of the ERC-1155 contract at the address
+    pub(crate) fn erc1155(provider: &Provider<Http>, contract: Address) -> ERC1155<Provider<Http>> {
+        ERC1155::new(contract, Arc::new(provider.clone()))
+    }
+
+    /// Returns false if the `supportsInterface` call failed because the contract does not implement it, the error otherwise
+    ///
+    /// Following ERC-165 the contract does not implement it if the call reverted or did not return a boolean, which includes returning no data.
+    fn unsupported_interface(error: ContractError<Provider<Http>>) -> Result<bool, Error> {
+        match error {
+            ContractError::Revert(_)
+            | ContractError::DecodingError(_)
+            | ContractError::AbiError(_)
+            | ContractError::DetokenizationError(_) => Ok(false),
+            error => Err(Error::Contract(error.to_string())),
+        }
     }
 
     /// Get the current price of gas as an [EthereumAmount].
@@ -133,6 +324,19 @@ impl EthClient {
         Ok(EthereumAmount { wei: gas_price })
     }
 
+    /// Estimates the fees per gas of a type-2 (EIP-1559) transaction from the `eth_feeHistory` of the recent blocks.
+    pub async fn estimate_fees(provider: &Provider<Http>) -> Result<Eip1559Fees, Error> {
+        let history = provider
+            .fee_history(
+                FEE_HISTORY_BLOCKS,
+                BlockNumber::Latest,
+                &[FEE_HISTORY_REWARD_PERCENTILE],
+            )
+            .await
+            .map_err(|e| Error::Provider(e.to_string()))?;
+        Eip1559Fees::from_fee_history(&history)
+    }
+
     /// Get the latest block number for the current network chain.
     pub async fn current_block_number(provider: &Provider<Http>) -> Result<u64, Error> {
         let block_number: ethers::types::U64 = provider.get_block_number().await.unwrap();
@@ -188,9 +392,39 @@ impl EthClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ethers::abi::ParamType;
+    use ethers::providers::{HttpClientError, JsonRpcError, ProviderError};
     use ethers::utils::Anvil;
     use std::str::FromStr;
 
+    #[test]
+    fn test_unsupported_interface() {
+        // a node reports a revert as a JSON-RPC error, the call converts it to a revert
+        let revert = ContractError::from_middleware_error(ProviderError::JsonRpcClientError(
+            Box::new(HttpClientError::JsonRpcError(JsonRpcError {
+                code: 3,
+                message: "execution reverted".to_string(),
+                data: None,
+            })),
+        ));
+        assert!(matches!(revert, ContractError::Revert(_)));
+        assert!(!EthClient::unsupported_interface(revert).unwrap());
+
+        // a contract without a fallback function returns no data
+        let empty_return = ethers::abi::decode(&[ParamType::Bool], &[]).unwrap_err();
+        assert!(
+            !EthClient::unsupported_interface(ContractError::DecodingError(empty_return)).unwrap()
+        );
+
+        let unreachable = ContractError::ProviderError {
+            e: ProviderError::CustomError("connection refused".to_string()),
+        };
+        assert!(matches!(
+            EthClient::unsupported_interface(unreachable),
+            Err(Error::Contract(_))
+        ));
+    }
+
     #[test]
     fn create_instance_of_ethclient() {
         let port = 8545u16;
@@ -4,23 +4,21 @@ use std::str::FromStr;
 
 use crate::Error;
 use crate::EthClient;
-use crate::{EthereumAmount, EthereumFormat};
+use crate::{
+    EthereumAmount, EthereumDerivationScheme, EthereumFormat, TokenAmount, TransactionOverrides,
+};
+use async_trait::async_trait;
 
-use bdk::bitcoin::secp256k1::ffi::types::AlignedType;
 use bdk::bitcoin::secp256k1::PublicKey;
-use bdk::bitcoin::secp256k1::Secp256k1;
+use bdk::bitcoin::secp256k1::{All, Secp256k1};
 use bdk::bitcoin::util::bip32::DerivationPath;
 use bdk::bitcoin::util::bip32::ExtendedPrivKey;
 use bdk::bitcoin::util::bip32::ExtendedPubKey;
 use bdk::keys::bip39::Mnemonic;
 use bdk::keys::{DerivableKey, ExtendedKey};
-use ethers::middleware::gas_oracle::GasNow;
-use ethers::prelude::gas_oracle::GasOracleMiddleware;
 use ethers::prelude::*;
-// use ethers::providers::{Middleware};
-// use ethers::types::{TransactionRequest};
-// use ethers::signers::{Signer};
 use tiny_keccak::{Hasher, Keccak};
+use walletd_coin_core::{CryptoWallet, CryptoWalletBuilder, HDNetworkType, Seed};
 
 /// Represents an EthereumPublicKey, wraps a [PublicKey] from the secp256k1 crate
 // TODO: EthereumPublicKey can likely be simplified by using a keystore and/or bdk
@@ -97,7 +95,11 @@ impl LowerHex for EthereumPublicKey {
 pub struct EthereumWalletBuilder {
     address_format: EthereumFormat,
     mnemonic: Option<Mnemonic>,
+    mnemonic_seed: Option<Seed>,
     chain_id: u64,
+    derivation_scheme: EthereumDerivationScheme,
+    account_index: u32,
+    address_index: u32,
 }
 
 impl Default for EthereumWalletBuilder {
@@ -108,7 +110,11 @@ impl Default for EthereumWalletBuilder {
         Self {
             address_format: EthereumFormat::Checksummed,
             mnemonic: None,
-            chain_id: 5, // Goerli
+            mnemonic_seed: None,
+            chain_id: 11155111, // Sepolia
+            derivation_scheme: EthereumDerivationScheme::Bip44,
+            account_index: 0,
+            address_index: 0,
         }
     }
 }
@@ -120,39 +126,87 @@ impl EthereumWalletBuilder {
     }
     /// Builds the EthereumWallet with the specified options
     pub fn build(&self) -> Result<EthereumWallet, Error> {
-        if self.mnemonic.is_none() {
-            return Err(Error::UnableToImportWallet(
-                "The mnemonic seed was provided".to_string(),
-            ));
+        let secp = Secp256k1::new();
+        let xprv = self.master_key()?;
+        let path = self
+            .derivation_scheme
+            .derivation_path(self.account_index, self.address_index)?;
+        self.build_from_master_key(&secp, &xprv, path)
+    }
+
+    /// Builds the wallets of `count` successive addresses derived from the mnemonic, starting from the specified account and address indices
+    ///
+    /// The address index is incremented from one wallet to the next, except for [EthereumDerivationScheme::LedgerLive] which increments the account index.
+    pub fn build_wallets(&self, count: u32) -> Result<Vec<EthereumWallet>, Error> {
+        let secp = Secp256k1::new();
+        let xprv = self.master_key()?;
+        (0..count)
+            .map(|offset| {
+                let (account_index, address_index) =
+                    if self.derivation_scheme.increments_account_index() {
+                        (
+                            self.account_index.checked_add(offset),
+                            Some(self.address_index),
+                        )
+                    } else {
+                        (
+                            Some(self.account_index),
+                            self.address_index.checked_add(offset),
+                        )
+                    };
+                let path = self.derivation_scheme.derivation_path(
+                    account_index.ok_or(Error::InvalidIndex(u32::MAX))?,
+                    address_index.ok_or(Error::InvalidIndex(u32::MAX))?,
+                )?;
+                self.build_from_master_key(&secp, &xprv, path)
+            })
+            .collect()
+    }
+
+    /// Returns the master key of the mnemonic seed or the mnemonic
+    fn master_key(&self) -> Result<ExtendedPrivKey, Error> {
+        match (&self.mnemonic_seed, &self.mnemonic) {
+            (Some(seed), _) => {
+                ExtendedPrivKey::new_master(bdk::bitcoin::Network::Bitcoin, seed.as_bytes())
+                    .map_err(|e| Error::UnableToImportWallet(e.to_string()))
+            }
+            (None, Some(mnemonic)) => {
+                let xkey: ExtendedKey = mnemonic
+                    .clone()
+                    .into_extended_key()
+                    .map_err(|e| Error::UnableToImportWallet(e.to_string()))?;
+                xkey.into_xprv(bdk::bitcoin::Network::Bitcoin)
+                    .ok_or_else(|| {
+                        Error::UnableToImportWallet("the mnemonic has no private key".to_string())
+                    })
+            }
+            (None, None) => Err(Error::UnableToImportWallet(
+                "Neither the mnemonic nor the mnemonic seed was provided".to_string(),
+            )),
         }
+    }
 
-        // we need secp256k1 context for key derivation
-        let mut buf: Vec<AlignedType> = Vec::new();
-        buf.resize(Secp256k1::preallocate_size(), AlignedType::zeroed());
-        let secp = Secp256k1::preallocated_new(buf.as_mut_slice()).unwrap();
-
-        let mnemonic = &self.mnemonic.clone().unwrap();
-        let xkey: ExtendedKey = mnemonic.clone().into_extended_key().unwrap();
-        // Get xprv from the extended key
-        let xprv = xkey.into_xprv(bdk::bitcoin::Network::Bitcoin).unwrap();
-        let path = DerivationPath::from_str("m/44h/60h/0h/0/0").unwrap();
-
-        let child = xprv.derive_priv(&secp, &path).unwrap();
-        // println!("Child at {}: {}", path, child);
-        let xpub = ExtendedPubKey::from_priv(&secp, &child);
-        // println!("Public key at {}: {}", path, xpub);
-        // println!("private key bytes: {:?}", &child.private_key.secret_bytes());
-        let public_key =
-            EthereumPublicKey(PublicKey::from_slice(&xpub.public_key.serialize()).unwrap());
-        // println!("test2: {:?}", public_key);
+    /// Builds the wallet of the key derived from the master key at the derivation path
+    fn build_from_master_key(
+        &self,
+        secp: &Secp256k1<All>,
+        xprv: &ExtendedPrivKey,
+        derivation_path: DerivationPath,
+    ) -> Result<EthereumWallet, Error> {
+        let child = xprv
+            .derive_priv(secp, &derivation_path)
+            .map_err(|e| Error::UnableToImportWallet(e.to_string()))?;
+        let xpub = ExtendedPubKey::from_priv(secp, &child);
+        let public_key = EthereumPublicKey(xpub.public_key);
         let public_address = public_key.to_public_address(self.address_format)?;
-        let wallet = EthereumWallet {
+        Ok(EthereumWallet {
             address_format: self.address_format,
+            chain_id: self.chain_id,
+            derivation_path,
             public_address,
             private_key: Some(child),
             public_key: Some(xpub),
-        };
-        Ok(wallet)
+        })
     }
 
     /// Allows specification of the address format for the wallet
@@ -166,12 +220,70 @@ impl EthereumWalletBuilder {
         self.mnemonic = Some(mnemonic);
         self
     }
+
+    /// Allows specification of the mnemonic seed for the wallet, the seed takes precedence over a mnemonic if both are specified
+    pub fn mnemonic_seed(&mut self, mnemonic_seed: Seed) -> &mut Self {
+        self.mnemonic_seed = Some(mnemonic_seed);
+        self
+    }
+
+    /// Allows specification of the chain id for the wallet, the default is 11155111 (Sepolia)
+    pub fn chain_id(&mut self, chain_id: u64) -> &mut Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Allows specification of the scheme of the derivation path, the default is [EthereumDerivationScheme::Bip44]
+    pub fn derivation_scheme(&mut self, derivation_scheme: EthereumDerivationScheme) -> &mut Self {
+        self.derivation_scheme = derivation_scheme;
+        self
+    }
+
+    /// Allows specification of the index of the account derived from the mnemonic, the default is the first account (0)
+    ///
+    /// The account index is not part of the [EthereumDerivationScheme::LegacyMew] path.
+    pub fn account_index(&mut self, account_index: u32) -> &mut Self {
+        self.account_index = account_index;
+        self
+    }
+
+    /// Allows specification of the index of the address derived from the account, the default is the first address (0)
+    ///
+    /// The address index is not part of the [EthereumDerivationScheme::LedgerLive] path.
+    pub fn address_index(&mut self, address_index: u32) -> &mut Self {
+        self.address_index = address_index;
+        self
+    }
+}
+
+impl CryptoWalletBuilder<EthereumWallet> for EthereumWalletBuilder {
+    fn new() -> Self {
+        EthereumWalletBuilder::new()
+    }
+
+    fn mnemonic_seed(&mut self, mnemonic_seed: Seed) -> &mut Self {
+        EthereumWalletBuilder::mnemonic_seed(self, mnemonic_seed)
+    }
+
+    /// Maps [HDNetworkType::MainNet] to chain id 1 (Mainnet) and [HDNetworkType::TestNet], [HDNetworkType::Signet] and any other network type to chain id 11155111 (Sepolia)
+    fn hd_network_type(&mut self, network_type: HDNetworkType) -> &mut Self {
+        match network_type {
+            HDNetworkType::MainNet => self.chain_id(1),
+            _ => self.chain_id(11155111),
+        }
+    }
+
+    fn build(&self) -> Result<EthereumWallet, Error> {
+        EthereumWalletBuilder::build(self)
+    }
 }
 
 /// Contains the information needed to interact with an Ethereum wallet with a single public address associated with it.
 #[derive(Debug, Clone)]
 pub struct EthereumWallet {
     address_format: EthereumFormat,
+    chain_id: u64,
+    derivation_path: DerivationPath,
     public_address: String,
     private_key: Option<ExtendedPrivKey>,
     public_key: Option<ExtendedPubKey>,
@@ -185,54 +297,288 @@ impl EthereumWallet {
 
     ///  Returns the balance for this Ethereum Wallet.
     pub async fn balance(&self, provider: &Provider<Http>) -> Result<EthereumAmount, Error> {
-        let address = ethers::types::Address::from_str(&self.public_address())
-            .map_err(|e| (Error::FromStr(e.to_string())))?;
-        let balance = EthClient::balance(provider, address).await?;
+        let balance = EthClient::balance(provider, parse_address(&self.public_address)?).await?;
         Ok(balance)
     }
 
-    // TODO: take chain_id as a parameter
-    // TODO: Take index as a parameter and use that for deriving the wallet we want (refactor keystore)
-    /// This function creates and broadcasts a basic Ethereum transfer transaction to the Ethereum mempool.
+    /// This function creates and broadcasts a type-2 (EIP-1559) Ethereum transfer transaction on the chain of the wallet and waits for it to be included in a block.
+    ///
+    /// The gas limit is estimated with `eth_estimateGas` and the fees with `eth_feeHistory`, use [transfer_with_overrides][EthereumWallet::transfer_with_overrides] to set them.
+    /// Returns the hash of the transaction.
     pub async fn transfer(
         &self,
         provider: &Provider<Http>,
         send_amount: EthereumAmount,
         to_address: &str,
     ) -> Result<String, Error> {
-        let private_key_bytes = self.private_key.unwrap().private_key.secret_bytes();
-        // EthereumWallet stores the private key as a 32 byte array
-        //let secret_bytes = private_key.to_bytes();
-
-        // Instantiate a ethers local wallet from the wallet's secret bytes
-        let wallet_from_bytes = Wallet::from_bytes(&private_key_bytes).unwrap();
-
-        // Link our wallet instance to our provider for signing our transactions
-        let client = SignerMiddleware::new(provider, wallet_from_bytes.with_chain_id(5u64));
-        let client = GasOracleMiddleware::new(client, GasNow::new());
-        // Create a transaction request to send 10000 wei to the Goerli address
-        // TODO: Use gas oracle for more complex transactions where required gas fee is not known
-        // 21000 = gas fee for basic transfer
-        let tx = TransactionRequest::new()
-            .to(to_address)
-            .gas(21000)
-            .value(send_amount.wei())
-            .chain_id(5u64);
-
-        let pending_tx = client.send_transaction(tx, None).await.unwrap();
-        let receipt = pending_tx
+        self.transfer_with_overrides(
+            provider,
+            send_amount,
+            to_address,
+            &TransactionOverrides::default(),
+        )
+        .await
+    }
+
+    /// Same as [transfer][EthereumWallet::transfer] but the gas limit, fees or nonce set in the overrides are used instead of the filled in ones.
+    pub async fn transfer_with_overrides(
+        &self,
+        provider: &Provider<Http>,
+        send_amount: EthereumAmount,
+        to_address: &str,
+        overrides: &TransactionOverrides,
+    ) -> Result<String, Error> {
+        let tx = Eip1559TransactionRequest::new()
+            .to(parse_address(to_address)?)
+            .value(send_amount.wei());
+        let receipt = self.send_transaction(provider, tx, overrides).await?;
+        Ok(format!("{:?}", receipt.transaction_hash))
+    }
+
+    /// Returns the balance of ERC-20 tokens of this Ethereum Wallet.
+    pub async fn token_balance(
+        &self,
+        provider: &Provider<Http>,
+        token: &str,
+    ) -> Result<TokenAmount, Error> {
+        EthClient::token_balance(
+            provider,
+            parse_address(token)?,
+            parse_address(&self.public_address)?,
+        )
+        .await
+    }
+
+    /// Transfers ERC-20 tokens of the wallet to an address, like [transfer][EthereumWallet::transfer] for ETH.
+    ///
+    /// Returns [Error::TokenDecimals] if the amount does not have the decimals of the token.
+    pub async fn transfer_token(
+        &self,
+        provider: &Provider<Http>,
+        token: &str,
+        amount: TokenAmount,
+        to_address: &str,
+    ) -> Result<String, Error> {
+        let token = parse_address(token)?;
+        let call = EthClient::erc20(provider, token)
+            .transfer(parse_address(to_address)?, amount.base_units());
+        self.send_token_call(provider, token, amount, call.calldata())
+            .await
+    }
+
+    /// Allows the spender to transfer up to `amount` ERC-20 tokens of the wallet with [transfer_from][EthereumWallet::transfer_from], replacing the previous allowance.
+    ///
+    /// Returns [Error::TokenDecimals] if the amount does not have the decimals of the token.
+    pub async fn approve(
+        &self,
+        provider: &Provider<Http>,
+        token: &str,
+        amount: TokenAmount,
+        spender: &str,
+    ) -> Result<String, Error> {
+        let token = parse_address(token)?;
+        let call =
+            EthClient::erc20(provider, token).approve(parse_address(spender)?, amount.base_units());
+        self.send_token_call(provider, token, amount, call.calldata())
             .await
-            .unwrap()
-            .ok_or_else(|| println!("tx dropped from mempool"))
-            .unwrap();
+    }
 
-        let tx = client
-            .get_transaction(receipt.transaction_hash)
+    /// Transfers ERC-20 tokens of the owner which approved the wallet to spend them to an address.
+    ///
+    /// Returns [Error::TokenDecimals] if the amount does not have the decimals of the token.
+    pub async fn transfer_from(
+        &self,
+        provider: &Provider<Http>,
+        token: &str,
+        amount: TokenAmount,
+        owner: &str,
+        to_address: &str,
+    ) -> Result<String, Error> {
+        let token = parse_address(token)?;
+        let call = EthClient::erc20(provider, token).transfer_from(
+            parse_address(owner)?,
+            parse_address(to_address)?,
+            amount.base_units(),
+        );
+        self.send_token_call(provider, token, amount, call.calldata())
             .await
-            .unwrap();
+    }
 
-        let tx_hash_string = tx.unwrap().hash.to_string();
-        Ok(tx_hash_string)
+    /// Transfers an ERC-721 token of the wallet to an address with `safeTransferFrom`, which fails if the recipient is a contract that can not receive it.
+    pub async fn transfer_erc721(
+        &self,
+        provider: &Provider<Http>,
+        contract: &str,
+        token_id: U256,
+        to_address: &str,
+    ) -> Result<String, Error> {
+        let contract = parse_address(contract)?;
+        let call = EthClient::erc721(provider, contract).safe_transfer_from(
+            parse_address(&self.public_address)?,
+            parse_address(to_address)?,
+            token_id,
+        );
+        self.send_contract_call(provider, contract, call.calldata())
+            .await
+    }
+
+    /// Transfers amounts of several ERC-1155 tokens of the wallet to an address with `safeBatchTransferFrom`, the amount at each index is transferred for the id at the same index.
+    pub async fn transfer_erc1155_batch(
+        &self,
+        provider: &Provider<Http>,
+        contract: &str,
+        ids: &[U256],
+        amounts: &[U256],
+        to_address: &str,
+    ) -> Result<String, Error> {
+        if ids.len() != amounts.len() {
+            return Err(Error::Contract(format!(
+                "safeBatchTransferFrom needs as many ids as amounts, got {} ids and {} amounts",
+                ids.len(),
+                amounts.len()
+            )));
+        }
+        let contract = parse_address(contract)?;
+        let call = EthClient::erc1155(provider, contract).safe_batch_transfer_from(
+            parse_address(&self.public_address)?,
+            parse_address(to_address)?,
+            ids.to_vec(),
+            amounts.to_vec(),
+            Bytes::new(),
+        );
+        self.send_contract_call(provider, contract, call.calldata())
+            .await
+    }
+
+    /// Sends a call of the token contract moving `amount`, after checking that the amount has the decimals of the token
+    async fn send_token_call(
+        &self,
+        provider: &Provider<Http>,
+        token: ethers::types::Address,
+        amount: TokenAmount,
+        calldata: Option<Bytes>,
+    ) -> Result<String, Error> {
+        let decimals = EthClient::token_decimals(provider, token).await?;
+        if amount.decimals() != decimals {
+            return Err(Error::TokenDecimals {
+                expected: decimals,
+                found: amount.decimals(),
+            });
+        }
+        self.send_contract_call(provider, token, calldata).await
+    }
+
+    /// Sends a call of a contract with the default gas and fee estimates and returns the hash of its transaction
+    async fn send_contract_call(
+        &self,
+        provider: &Provider<Http>,
+        contract: ethers::types::Address,
+        calldata: Option<Bytes>,
+    ) -> Result<String, Error> {
+        let calldata = calldata.ok_or_else(|| Error::Contract("missing calldata".to_string()))?;
+        let tx = Eip1559TransactionRequest::new().to(contract).data(calldata);
+        let receipt = self
+            .send_transaction(provider, tx, &TransactionOverrides::default())
+            .await?;
+        Ok(format!("{:?}", receipt.transaction_hash))
+    }
+
+    /// Signs the transaction with the private key of the wallet, broadcasts it and waits for it to be included in a block
+    ///
+    /// The transaction is sent from the address of the wallet on its chain, returns [Error::WrongChainId] if the provider is connected to another chain.
+    /// The nonce, gas limit and fees not set in the overrides are filled in from the provider.
+    pub(crate) async fn send_transaction(
+        &self,
+        provider: &Provider<Http>,
+        mut tx: Eip1559TransactionRequest,
+        overrides: &TransactionOverrides,
+    ) -> Result<TransactionReceipt, Error> {
+        let provider_chain_id = provider
+            .get_chainid()
+            .await
+            .map_err(|e| Error::Provider(e.to_string()))?;
+        if provider_chain_id != U256::from(self.chain_id) {
+            return Err(Error::WrongChainId {
+                expected: self.chain_id,
+                found: provider_chain_id.low_u64(),
+            });
+        }
+        let signer = self.signer()?;
+
+        tx = tx.from(signer.address()).chain_id(self.chain_id);
+        if let Some(nonce) = overrides.nonce {
+            tx = tx.nonce(nonce);
+        }
+        let estimated_fees = if overrides.estimates_fees() {
+            Some(EthClient::estimate_fees(provider).await?)
+        } else {
+            None
+        };
+        let (max_fee_per_gas, max_priority_fee_per_gas) =
+            overrides.fees_per_gas(estimated_fees.as_ref())?;
+        tx = tx
+            .max_fee_per_gas(max_fee_per_gas.wei())
+            .max_priority_fee_per_gas(max_priority_fee_per_gas.wei());
+        let gas_limit = match overrides.gas_limit {
+            Some(gas_limit) => gas_limit.into(),
+            None => provider
+                .estimate_gas(&tx.clone().into(), None)
+                .await
+                .map_err(|e| Error::Provider(e.to_string()))?,
+        };
+        tx = tx.gas(gas_limit);
+
+        let client = SignerMiddleware::new(provider, signer);
+        let pending_tx = client
+            .send_transaction(tx, None)
+            .await
+            .map_err(|e| Error::SendTransaction(e.to_string()))?;
+        let tx_hash = pending_tx.tx_hash();
+        let receipt = pending_tx
+            .await
+            .map_err(|e| Error::Provider(e.to_string()))?
+            .ok_or_else(|| {
+                Error::SendTransaction(format!(
+                    "transaction {:?} was dropped from the mempool",
+                    tx_hash
+                ))
+            })?;
+        if receipt.status == Some(0.into()) {
+            return Err(Error::SendTransaction(format!(
+                "transaction {:?} reverted",
+                tx_hash
+            )));
+        }
+        Ok(receipt)
+    }
+
+    /// Signs a message following EIP-191 (`personal_sign`), which prefixes it with "\x19Ethereum Signed Message:\n" and its length before hashing.
+    ///
+    /// The signer of the message is recovered with [recover_message_signer][crate::recover_message_signer].
+    pub fn sign_message(&self, message: impl AsRef<[u8]>) -> Result<Signature, Error> {
+        self.sign_hash(crate::message_hash(message))
+    }
+
+    /// Signs EIP-712 typed data given as the JSON object passed to `eth_signTypedData_v4`, with the `types`, `primaryType`, `domain` and `message` fields.
+    ///
+    /// The signer of the typed data is recovered with [recover_typed_data_signer][crate::recover_typed_data_signer].
+    pub fn sign_typed_data(&self, typed_data: &str) -> Result<Signature, Error> {
+        self.sign_hash(crate::typed_data_hash(typed_data)?)
+    }
+
+    /// Signs a hash with the private key of the wallet
+    fn sign_hash(&self, hash: H256) -> Result<Signature, Error> {
+        self.signer()?
+            .sign_hash(hash)
+            .map_err(|e| Error::Signature(e.to_string()))
+    }
+
+    /// Returns the private key of the wallet as a signer of transactions on its chain
+    fn signer(&self) -> Result<LocalWallet, Error> {
+        let private_key = self.private_key.ok_or(Error::MissingPrivateKey)?;
+        let signer = LocalWallet::from_bytes(&private_key.private_key.secret_bytes())
+            .map_err(|e| Error::Signature(e.to_string()))?;
+        Ok(signer.with_chain_id(self.chain_id))
     }
 
     /// Syncs the wallet with the blockchain by adding previously used addresses to the wallet.
@@ -266,4 +612,134 @@ impl EthereumWallet {
             None => Err(Error::MissingPublicKey),
         }
     }
+
+    /// Returns the chain id the wallet was built for
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Returns the derivation path of the wallet's key
+    pub fn derivation_path(&self) -> &DerivationPath {
+        &self.derivation_path
+    }
+}
+
+/// Parses a hex encoded Ethereum address
+fn parse_address(address: &str) -> Result<ethers::types::Address, Error> {
+    ethers::types::Address::from_str(address).map_err(|e| Error::FromStr(e.to_string()))
+}
+
+#[async_trait(?Send)]
+impl CryptoWallet for EthereumWallet {
+    type ErrorType = Error;
+    type CryptoAmount = EthereumAmount;
+    type AddressFormat = EthereumFormat;
+    /// The chain id
+    type NetworkType = u64;
+    type BlockchainClient = Provider<Http>;
+    type WalletBuilder = EthereumWalletBuilder;
+
+    fn builder() -> Self::WalletBuilder {
+        EthereumWalletBuilder::new()
+    }
+
+    async fn balance(
+        &self,
+        blockchain_client: &Self::BlockchainClient,
+    ) -> Result<Self::CryptoAmount, Self::ErrorType> {
+        EthereumWallet::balance(self, blockchain_client).await
+    }
+
+    fn receive_address(&self) -> Result<String, Self::ErrorType> {
+        EthereumWallet::receive_address(self)
+    }
+
+    async fn transfer(
+        &self,
+        blockchain_client: &Self::BlockchainClient,
+        send_amount: Self::CryptoAmount,
+        to_address: &str,
+    ) -> Result<String, Self::ErrorType> {
+        EthereumWallet::transfer(self, blockchain_client, send_amount, to_address).await
+    }
+
+    async fn sync(
+        &mut self,
+        _blockchain_client: &Self::BlockchainClient,
+    ) -> Result<(), Self::ErrorType> {
+        EthereumWallet::sync(self).await
+    }
+
+    fn network(&self) -> Result<Self::NetworkType, Self::ErrorType> {
+        Ok(self.chain_id)
+    }
+
+    fn address_format(&self) -> Self::AddressFormat {
+        EthereumWallet::address_format(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::tests::{
+        EIP191_MESSAGE, EIP191_SIGNATURE, EIP191_SIGNER, EIP712_MAIL, EIP712_SIGNATURE,
+        EIP712_SIGNER,
+    };
+    use bdk::bitcoin::secp256k1::SecretKey;
+
+    /// Returns a wallet of the private key instead of a key derived from a mnemonic
+    fn wallet_from_secret(secret: &[u8]) -> EthereumWallet {
+        let secp = Secp256k1::new();
+        let mut xprv =
+            ExtendedPrivKey::new_master(bdk::bitcoin::Network::Bitcoin, &[0; 32]).unwrap();
+        xprv.private_key = SecretKey::from_slice(secret).unwrap();
+        let xpub = ExtendedPubKey::from_priv(&secp, &xprv);
+        EthereumWallet {
+            address_format: EthereumFormat::Checksummed,
+            chain_id: 1,
+            derivation_path: DerivationPath::master(),
+            public_address: EthereumPublicKey(xpub.public_key)
+                .to_public_address(EthereumFormat::Checksummed)
+                .unwrap(),
+            private_key: Some(xprv),
+            public_key: Some(xpub),
+        }
+    }
+
+    #[test]
+    fn test_sign_typed_data() -> Result<(), Error> {
+        let wallet = wallet_from_secret(&ethers::utils::keccak256("cow"));
+        assert_eq!(wallet.public_address(), EIP712_SIGNER);
+        let signature = wallet.sign_typed_data(EIP712_MAIL)?;
+        assert_eq!(signature.to_string(), EIP712_SIGNATURE);
+        assert_eq!(
+            crate::recover_typed_data_signer(EIP712_MAIL, &signature)?,
+            wallet.public_address()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_message() -> Result<(), Error> {
+        let wallet = wallet_from_secret(
+            &hex::decode("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318")
+                .unwrap(),
+        );
+        assert_eq!(wallet.public_address(), EIP191_SIGNER);
+        let signature = wallet.sign_message(EIP191_MESSAGE)?;
+        assert_eq!(signature.to_string(), EIP191_SIGNATURE);
+
+        let mnemonic =
+            Mnemonic::parse("outer ride neither foil glue number place usage ball shed dry point")
+                .unwrap();
+        let wallet = EthereumWallet::builder().mnemonic(mnemonic).build()?;
+        let signature = wallet.sign_message(b"walletd login")?;
+        assert!(crate::verify_message(
+            "walletd login",
+            &signature,
+            &wallet.public_address()
+        )?);
+        Ok(())
+    }
 }
@@ -25,6 +25,25 @@
 //! ```
 //! We see that by default the Ethereum wallet uses the derivation path "m/44'/60'/0'/0/" corresponding to BIP44 for the purpose value and 60' corresponding to the coin type for Ethereum.
 //!
+//! ### Multiple Accounts and Addresses
+//! The account and address indices of the derivation path are set on the builder, and [build_wallets][EthereumWalletBuilder::build_wallets] derives the wallets of several successive addresses.
+//! Funds of other wallets are imported by choosing their [derivation scheme][EthereumDerivationScheme]: Ledger Live derives m/44'/60'/account_index'/0/0 and the legacy MyEtherWallet m/44'/60'/0'/address_index.
+//! ```
+//! # use walletd_ethereum::prelude::*;
+//! # fn ethereum() -> Result<(), walletd_ethereum::Error> {
+//! let mnemonic = Mnemonic::parse("joy tail arena mix other envelope diary achieve short nest true vocal").unwrap();
+//! let second_address = EthereumWallet::builder().mnemonic(mnemonic.clone()).address_index(1).build()?;
+//! let ledger_live_accounts = EthereumWallet::builder()
+//!     .mnemonic(mnemonic)
+//!     .derivation_scheme(EthereumDerivationScheme::LedgerLive)
+//!     .build_wallets(5)?;
+//! for wallet in ledger_live_accounts {
+//!     println!("{}: {}", wallet.derivation_path(), wallet.public_address());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! We need to add a blockchain connector to our [ethereum wallet][EthereumWallet] to be able to interact with the Ethereum blockchain.
 //!
 //!
@@ -80,17 +99,112 @@
 //! # }
 //! ```
 //!
+//! ### Send ETH
+//! [EthereumWallet::transfer] sends a type-2 (EIP-1559) transaction on the chain id the wallet was built with, which must match the chain of the provider.
+//! The gas limit is estimated with `eth_estimateGas` and the fees from the `eth_feeHistory` of the recent blocks, [TransactionOverrides] sets them instead.
+//! ```no_run
+//! # use ethers::prelude::*;
+//! # use walletd_ethereum::prelude::*;
+//! # async fn ethereum() -> Result<(), walletd_ethereum::Error> {
+//! let mnemonic_phrase = "mandate rude write gather vivid inform leg swift usual early bamboo element";
+//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let ethereum_wallet = EthereumWallet::builder().mnemonic(mnemonic).chain_id(11155111).build()?;
+//! let provider = Provider::try_from("https://rpc.sepolia.org").unwrap();
+//! let to_address = "0x681dA56258fF429026449F1435aE87e1B6e9F85b";
+//! let tx_hash = ethereum_wallet.transfer(&provider, EthereumAmount::from_eth(0.001), to_address).await?;
+//! println!("sent in transaction {}", tx_hash);
+//!
+//! let mut overrides = TransactionOverrides::new();
+//! overrides.gas_limit(21_000).max_priority_fee_per_gas(EthereumAmount::from_wei(2_000_000_000u64.into()));
+//! let tx_hash = ethereum_wallet.transfer_with_overrides(&provider, EthereumAmount::from_eth(0.001), to_address, &overrides).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### ERC-20 Tokens
+//! [EthClient] reads the balances, allowances and metadata of ERC-20 tokens, and the wallet transfers its tokens or lets another address spend them.
+//! Token amounts are [TokenAmount]s counted in the base units of the token with its number of decimals, which must match the decimals of the token when sending.
+//! ```no_run
+//! # use ethers::prelude::*;
+//! # use walletd_ethereum::prelude::*;
+//! # async fn ethereum(ethereum_wallet: EthereumWallet, provider: Provider<Http>) -> Result<(), walletd_ethereum::Error> {
+//! let usdc = "0x1c7D4B196Cb0C7B01d743Fbc6116a902379C7238";
+//! let metadata = EthClient::token_metadata(&provider, usdc.parse().unwrap()).await?;
+//! let balance = ethereum_wallet.token_balance(&provider, usdc).await?;
+//! println!("balance: {} {}", balance, metadata.symbol);
+//!
+//! let amount = TokenAmount::from_decimal_str("12.5", metadata.decimals)?;
+//! let tx_hash = ethereum_wallet.transfer_token(&provider, usdc, amount, "0x681dA56258fF429026449F1435aE87e1B6e9F85b").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### NFTs
+//! [EthClient::nft_standard] detects with ERC-165 whether a contract is an ERC-721 or ERC-1155 collection, [EthClient] reads the owners, balances and metadata URIs of its tokens and the wallet transfers them.
+//! ```no_run
+//! # use ethers::prelude::*;
+//! # use walletd_ethereum::prelude::*;
+//! # async fn ethereum(ethereum_wallet: EthereumWallet, provider: Provider<Http>) -> Result<(), walletd_ethereum::Error> {
+//! let collection = "0x1a92f7381b9f03921564a437210bb9396471050c";
+//! let to_address = "0x681dA56258fF429026449F1435aE87e1B6e9F85b";
+//! match EthClient::nft_standard(&provider, collection.parse().unwrap()).await? {
+//!     Some(NftStandard::Erc721) => {
+//!         let uri = EthClient::erc721_token_uri(&provider, collection.parse().unwrap(), 42.into()).await?;
+//!         println!("token 42: {}", uri);
+//!         ethereum_wallet.transfer_erc721(&provider, collection, 42.into(), to_address).await?;
+//!     }
+//!     Some(NftStandard::Erc1155) => {
+//!         ethereum_wallet.transfer_erc1155_batch(&provider, collection, &[42.into()], &[1.into()], to_address).await?;
+//!     }
+//!     None => println!("not an NFT collection"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ### Sign Messages and Typed Data
+//! [EthereumWallet::sign_message] signs a message following EIP-191 (`personal_sign`), as used to log in to dApps, and [EthereumWallet::sign_typed_data] signs EIP-712 typed data such as permits.
+//! [recover_message_signer] and [recover_typed_data_signer] return the address which signed them.
+//! ```
+//! # use walletd_ethereum::prelude::*;
+//! # fn ethereum() -> Result<(), walletd_ethereum::Error> {
+//! let mnemonic = Mnemonic::parse("joy tail arena mix other envelope diary achieve short nest true vocal").unwrap();
+//! let ethereum_wallet = EthereumWallet::builder().mnemonic(mnemonic).build()?;
+//! let signature = ethereum_wallet.sign_message("Sign in to example.com")?;
+//! let signer = walletd_ethereum::recover_message_signer("Sign in to example.com", &signature)?;
+//! assert_eq!(signer, ethereum_wallet.public_address());
+//! println!("signature: 0x{}", signature);
+//! # Ok(())
+//! # }
+//! ```
+//!
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
 use core::fmt;
 
+mod derivation;
+pub use derivation::EthereumDerivationScheme;
 mod ethclient;
-pub use ethclient::EthClient;
+pub use ethclient::{EthClient, TokenMetadata};
 mod ethereum_amount;
 pub use ethereum_amount::EthereumAmount;
 mod ethereum_wallet;
 pub use ethereum_wallet::{EthereumWallet, EthereumWalletBuilder};
+mod nft;
+pub use nft::{
+    NftStandard, ERC1155_INTERFACE_ID, ERC1155_METADATA_URI_INTERFACE_ID, ERC165_INTERFACE_ID,
+    ERC721_INTERFACE_ID, ERC721_METADATA_INTERFACE_ID,
+};
+mod signing;
+pub use signing::{
+    message_hash, recover_message_signer, recover_typed_data_signer, typed_data_hash,
+    verify_message, verify_typed_data,
+};
+mod token_amount;
+pub use token_amount::TokenAmount;
+mod transaction;
+pub use transaction::{Eip1559Fees, TransactionOverrides};
 mod error;
 pub use error::Error;
 pub use ethers;
@@ -0,0 +1,116 @@
+use core::fmt;
+use ethers::types::U256;
+
+/// The ERC-165 interface id of ERC-165 itself
+pub const ERC165_INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+/// The ERC-165 interface id of ERC-721
+pub const ERC721_INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+/// The ERC-165 interface id of the metadata extension of ERC-721, which defines `tokenURI`
+pub const ERC721_METADATA_INTERFACE_ID: [u8; 4] = [0x5b, 0x5e, 0x13, 0x9f];
+/// The ERC-165 interface id of ERC-1155
+pub const ERC1155_INTERFACE_ID: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+/// The ERC-165 interface id of the metadata URI extension of ERC-1155, which defines `uri`
+pub const ERC1155_METADATA_URI_INTERFACE_ID: [u8; 4] = [0x0e, 0x89, 0x34, 0x1c];
+/// The interface id that no ERC-165 contract supports
+pub(crate) const INVALID_INTERFACE_ID: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+
+/// Represents the NFT standard a contract implements, as detected by [EthClient::nft_standard][crate::EthClient::nft_standard]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NftStandard {
+    /// ERC-721, where each token id is a unique token with a single owner
+    Erc721,
+    /// ERC-1155, where each token id has a balance per owner
+    Erc1155,
+}
+
+impl NftStandard {
+    /// Returns the ERC-165 interface id of the standard
+    pub fn interface_id(&self) -> [u8; 4] {
+        match self {
+            NftStandard::Erc721 => ERC721_INTERFACE_ID,
+            NftStandard::Erc1155 => ERC1155_INTERFACE_ID,
+        }
+    }
+}
+
+impl fmt::Display for NftStandard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NftStandard::Erc721 => write!(f, "ERC-721"),
+            NftStandard::Erc1155 => write!(f, "ERC-1155"),
+        }
+    }
+}
+
+/// Replaces the `{id}` placeholder of an ERC-1155 URI with the token id as 64 lowercase hex digits, as required by the standard
+pub(crate) fn erc1155_token_uri(uri: &str, id: U256) -> String {
+    let mut id_bytes = [0u8; 32];
+    id.to_big_endian(&mut id_bytes);
+    uri.replace("{id}", &hex::encode(id_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erc1155_token_uri() {
+        // the example of the ERC-1155 specification
+        assert_eq!(
+            erc1155_token_uri("https://token-cdn-domain/{id}.json", 314592.into()),
+            "https://token-cdn-domain/000000000000000000000000000000000000000000000000000000000004cce0.json"
+        );
+        assert_eq!(
+            erc1155_token_uri("ipfs://fixed.json", 1.into()),
+            "ipfs://fixed.json"
+        );
+    }
+
+    #[test]
+    fn test_interface_ids() {
+        // an interface id is the xor of the selectors of its functions
+        let selector = |signature: &str| ethers::utils::id(signature);
+        let xor = |signatures: &[&str]| {
+            signatures.iter().fold([0u8; 4], |id, signature| {
+                let selector = selector(signature);
+                [
+                    id[0] ^ selector[0],
+                    id[1] ^ selector[1],
+                    id[2] ^ selector[2],
+                    id[3] ^ selector[3],
+                ]
+            })
+        };
+        assert_eq!(xor(&["supportsInterface(bytes4)"]), ERC165_INTERFACE_ID);
+        assert_eq!(
+            xor(&["name()", "symbol()", "tokenURI(uint256)"]),
+            ERC721_METADATA_INTERFACE_ID
+        );
+        assert_eq!(xor(&["uri(uint256)"]), ERC1155_METADATA_URI_INTERFACE_ID);
+        assert_eq!(
+            xor(&[
+                "safeTransferFrom(address,address,uint256,uint256,bytes)",
+                "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+                "balanceOf(address,uint256)",
+                "balanceOfBatch(address[],uint256[])",
+                "setApprovalForAll(address,bool)",
+                "isApprovedForAll(address,address)",
+            ]),
+            NftStandard::Erc1155.interface_id()
+        );
+        assert_eq!(
+            xor(&[
+                "balanceOf(address)",
+                "ownerOf(uint256)",
+                "safeTransferFrom(address,address,uint256,bytes)",
+                "safeTransferFrom(address,address,uint256)",
+                "transferFrom(address,address,uint256)",
+                "approve(address,uint256)",
+                "setApprovalForAll(address,bool)",
+                "getApproved(uint256)",
+                "isApprovedForAll(address,address)",
+            ]),
+            NftStandard::Erc721.interface_id()
+        );
+    }
+}
@@ -5,7 +5,11 @@
 //! use walletd_ethereum::prelude::*;
 //! ```
 
-pub use crate::{EthClient, EthereumAmount, EthereumFormat, EthereumWallet, EthereumWalletBuilder};
+pub use crate::{
+    Eip1559Fees, EthClient, EthereumAmount, EthereumDerivationScheme, EthereumFormat,
+    EthereumWallet, EthereumWalletBuilder, NftStandard, TokenAmount, TokenMetadata,
+    TransactionOverrides,
+};
 
 pub use bdk::keys::bip39::Mnemonic;
 pub use ethers::types::Transaction;
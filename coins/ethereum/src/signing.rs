@@ -0,0 +1,164 @@
+use crate::Error;
+use ethers::types::transaction::eip712::{Eip712, TypedData};
+use ethers::types::{Address, Signature, H256};
+use ethers::utils::{hash_message, to_checksum};
+use std::str::FromStr;
+
+/// Returns the hash signed for a message by [EthereumWallet::sign_message][crate::EthereumWallet::sign_message], which prefixes the message following EIP-191 (`personal_sign`)
+pub fn message_hash(message: impl AsRef<[u8]>) -> H256 {
+    hash_message(message)
+}
+
+/// Returns the hash signed for EIP-712 typed data by [EthereumWallet::sign_typed_data][crate::EthereumWallet::sign_typed_data]
+///
+/// The typed data is the JSON object with the `types`, `primaryType`, `domain` and `message` fields passed to `eth_signTypedData_v4`.
+pub fn typed_data_hash(typed_data: &str) -> Result<H256, Error> {
+    let typed_data: TypedData = serde_json::from_str(typed_data)
+        .map_err(|e| Error::FromStr(format!("Invalid EIP-712 typed data: {}", e)))?;
+    let hash = typed_data
+        .encode_eip712()
+        .map_err(|e| Error::Signature(e.to_string()))?;
+    Ok(H256(hash))
+}
+
+/// Returns the checksummed address of the signer of an EIP-191 signed message
+pub fn recover_message_signer(
+    message: impl AsRef<[u8]>,
+    signature: &Signature,
+) -> Result<String, Error> {
+    recover_signer(message_hash(message), signature)
+}
+
+/// Returns the checksummed address of the signer of EIP-712 typed data
+pub fn recover_typed_data_signer(typed_data: &str, signature: &Signature) -> Result<String, Error> {
+    recover_signer(typed_data_hash(typed_data)?, signature)
+}
+
+/// Returns whether the EIP-191 signed message was signed by the address
+pub fn verify_message(
+    message: impl AsRef<[u8]>,
+    signature: &Signature,
+    address: &str,
+) -> Result<bool, Error> {
+    is_signer(&recover_message_signer(message, signature)?, address)
+}
+
+/// Returns whether the EIP-712 typed data was signed by the address
+pub fn verify_typed_data(
+    typed_data: &str,
+    signature: &Signature,
+    address: &str,
+) -> Result<bool, Error> {
+    is_signer(&recover_typed_data_signer(typed_data, signature)?, address)
+}
+
+/// Returns the checksummed address of the key which signed the hash
+fn recover_signer(hash: H256, signature: &Signature) -> Result<String, Error> {
+    let address = signature
+        .recover(hash)
+        .map_err(|e| Error::Signature(e.to_string()))?;
+    Ok(to_checksum(&address, None))
+}
+
+/// Compares the recovered signer with an address in any letter case
+fn is_signer(signer: &str, address: &str) -> Result<bool, Error> {
+    let address = Address::from_str(address).map_err(|e| Error::FromStr(e.to_string()))?;
+    Ok(Address::from_str(signer).map_err(|e| Error::FromStr(e.to_string()))? == address)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// The typed data of the example of the EIP-712 specification
+    pub(crate) const EIP712_MAIL: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person" },
+                { "name": "contents", "type": "string" }
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+    /// The signer of the EIP-712 example, whose private key is keccak256("cow")
+    pub(crate) const EIP712_SIGNER: &str = "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826";
+    /// The signature of the EIP-712 example, r, s and v
+    pub(crate) const EIP712_SIGNATURE: &str = "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c";
+
+    /// The message, signer and signature of the `web3.eth.accounts.sign` example
+    pub(crate) const EIP191_MESSAGE: &str = "Some data";
+    pub(crate) const EIP191_SIGNER: &str = "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23";
+    pub(crate) const EIP191_SIGNATURE: &str = "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+
+    #[test]
+    fn test_typed_data_hash() -> Result<(), Error> {
+        assert_eq!(
+            format!("{:?}", typed_data_hash(EIP712_MAIL)?),
+            "0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+        assert!(matches!(
+            typed_data_hash(r#"{"types": {}}"#),
+            Err(Error::FromStr(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_typed_data_signer() -> Result<(), Error> {
+        let signature = Signature::from_str(EIP712_SIGNATURE).unwrap();
+        assert_eq!(
+            recover_typed_data_signer(EIP712_MAIL, &signature)?,
+            EIP712_SIGNER
+        );
+        assert!(verify_typed_data(
+            EIP712_MAIL,
+            &signature,
+            &EIP712_SIGNER.to_lowercase()
+        )?);
+        let tampered = EIP712_MAIL.replace("Hello, Bob!", "Hello, Eve!");
+        assert!(!verify_typed_data(&tampered, &signature, EIP712_SIGNER)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_message_signer() -> Result<(), Error> {
+        let signature = Signature::from_str(EIP191_SIGNATURE).unwrap();
+        assert_eq!(
+            format!("{:?}", message_hash(EIP191_MESSAGE)),
+            "0x1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655"
+        );
+        assert_eq!(
+            recover_message_signer(EIP191_MESSAGE, &signature)?,
+            EIP191_SIGNER
+        );
+        assert!(verify_message(EIP191_MESSAGE, &signature, EIP191_SIGNER)?);
+        assert!(!verify_message("Other data", &signature, EIP191_SIGNER)?);
+        assert!(matches!(
+            verify_message(EIP191_MESSAGE, &signature, "not an address"),
+            Err(Error::FromStr(_))
+        ));
+        Ok(())
+    }
+}
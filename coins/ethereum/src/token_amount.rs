@@ -0,0 +1,208 @@
+use crate::Error;
+use core::fmt;
+use ethers::types::U256;
+use std::ops;
+
+/// An amount of an ERC-20 token, counted in the base units of the token with the number of decimals of the token
+///
+/// The decimal representation of the amount divides the base units by 10^decimals, so that 1.5 tokens with 6 decimals are 1500000 base units.
+/// ```
+/// use walletd_ethereum::TokenAmount;
+/// # fn token() -> Result<(), walletd_ethereum::Error> {
+/// let amount = TokenAmount::from_decimal_str("1.5", 6)?;
+/// assert_eq!(amount.base_units(), 1_500_000.into());
+/// assert_eq!(amount.to_string(), "1.5");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub struct TokenAmount {
+    /// The number of base units (U256) in the amount
+    pub base_units: U256,
+    /// The number of decimals of the token
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    /// Creates a new TokenAmount from the number of base units of a token with `decimals` decimals
+    pub fn from_base_units(base_units: U256, decimals: u8) -> Self {
+        Self {
+            base_units,
+            decimals,
+        }
+    }
+
+    /// Parses a decimal value, such as `12.345`, of a token with `decimals` decimals
+    ///
+    /// The parsing is exact: returns [Error::FromStr] if the value has more fractional digits than the token has decimals and [Error::Overflow] if it does not fit in a U256.
+    pub fn from_decimal_str(value: &str, decimals: u8) -> Result<Self, Error> {
+        let invalid = || {
+            Error::FromStr(format!(
+                "Invalid amount {} of a token with {} decimals",
+                value, decimals
+            ))
+        };
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if (whole.is_empty() && fraction.is_empty())
+            || fraction.len() > decimals.into()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let digits = format!(
+            "{}{:0<width$}",
+            whole,
+            fraction,
+            width = usize::from(decimals)
+        );
+        let digits = match digits.trim_start_matches('0') {
+            "" => "0",
+            digits => digits,
+        };
+        let base_units = U256::from_dec_str(digits)
+            .map_err(|_| Error::Overflow(format!("Token amount {} is too large", value)))?;
+        Ok(Self::from_base_units(base_units, decimals))
+    }
+
+    /// Returns the number of base units in the amount
+    pub fn base_units(&self) -> U256 {
+        self.base_units
+    }
+
+    /// Returns the number of decimals of the token
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the amount as a decimal string, without trailing zeros
+    pub fn to_decimal_string(&self) -> String {
+        let digits = format!(
+            "{:0>width$}",
+            self.base_units.to_string(),
+            width = usize::from(self.decimals) + 1
+        );
+        let (whole, fraction) = digits.split_at(digits.len() - usize::from(self.decimals));
+        match fraction.trim_end_matches('0') {
+            "" => whole.to_string(),
+            fraction => format!("{}.{}", whole, fraction),
+        }
+    }
+
+    /// Returns an error unless both amounts have the same number of decimals
+    fn check_decimals(&self, rhs: &Self) -> Result<(), Error> {
+        if self.decimals != rhs.decimals {
+            return Err(Error::TokenDecimals {
+                expected: self.decimals,
+                found: rhs.decimals,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_decimal_string())
+    }
+}
+
+impl ops::Add<Self> for TokenAmount {
+    type Output = Result<Self, Error>;
+
+    fn add(self, rhs: Self) -> Result<Self, Error> {
+        self.check_decimals(&rhs)?;
+        let base_units = self
+            .base_units
+            .checked_add(rhs.base_units)
+            .ok_or(Error::Overflow(format!(
+                "Overflow in U256 when adding {} to {}",
+                self.base_units, rhs.base_units
+            )))?;
+        Ok(Self::from_base_units(base_units, self.decimals))
+    }
+}
+
+impl ops::Sub for TokenAmount {
+    type Output = Result<Self, Error>;
+
+    fn sub(self, rhs: Self) -> Result<Self, Error> {
+        self.check_decimals(&rhs)?;
+        let base_units = self
+            .base_units
+            .checked_sub(rhs.base_units)
+            .ok_or(Error::Overflow(format!(
+                "Overflow in U256 when subtracting {} from {}",
+                self.base_units, rhs.base_units
+            )))?;
+        Ok(Self::from_base_units(base_units, self.decimals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() -> Result<(), Error> {
+        for (value, decimals, base_units, formatted) in [
+            ("1.5", 6, 1_500_000u64, "1.5"),
+            ("0.000001", 6, 1, "0.000001"),
+            (".25", 2, 25, "0.25"),
+            ("42", 0, 42, "42"),
+            ("007.10", 18, 7_100_000_000_000_000_000, "7.1"),
+            ("0", 18, 0, "0"),
+        ] {
+            let amount = TokenAmount::from_decimal_str(value, decimals)?;
+            assert_eq!(amount.base_units(), base_units.into());
+            assert_eq!(amount.decimals(), decimals);
+            assert_eq!(amount.to_string(), formatted);
+        }
+        assert_eq!(
+            TokenAmount::from_base_units(U256::MAX, 77).to_decimal_string(),
+            "1.15792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid_amounts() {
+        for (value, decimals) in [
+            ("", 6),
+            (".", 6),
+            ("1.5", 0),
+            ("1.0000001", 6),
+            ("1,5", 6),
+            ("-1", 6),
+            ("1e3", 6),
+        ] {
+            assert!(matches!(
+                TokenAmount::from_decimal_str(value, decimals),
+                Err(Error::FromStr(_))
+            ));
+        }
+        assert!(matches!(
+            TokenAmount::from_decimal_str(&"9".repeat(80), 0),
+            Err(Error::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_arithmetic() -> Result<(), Error> {
+        let one = TokenAmount::from_decimal_str("1", 6)?;
+        let half = TokenAmount::from_decimal_str("0.5", 6)?;
+        assert_eq!((one + half)?.to_string(), "1.5");
+        assert_eq!((one - half)?, half);
+        assert!(matches!(half - one, Err(Error::Overflow(_))));
+        assert!(matches!(
+            one + TokenAmount::from_decimal_str("1", 18)?,
+            Err(Error::TokenDecimals {
+                expected: 6,
+                found: 18
+            })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use crate::Error;
+use crate::EthereumAmount;
+use ethers::types::{FeeHistory, U256};
+
+/// The number of recent blocks sampled by `eth_feeHistory` to estimate the fees of a transaction
+pub(crate) const FEE_HISTORY_BLOCKS: u64 = 10;
+/// The percentile of the priority fees paid in each sampled block
+pub(crate) const FEE_HISTORY_REWARD_PERCENTILE: f64 = 50.0;
+/// The priority fee used when none of the sampled blocks paid one, 1 gwei
+const DEFAULT_PRIORITY_FEE: u64 = 1_000_000_000;
+
+/// The fees per gas of a type-2 (EIP-1559) transaction, estimated from the `eth_feeHistory` of the recent blocks by [EthClient::estimate_fees][crate::EthClient::estimate_fees]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Eip1559Fees {
+    /// The base fee per gas of the next block
+    pub base_fee_per_gas: EthereumAmount,
+    /// The tip per gas paid to the block producer, the median of the priority fees paid in the sampled blocks
+    pub max_priority_fee_per_gas: EthereumAmount,
+    /// The maximum fee per gas, twice the base fee plus the priority fee so that the transaction stays valid over several blocks of rising base fee
+    pub max_fee_per_gas: EthereumAmount,
+}
+
+impl Eip1559Fees {
+    /// Estimates the fees from the result of `eth_feeHistory`
+    ///
+    /// Returns [Error::Provider] if the node did not return the base fee of the next block, which happens when the chain does not support EIP-1559.
+    pub(crate) fn from_fee_history(history: &FeeHistory) -> Result<Self, Error> {
+        let base_fee_per_gas = *history.base_fee_per_gas.last().ok_or_else(|| {
+            Error::Provider("no base fee in eth_feeHistory, EIP-1559 is not supported".into())
+        })?;
+        let mut rewards: Vec<U256> = history
+            .reward
+            .iter()
+            .filter_map(|block| block.first().copied())
+            .filter(|reward| !reward.is_zero())
+            .collect();
+        rewards.sort();
+        let max_priority_fee_per_gas = match rewards.len() {
+            0 => U256::from(DEFAULT_PRIORITY_FEE),
+            len => rewards[len / 2],
+        };
+        Ok(Self::with_priority_fee(
+            base_fee_per_gas,
+            max_priority_fee_per_gas,
+        ))
+    }
+
+    /// Returns the fees paying the priority fee on top of the base fee
+    pub(crate) fn with_priority_fee(
+        base_fee_per_gas: U256,
+        max_priority_fee_per_gas: U256,
+    ) -> Self {
+        let max_fee_per_gas = base_fee_per_gas
+            .saturating_mul(2.into())
+            .saturating_add(max_priority_fee_per_gas);
+        Self {
+            base_fee_per_gas: EthereumAmount::from_wei(base_fee_per_gas),
+            max_priority_fee_per_gas: EthereumAmount::from_wei(max_priority_fee_per_gas),
+            max_fee_per_gas: EthereumAmount::from_wei(max_fee_per_gas),
+        }
+    }
+}
+
+/// Overrides the values [EthereumWallet][crate::EthereumWallet] otherwise fills in when sending a transaction
+///
+/// By default the gas limit is estimated with `eth_estimateGas`, the fees with `eth_feeHistory` and the nonce is the next one of the wallet's address.
+/// ```
+/// use walletd_ethereum::{EthereumAmount, TransactionOverrides};
+/// let mut overrides = TransactionOverrides::new();
+/// overrides
+///     .gas_limit(21_000)
+///     .max_priority_fee_per_gas(EthereumAmount::from_wei(2_000_000_000u64.into()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionOverrides {
+    pub(crate) gas_limit: Option<u64>,
+    pub(crate) max_fee_per_gas: Option<EthereumAmount>,
+    pub(crate) max_priority_fee_per_gas: Option<EthereumAmount>,
+    pub(crate) nonce: Option<u64>,
+}
+
+impl TransactionOverrides {
+    /// Creates overrides which leave every value to be filled in
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the gas limit instead of estimating it
+    pub fn gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the maximum fee per gas instead of estimating it, it must be at least an overridden priority fee and caps an estimated one
+    pub fn max_fee_per_gas(&mut self, max_fee_per_gas: EthereumAmount) -> &mut Self {
+        self.max_fee_per_gas = Some(max_fee_per_gas);
+        self
+    }
+
+    /// Sets the priority fee per gas instead of estimating it, the estimated maximum fee includes it
+    pub fn max_priority_fee_per_gas(
+        &mut self,
+        max_priority_fee_per_gas: EthereumAmount,
+    ) -> &mut Self {
+        self.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        self
+    }
+
+    /// Sets the nonce, for example to replace a pending transaction
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Returns whether the fees have to be estimated because they are not both overridden
+    pub(crate) fn estimates_fees(&self) -> bool {
+        self.max_fee_per_gas.is_none() || self.max_priority_fee_per_gas.is_none()
+    }
+
+    /// Returns the maximum fee and the priority fee per gas, the ones not overridden are taken from the estimated fees
+    ///
+    /// An estimated priority fee is capped at an overridden maximum fee.
+    /// Returns [Error::PriorityFeeAboveMaxFee] if both are overridden and the priority fee is above the maximum fee.
+    pub(crate) fn fees_per_gas(
+        &self,
+        estimated: Option<&Eip1559Fees>,
+    ) -> Result<(EthereumAmount, EthereumAmount), Error> {
+        match (
+            self.max_fee_per_gas,
+            self.max_priority_fee_per_gas,
+            estimated,
+        ) {
+            (Some(max_fee), Some(priority_fee), _) => {
+                if priority_fee > max_fee {
+                    return Err(Error::PriorityFeeAboveMaxFee {
+                        max_fee: max_fee.wei(),
+                        priority_fee: priority_fee.wei(),
+                    });
+                }
+                Ok((max_fee, priority_fee))
+            }
+            (Some(max_fee), None, Some(fees)) => {
+                Ok((max_fee, fees.max_priority_fee_per_gas.min(max_fee)))
+            }
+            (None, Some(priority_fee), Some(fees)) => {
+                let fees =
+                    Eip1559Fees::with_priority_fee(fees.base_fee_per_gas.wei(), priority_fee.wei());
+                Ok((fees.max_fee_per_gas, fees.max_priority_fee_per_gas))
+            }
+            (None, None, Some(fees)) => Ok((fees.max_fee_per_gas, fees.max_priority_fee_per_gas)),
+            (_, _, None) => Err(Error::Provider("the fees were not estimated".into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_history(base_fees: &[u64], rewards: &[u64]) -> FeeHistory {
+        FeeHistory {
+            base_fee_per_gas: base_fees.iter().map(|fee| U256::from(*fee)).collect(),
+            gas_used_ratio: vec![0.5; rewards.len()],
+            oldest_block: U256::zero(),
+            reward: rewards.iter().map(|fee| vec![U256::from(*fee)]).collect(),
+        }
+    }
+
+    #[test]
+    fn test_fees_from_fee_history() -> Result<(), Error> {
+        let history = fee_history(&[100, 110, 120, 130], &[3, 0, 1]);
+        let fees = Eip1559Fees::from_fee_history(&history)?;
+        assert_eq!(fees.base_fee_per_gas.wei(), 130.into());
+        // the empty block is ignored
+        assert_eq!(fees.max_priority_fee_per_gas.wei(), 3.into());
+        assert_eq!(fees.max_fee_per_gas.wei(), 263.into());
+
+        let fees = Eip1559Fees::from_fee_history(&fee_history(&[7, 8], &[0]))?;
+        assert_eq!(
+            fees.max_priority_fee_per_gas.wei(),
+            DEFAULT_PRIORITY_FEE.into()
+        );
+        assert_eq!(
+            fees.max_fee_per_gas.wei(),
+            (DEFAULT_PRIORITY_FEE + 16).into()
+        );
+
+        assert!(matches!(
+            Eip1559Fees::from_fee_history(&fee_history(&[], &[])),
+            Err(Error::Provider(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_overrides() {
+        assert_eq!(TransactionOverrides::new(), TransactionOverrides::default());
+        let mut overrides = TransactionOverrides::new();
+        overrides.gas_limit(50_000).nonce(3);
+        assert_eq!(overrides.gas_limit, Some(50_000));
+        assert_eq!(overrides.nonce, Some(3));
+        assert_eq!(overrides.max_fee_per_gas, None);
+    }
+
+    #[test]
+    fn test_fees_per_gas() -> Result<(), Error> {
+        let estimated = Eip1559Fees::with_priority_fee(100.into(), 30.into());
+        let wei = |amount: u64| EthereumAmount::from_wei(amount.into());
+
+        let overrides = TransactionOverrides::new();
+        assert!(overrides.estimates_fees());
+        assert_eq!(
+            overrides.fees_per_gas(Some(&estimated))?,
+            (wei(230), wei(30))
+        );
+
+        let mut overrides = TransactionOverrides::new();
+        overrides.max_priority_fee_per_gas(wei(50));
+        assert_eq!(
+            overrides.fees_per_gas(Some(&estimated))?,
+            (wei(250), wei(50))
+        );
+
+        let mut overrides = TransactionOverrides::new();
+        overrides
+            .max_fee_per_gas(wei(25))
+            .max_priority_fee_per_gas(wei(5));
+        assert!(!overrides.estimates_fees());
+        assert_eq!(overrides.fees_per_gas(None)?, (wei(25), wei(5)));
+
+        overrides.max_priority_fee_per_gas(wei(26));
+        assert!(matches!(
+            overrides.fees_per_gas(None),
+            Err(Error::PriorityFeeAboveMaxFee { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fees_per_gas_with_max_fee_override() -> Result<(), Error> {
+        let estimated = Eip1559Fees::with_priority_fee(100.into(), 30.into());
+        let wei = |amount: u64| EthereumAmount::from_wei(amount.into());
+
+        let mut overrides = TransactionOverrides::new();
+        overrides.max_fee_per_gas(wei(500));
+        assert!(overrides.estimates_fees());
+        assert_eq!(
+            overrides.fees_per_gas(Some(&estimated))?,
+            (wei(500), wei(30))
+        );
+
+        // the estimated priority fee is capped at the maximum fee
+        overrides.max_fee_per_gas(wei(20));
+        assert_eq!(
+            overrides.fees_per_gas(Some(&estimated))?,
+            (wei(20), wei(20))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,394 @@
+//! End-to-end tests of [EthereumWallet] against a local Anvil node, which run without any network access.
+//!
+//! Each test starts its own node from the executable in the `ANVIL_EXE` environment variable or the `anvil` found in the `PATH`.
+//! The tests are ignored by default, run them with `cargo test --test anvil -- --ignored`; they fail when no executable is available,
+//! and the tests of the contracts of the `tests/contracts` directory also when no `solc` compiler is available.
+
+use bdk::keys::bip39::Mnemonic;
+use ethers::prelude::*;
+use ethers::utils::{to_checksum, Anvil, AnvilInstance};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use walletd_ethereum::prelude::*;
+use walletd_ethereum::Error;
+
+/// The mnemonic of the accounts funded by the node, the wallet derives its first account
+const MNEMONIC: &str = "outer ride neither foil glue number place usage ball shed dry point";
+const CHAIN_ID: u64 = 1337;
+const GWEI: u64 = 1_000_000_000;
+
+/// Returns the path of the anvil executable if there is one
+fn anvil_exe() -> Option<PathBuf> {
+    if let Some(exe) = std::env::var_os("ANVIL_EXE") {
+        return Some(exe.into());
+    }
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join("anvil"))
+        .find(|exe| exe.is_file())
+}
+
+/// Starts a node and returns it with a provider connected to it, panics if no anvil executable is available
+fn start() -> (AnvilInstance, Provider<Http>) {
+    let exe = anvil_exe().expect("no anvil executable found");
+    let anvil = Anvil::at(exe).chain_id(CHAIN_ID).mnemonic(MNEMONIC).spawn();
+    let provider = Provider::<Http>::try_from(anvil.endpoint())
+        .unwrap()
+        .interval(Duration::from_millis(10));
+    (anvil, provider)
+}
+
+/// Returns the wallet of the first funded account, built for the chain id
+fn wallet(chain_id: u64) -> Result<EthereumWallet, Error> {
+    EthereumWallet::builder()
+        .mnemonic(Mnemonic::parse(MNEMONIC).unwrap())
+        .chain_id(chain_id)
+        .build()
+}
+
+/// Returns the wallet of a funded account other than the first one
+fn other_wallet(address_index: u32) -> Result<EthereumWallet, Error> {
+    EthereumWallet::builder()
+        .mnemonic(Mnemonic::parse(MNEMONIC).unwrap())
+        .chain_id(CHAIN_ID)
+        .address_index(address_index)
+        .build()
+}
+
+/// Compiles a contract of the `tests/contracts` directory and deploys it from the first account, panics if no solc executable is available
+async fn deploy<T: abi::Tokenize>(
+    anvil: &AnvilInstance,
+    provider: &Provider<Http>,
+    file: &str,
+    contract: &str,
+    constructor_args: T,
+) -> Address {
+    let solc = Solc::default();
+    solc.version().expect("no solc executable found");
+    let source = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/contracts")
+        .join(file);
+    let compiled = solc.compile_source(source).unwrap();
+    let (abi, bytecode, _) = compiled.find(contract).unwrap().into_parts_or_default();
+    let signer = LocalWallet::from(anvil.keys()[0].clone()).with_chain_id(CHAIN_ID);
+    let client = Arc::new(SignerMiddleware::new(provider.clone(), signer));
+    let deployed = ContractFactory::new(abi, bytecode, client)
+        .deploy(constructor_args)
+        .unwrap()
+        .send()
+        .await
+        .unwrap();
+    deployed.address()
+}
+
+/// Returns the transaction with the hash returned by a transfer
+async fn transaction(provider: &Provider<Http>, tx_hash: &str) -> Transaction {
+    provider
+        .get_transaction(tx_hash.parse::<H256>().unwrap())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore = "requires an anvil executable"]
+async fn test_transfer() -> Result<(), Error> {
+    let (anvil, provider) = start();
+    let wallet = wallet(CHAIN_ID)?;
+    assert_eq!(
+        wallet.public_address(),
+        to_checksum(&anvil.addresses()[0], None)
+    );
+    let recipient = anvil.addresses()[1];
+    let before = EthClient::balance(&provider, recipient).await?;
+
+    let amount = EthereumAmount::from_wei(U256::exp10(18));
+    let tx_hash = wallet
+        .transfer(&provider, amount, &format!("{:?}", recipient))
+        .await?;
+
+    let tx = transaction(&provider, &tx_hash).await;
+    assert_eq!(tx.transaction_type, Some(2.into()));
+    assert_eq!(tx.chain_id, Some(CHAIN_ID.into()));
+    assert_eq!(tx.gas, 21_000.into());
+    assert!(tx.max_fee_per_gas >= tx.max_priority_fee_per_gas);
+    assert_eq!(
+        EthClient::balance(&provider, recipient).await?,
+        (before + amount)?
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires an anvil executable"]
+async fn test_transfer_with_overrides() -> Result<(), Error> {
+    let (anvil, provider) = start();
+    let wallet = wallet(CHAIN_ID)?;
+    let recipient = format!("{:?}", anvil.addresses()[1]);
+    let mut overrides = TransactionOverrides::new();
+    overrides
+        .gas_limit(30_000)
+        .max_fee_per_gas(EthereumAmount::from_wei((100 * GWEI).into()))
+        .max_priority_fee_per_gas(EthereumAmount::from_wei((2 * GWEI).into()))
+        .nonce(0);
+    let tx_hash = wallet
+        .transfer_with_overrides(
+            &provider,
+            EthereumAmount::from_wei(1.into()),
+            &recipient,
+            &overrides,
+        )
+        .await?;
+
+    let tx = transaction(&provider, &tx_hash).await;
+    assert_eq!(tx.gas, 30_000.into());
+    assert_eq!(tx.max_fee_per_gas, Some((100 * GWEI).into()));
+    assert_eq!(tx.max_priority_fee_per_gas, Some((2 * GWEI).into()));
+    assert_eq!(tx.nonce, 0.into());
+
+    // the estimated maximum fee includes the overridden priority fee
+    let mut overrides = TransactionOverrides::new();
+    overrides.max_priority_fee_per_gas(EthereumAmount::from_wei((500 * GWEI).into()));
+    let tx_hash = wallet
+        .transfer_with_overrides(
+            &provider,
+            EthereumAmount::from_wei(1.into()),
+            &recipient,
+            &overrides,
+        )
+        .await?;
+    let tx = transaction(&provider, &tx_hash).await;
+    assert_eq!(tx.nonce, 1.into());
+    assert!(tx.max_fee_per_gas.unwrap() > (500 * GWEI).into());
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires an anvil executable"]
+async fn test_estimate_fees() -> Result<(), Error> {
+    let (_anvil, provider) = start();
+    let fees = EthClient::estimate_fees(&provider).await?;
+    assert!(!fees.base_fee_per_gas.wei().is_zero());
+    assert!(!fees.max_priority_fee_per_gas.wei().is_zero());
+    assert_eq!(
+        fees.max_fee_per_gas,
+        ((fees.base_fee_per_gas * 2)? + fees.max_priority_fee_per_gas)?
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires an anvil executable"]
+async fn test_wrong_chain_id() -> Result<(), Error> {
+    let (anvil, provider) = start();
+    let recipient = format!("{:?}", anvil.addresses()[1]);
+    let result = wallet(1)?
+        .transfer(&provider, EthereumAmount::from_wei(1.into()), &recipient)
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::WrongChainId {
+            expected: 1,
+            found: CHAIN_ID
+        })
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires anvil and solc executables"]
+async fn test_erc20_token() -> Result<(), Error> {
+    let (anvil, provider) = start();
+    let supply = TokenAmount::from_decimal_str("1000000", 6)?;
+    let args = (
+        "Test Token".to_string(),
+        "TST".to_string(),
+        6u8,
+        supply.base_units(),
+    );
+    let token = deploy(&anvil, &provider, "TestToken.sol", "TestToken", args).await;
+    let token_address = format!("{:?}", token);
+    let owner = wallet(CHAIN_ID)?;
+    let spender = other_wallet(1)?;
+    let [owner_address, spender_address, recipient] = [0, 1, 2].map(|i| anvil.addresses()[i]);
+
+    assert_eq!(
+        EthClient::token_metadata(&provider, token).await?,
+        TokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TST".to_string(),
+            decimals: 6,
+        }
+    );
+    assert_eq!(
+        EthClient::token_total_supply(&provider, token).await?,
+        supply
+    );
+    assert_eq!(
+        owner.token_balance(&provider, &token_address).await?,
+        supply
+    );
+
+    let amount = TokenAmount::from_decimal_str("1.5", 6)?;
+    owner
+        .transfer_token(
+            &provider,
+            &token_address,
+            amount,
+            &format!("{:?}", spender_address),
+        )
+        .await?;
+    assert_eq!(
+        spender.token_balance(&provider, &token_address).await?,
+        amount
+    );
+    assert_eq!(
+        owner.token_balance(&provider, &token_address).await?,
+        (supply - amount)?
+    );
+    assert!(matches!(
+        owner
+            .transfer_token(
+                &provider,
+                &token_address,
+                TokenAmount::from_decimal_str("1.5", 18)?,
+                &format!("{:?}", spender_address),
+            )
+            .await,
+        Err(Error::TokenDecimals {
+            expected: 6,
+            found: 18
+        })
+    ));
+
+    owner
+        .approve(
+            &provider,
+            &token_address,
+            TokenAmount::from_decimal_str("10", 6)?,
+            &format!("{:?}", spender_address),
+        )
+        .await?;
+    let allowance = EthClient::token_allowance(&provider, token, owner_address, spender_address);
+    assert_eq!(allowance.await?.to_string(), "10");
+    spender
+        .transfer_from(
+            &provider,
+            &token_address,
+            TokenAmount::from_decimal_str("2.5", 6)?,
+            &format!("{:?}", owner_address),
+            &format!("{:?}", recipient),
+        )
+        .await?;
+    assert_eq!(
+        EthClient::token_balance(&provider, token, recipient)
+            .await?
+            .to_string(),
+        "2.5"
+    );
+    let allowance = EthClient::token_allowance(&provider, token, owner_address, spender_address);
+    assert_eq!(allowance.await?.to_string(), "7.5");
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires anvil and solc executables"]
+async fn test_erc721() -> Result<(), Error> {
+    let (anvil, provider) = start();
+    let args = ("ipfs://collection/".to_string(), U256::from(3));
+    let nft = deploy(&anvil, &provider, "TestNft721.sol", "TestNft721", args).await;
+    let [owner_address, recipient] = [0, 1].map(|i| anvil.addresses()[i]);
+
+    assert_eq!(
+        EthClient::nft_standard(&provider, nft).await?,
+        Some(NftStandard::Erc721)
+    );
+    assert!(
+        EthClient::supports_interface(
+            &provider,
+            nft,
+            walletd_ethereum::ERC721_METADATA_INTERFACE_ID
+        )
+        .await?
+    );
+    assert!(
+        !EthClient::supports_interface(&provider, nft, walletd_ethereum::ERC1155_INTERFACE_ID)
+            .await?
+    );
+    // an account without code does not implement ERC-165
+    assert_eq!(EthClient::nft_standard(&provider, recipient).await?, None);
+
+    assert_eq!(
+        EthClient::erc721_owner_of(&provider, nft, 2.into()).await?,
+        owner_address
+    );
+    assert_eq!(
+        EthClient::erc721_token_uri(&provider, nft, 2.into()).await?,
+        "ipfs://collection/2"
+    );
+    wallet(CHAIN_ID)?
+        .transfer_erc721(
+            &provider,
+            &format!("{:?}", nft),
+            2.into(),
+            &format!("{:?}", recipient),
+        )
+        .await?;
+    assert_eq!(
+        EthClient::erc721_owner_of(&provider, nft, 2.into()).await?,
+        recipient
+    );
+    assert!(matches!(
+        EthClient::erc721_owner_of(&provider, nft, 4.into()).await,
+        Err(Error::Contract(_))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires anvil and solc executables"]
+async fn test_erc1155() -> Result<(), Error> {
+    let (anvil, provider) = start();
+    let args = (
+        "https://example.com/{id}.json".to_string(),
+        vec![U256::from(1), U256::from(2)],
+        vec![U256::from(10), U256::from(5)],
+    );
+    let nft = deploy(&anvil, &provider, "TestNft1155.sol", "TestNft1155", args).await;
+    let [owner_address, recipient] = [0, 1].map(|i| anvil.addresses()[i]);
+
+    assert_eq!(
+        EthClient::nft_standard(&provider, nft).await?,
+        Some(NftStandard::Erc1155)
+    );
+    assert_eq!(
+        EthClient::erc1155_uri(&provider, nft, 1.into()).await?,
+        format!("https://example.com/{:064x}.json", 1)
+    );
+    let owners = [owner_address, owner_address, recipient, recipient];
+    let ids = [1, 2, 1, 2].map(U256::from);
+    assert_eq!(
+        EthClient::erc1155_balance_of_batch(&provider, nft, &owners, &ids).await?,
+        [10, 5, 0, 0].map(U256::from)
+    );
+
+    wallet(CHAIN_ID)?
+        .transfer_erc1155_batch(
+            &provider,
+            &format!("{:?}", nft),
+            &[1, 2].map(U256::from),
+            &[3, 5].map(U256::from),
+            &format!("{:?}", recipient),
+        )
+        .await?;
+    assert_eq!(
+        EthClient::erc1155_balance_of_batch(&provider, nft, &owners, &ids).await?,
+        [7, 0, 3, 5].map(U256::from)
+    );
+    assert!(matches!(
+        EthClient::erc1155_balance_of_batch(&provider, nft, &owners, &ids[..1]).await,
+        Err(Error::Contract(_))
+    ));
+    Ok(())
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+interface IERC1155Receiver {
+    function onERC1155BatchReceived(
+        address operator,
+        address from,
+        uint256[] calldata ids,
+        uint256[] calldata values,
+        bytes calldata data
+    ) external returns (bytes4);
+
+    function onERC1155Received(address operator, address from, uint256 id, uint256 value, bytes calldata data)
+        external
+        returns (bytes4);
+}
+
+/// A minimal ERC-1155 collection minting the amounts of its ids to the deployer, used by the Anvil tests
+contract TestNft1155 {
+    string private uri_;
+    mapping(uint256 => mapping(address => uint256)) public balanceOf;
+    mapping(address => mapping(address => bool)) public isApprovedForAll;
+
+    event TransferSingle(
+        address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value
+    );
+    event TransferBatch(
+        address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values
+    );
+    event ApprovalForAll(address indexed account, address indexed operator, bool approved);
+
+    constructor(string memory tokenUri, uint256[] memory ids, uint256[] memory amounts) {
+        require(ids.length == amounts.length, "length mismatch");
+        uri_ = tokenUri;
+        for (uint256 i = 0; i < ids.length; i++) {
+            balanceOf[ids[i]][msg.sender] += amounts[i];
+        }
+        emit TransferBatch(msg.sender, address(0), msg.sender, ids, amounts);
+    }
+
+    function supportsInterface(bytes4 interfaceId) external pure returns (bool) {
+        return interfaceId == 0x01ffc9a7 || interfaceId == 0xd9b67a26 || interfaceId == 0x0e89341c;
+    }
+
+    function uri(uint256) external view returns (string memory) {
+        return uri_;
+    }
+
+    function balanceOfBatch(address[] calldata accounts, uint256[] calldata ids)
+        external
+        view
+        returns (uint256[] memory balances)
+    {
+        require(accounts.length == ids.length, "length mismatch");
+        balances = new uint256[](accounts.length);
+        for (uint256 i = 0; i < accounts.length; i++) {
+            balances[i] = balanceOf[ids[i]][accounts[i]];
+        }
+    }
+
+    function setApprovalForAll(address operator, bool approved) external {
+        isApprovedForAll[msg.sender][operator] = approved;
+        emit ApprovalForAll(msg.sender, operator, approved);
+    }
+
+    function safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes calldata data) external {
+        _move(from, to, id, amount);
+        emit TransferSingle(msg.sender, from, to, id, amount);
+        if (to.code.length > 0) {
+            require(
+                IERC1155Receiver(to).onERC1155Received(msg.sender, from, id, amount, data)
+                    == IERC1155Receiver.onERC1155Received.selector,
+                "unsafe recipient"
+            );
+        }
+    }
+
+    function safeBatchTransferFrom(
+        address from,
+        address to,
+        uint256[] calldata ids,
+        uint256[] calldata amounts,
+        bytes calldata data
+    ) external {
+        require(ids.length == amounts.length, "length mismatch");
+        for (uint256 i = 0; i < ids.length; i++) {
+            _move(from, to, ids[i], amounts[i]);
+        }
+        emit TransferBatch(msg.sender, from, to, ids, amounts);
+        if (to.code.length > 0) {
+            require(
+                IERC1155Receiver(to).onERC1155BatchReceived(msg.sender, from, ids, amounts, data)
+                    == IERC1155Receiver.onERC1155BatchReceived.selector,
+                "unsafe recipient"
+            );
+        }
+    }
+
+    function _move(address from, address to, uint256 id, uint256 amount) internal {
+        require(msg.sender == from || isApprovedForAll[from][msg.sender], "not authorized");
+        require(to != address(0), "zero address");
+        require(balanceOf[id][from] >= amount, "insufficient balance");
+        balanceOf[id][from] -= amount;
+        balanceOf[id][to] += amount;
+    }
+}
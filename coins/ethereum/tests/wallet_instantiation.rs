@@ -1,5 +1,9 @@
 use bdk::keys::bip39::Mnemonic;
-use walletd_ethereum::EthereumWallet;
+use ethers::signers::coins_bip39::English;
+use ethers::signers::{MnemonicBuilder, Signer};
+use ethers::utils::to_checksum;
+use walletd_coin_core::{CryptoWallet, CryptoWalletBuilder, HDNetworkType, Seed};
+use walletd_ethereum::{EthereumDerivationScheme, EthereumWallet};
 
 #[test]
 fn test_wallet_instantiation_from_mnemonic_seed() {
@@ -17,3 +21,120 @@ fn test_wallet_instantiation_from_mnemonic_seed() {
         "0x6EEb11eA2905fEe101f72BF94F792dbc2dfB42B7"
     );
 }
+
+#[test]
+fn test_wallet_instantiation_from_crypto_wallet_builder() {
+    let mnemonic_phrase: &str =
+        "outer ride neither foil glue number place usage ball shed dry point";
+    let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+    let seed = Seed::new(mnemonic.to_seed("").to_vec());
+
+    let wallet = <EthereumWallet as CryptoWallet>::builder()
+        .mnemonic_seed(seed)
+        .hd_network_type(HDNetworkType::MainNet)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        &wallet.public_address(),
+        "0x6EEb11eA2905fEe101f72BF94F792dbc2dfB42B7"
+    );
+    assert_eq!(CryptoWallet::network(&wallet).unwrap(), 1);
+}
+
+#[test]
+fn test_wallet_instantiation_defaults_to_sepolia() {
+    let mnemonic_phrase: &str =
+        "outer ride neither foil glue number place usage ball shed dry point";
+    let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+    let seed = Seed::new(mnemonic.to_seed("").to_vec());
+
+    let wallet = EthereumWallet::builder()
+        .mnemonic(mnemonic)
+        .build()
+        .unwrap();
+    assert_eq!(wallet.chain_id(), 11155111);
+
+    let wallet = <EthereumWallet as CryptoWallet>::builder()
+        .mnemonic_seed(seed)
+        .hd_network_type(HDNetworkType::TestNet)
+        .build()
+        .unwrap();
+    assert_eq!(CryptoWallet::network(&wallet).unwrap(), 11155111);
+}
+
+const HARDHAT_MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+#[test]
+fn test_wallet_instantiation_with_indices() {
+    let mnemonic = Mnemonic::parse(HARDHAT_MNEMONIC).unwrap();
+    let wallets = EthereumWallet::builder()
+        .mnemonic(mnemonic.clone())
+        .build_wallets(3)
+        .unwrap();
+    let addresses: Vec<String> = wallets.iter().map(|w| w.public_address()).collect();
+    assert_eq!(
+        addresses,
+        [
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+            "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC",
+        ]
+    );
+    assert_eq!(wallets[2].derivation_path().to_string(), "m/44'/60'/0'/0/2");
+
+    let wallet = EthereumWallet::builder()
+        .mnemonic(mnemonic)
+        .address_index(1)
+        .build()
+        .unwrap();
+    assert_eq!(wallet.public_address(), addresses[1]);
+}
+
+#[test]
+fn test_wallet_instantiation_with_derivation_schemes() {
+    let mnemonic = Mnemonic::parse(HARDHAT_MNEMONIC).unwrap();
+    for scheme in [
+        EthereumDerivationScheme::Bip44,
+        EthereumDerivationScheme::LedgerLive,
+        EthereumDerivationScheme::LegacyMew,
+    ] {
+        let wallets = EthereumWallet::builder()
+            .mnemonic(mnemonic.clone())
+            .derivation_scheme(scheme)
+            .account_index(1)
+            .address_index(2)
+            .build_wallets(2)
+            .unwrap();
+        for wallet in wallets {
+            // the address is checked against the derivation of the ethers signer
+            let signer = MnemonicBuilder::<English>::default()
+                .phrase(HARDHAT_MNEMONIC)
+                .derivation_path(&wallet.derivation_path().to_string())
+                .unwrap()
+                .build()
+                .unwrap();
+            assert_eq!(
+                wallet.public_address(),
+                to_checksum(&signer.address(), None)
+            );
+        }
+    }
+
+    let ledger_live = EthereumWallet::builder()
+        .mnemonic(mnemonic.clone())
+        .derivation_scheme(EthereumDerivationScheme::LedgerLive)
+        .build_wallets(2)
+        .unwrap();
+    assert_eq!(
+        ledger_live[1].derivation_path().to_string(),
+        "m/44'/60'/1'/0/0"
+    );
+    let legacy_mew = EthereumWallet::builder()
+        .mnemonic(mnemonic)
+        .derivation_scheme(EthereumDerivationScheme::LegacyMew)
+        .address_index(5)
+        .build()
+        .unwrap();
+    assert_eq!(legacy_mew.derivation_path().to_string(), "m/44'/60'/0'/5");
+}
@@ -1,8 +1,10 @@
 extern crate walletd_monero;
 
+use walletd_coin_core::CryptoWallet;
+
 use walletd_monero::{
-    AddressType as MoneroFormat, CryptoWallet, Mnemonic as MoneroMnemonic, MnemonicExt,
-    MoneroLWSConnection, MoneroWallet, Network as MoneroNetworkType,
+    AddressType as MoneroFormat, Mnemonic as MoneroMnemonic, MnemonicExt, MoneroLWSConnection,
+    MoneroWallet, Network as MoneroNetworkType,
 };
 
 /// example with using MyMonero LWS Server
@@ -40,24 +42,17 @@ async fn main() {
     assert!(my_test_wallet.public_keys().view_key().is_some());
     let my_public_view_key = my_test_wallet.public_keys().view_key().unwrap();
     let my_public_spend_key = my_test_wallet.public_keys().spend_key().unwrap();
-    println!(
-        "my_public_address: {}",
-        my_test_wallet.public_address().to_string()
-    );
-    println!("my_public_view_key: {}", my_public_view_key.to_string());
-    println!("my_public_spend_key: {}", my_public_spend_key.to_string());
+    println!("my_public_address: {}", my_test_wallet.public_address());
+    println!("my_public_view_key: {}", my_public_view_key);
+    println!("my_public_spend_key: {}", my_public_spend_key);
     println!(
         "my_private_view_key: {}",
-        my_test_wallet.private_keys().view_key().to_string()
+        my_test_wallet.private_keys().view_key()
     );
     println!(
         "my_private_spend_key: {}",
-        my_test_wallet
-            .private_keys()
-            .spend_key()
-            .unwrap()
-            .to_string()
+        my_test_wallet.private_keys().spend_key().unwrap()
     );
     let balance = my_test_wallet.balance(&blockchain_client).await.unwrap();
-    println!("balance: {}", balance.to_string());
+    println!("balance: {}", balance);
 }
@@ -1,9 +1,9 @@
 extern crate walletd_monero;
 
-use monero_generators::bulletproofs_generators;
+use walletd_monero::rct_types::bulletproofs_generators;
 
 fn main() {
     println!("Bulletproof+ example");
-    let _generators = bulletproofs_generators(b"bulletproof_plus");
+    let _generators = bulletproofs_generators(b"bulletproof_plus").unwrap();
     println!("done generating example");
 }
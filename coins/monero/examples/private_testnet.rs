@@ -1,6 +1,6 @@
 extern crate walletd_monero;
 
-/// example running monero private testnet, https://github.com/moneroexamples/private-testnet
+// example running monero private testnet, https://github.com/moneroexamples/private-testnet
 // const PRIVATE_TESTNET_URL: &str = "http://localhost:28081/json_rpc";
 
 // TODO(#61): add more examples and tests for Monero and other coins
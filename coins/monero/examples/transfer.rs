@@ -1,8 +1,10 @@
 extern crate walletd_monero;
 
+use walletd_coin_core::CryptoWallet;
+
 use walletd_monero::{
-    AddressType as MoneroFormat, CryptoWallet, Mnemonic as MoneroMnemonic, MnemonicExt,
-    MoneroAmount, MoneroLWSConnection, MoneroWallet, Network as MoneroNetworkType,
+    AddressType as MoneroFormat, Mnemonic as MoneroMnemonic, MnemonicExt, MoneroAmount,
+    MoneroLWSConnection, MoneroWallet, Network as MoneroNetworkType,
 };
 
 /// example with using MyMonero LWS Server
@@ -68,7 +70,7 @@ async fn main() {
     let address_send_to =
     "56QYg2kEWUvTmAVxTeNBaWGBJjdEqfSGsgreEh4PaZnwYeXU3iPHgTBN3FK5rfE1Ak7Wqi1AeG4H3dSYRxQtqdwSSnQifdn";
     my_test_wallet
-        .transfer(&blockchain_client, &send_amount, &address_send_to)
+        .transfer(&blockchain_client, send_amount, address_send_to)
         .await
         .unwrap();
 }
@@ -140,12 +140,12 @@ pub fn calculate_fee_from_weight(
     fee_quantization_mask: u64,
 ) -> MoneroAmount {
     let mut fee = weight * base_fee;
-    fee = (fee + fee_quantization_mask - 1) / fee_quantization_mask * fee_quantization_mask;
+    fee = fee.div_ceil(fee_quantization_mask) * fee_quantization_mask;
     MoneroAmount::from_piconero(fee)
 }
 
 pub fn calculate_fee(fee_per_kb: u64, bytes: u64) -> MoneroAmount {
-    let kb = (bytes + 1023) / 1024;
+    let kb = bytes.div_ceil(1024);
     MoneroAmount::from_piconero(kb * fee_per_kb)
 }
 
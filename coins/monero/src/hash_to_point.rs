@@ -0,0 +1,162 @@
+//! Monero's hash to point function, adopted from the monero_generators crate
+use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use lazy_static::lazy_static;
+use num::bigint::BigUint;
+use num::{One, Zero};
+
+use crate::keccak256;
+
+lazy_static! {
+    /// The field modulus 2^255 - 19
+    static ref FIELD_MODULUS: BigUint = (BigUint::one() << 255u32) - BigUint::from(19u8);
+
+    /// Monero alternate generator `H`, used for amounts in Pedersen commitments.
+    pub static ref H: EdwardsPoint =
+        CompressedEdwardsY(keccak256(&ED25519_BASEPOINT_POINT.compress().to_bytes()))
+            .decompress()
+            .expect("The hash of the basepoint should be a valid Edwards Point")
+            .mul_by_cofactor();
+}
+
+/// An element of the field of the curve, only used by [hash_to_point] which runs in variable time
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FieldElement(BigUint);
+
+impl FieldElement {
+    fn new(value: BigUint) -> Self {
+        Self(value % &*FIELD_MODULUS)
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Self::new(BigUint::from(value))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::new(&self.0 + &other.0)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Self::new(&self.0 + &*FIELD_MODULUS - &other.0)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Self::new(&self.0 * &other.0)
+    }
+
+    fn neg(&self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    fn pow(&self, exponent: &Self) -> Self {
+        Self(self.0.modpow(&exponent.0, &FIELD_MODULUS))
+    }
+
+    /// Returns the inverse of the element, zero for zero
+    fn invert(&self) -> Self {
+        Self(
+            self.0
+                .modpow(&(&*FIELD_MODULUS - BigUint::from(2u8)), &FIELD_MODULUS),
+        )
+    }
+
+    fn zero() -> Self {
+        Self(BigUint::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns the 32 byte little endian encoding of the element
+    fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        let le_bytes = self.0.to_bytes_le();
+        bytes[..le_bytes.len()].copy_from_slice(&le_bytes);
+        bytes
+    }
+}
+
+/// Monero's hash to point function, as named `ge_fromfe_frombytes_vartime`.
+pub fn hash_to_point(bytes: [u8; 32]) -> EdwardsPoint {
+    #[allow(non_snake_case)]
+    let A = FieldElement::from_u64(486662);
+    let one = FieldElement::from_u64(1);
+
+    let hash_squared = FieldElement::new(BigUint::from_bytes_le(&keccak256(&bytes))).square();
+    let v = hash_squared.add(&hash_squared);
+    let w = v.add(&one);
+    let x = w.square().add(&A.square().neg().mul(&v));
+
+    // This isn't the complete X, yet its initial value
+    // We don't calculate the full X, and instead solely calculate Y, letting dalek reconstruct X
+    #[allow(non_snake_case)]
+    let X = {
+        let u = &w;
+        let v = &x;
+        let v3 = v.mul(v).mul(v);
+        let uv3 = u.mul(&v3);
+        let v7 = v3.mul(&v3).mul(v);
+        let uv7 = u.mul(&v7);
+        let exponent = FieldElement::from_u64(5)
+            .neg()
+            .mul(&FieldElement::from_u64(8).invert());
+        uv3.mul(&uv7.pow(&exponent))
+    };
+    let x = X.square().mul(&x);
+
+    let y = w.sub(&x);
+    let non_zero_0 = !y.is_zero();
+    let y_if_non_zero_0 = w.add(&x);
+    let sign = non_zero_0 && !y_if_non_zero_0.is_zero();
+
+    let z = A.neg().mul(if sign { &one } else { &v });
+    #[allow(non_snake_case)]
+    let Z = z.add(&w);
+    #[allow(non_snake_case)]
+    let Y = z.sub(&w).mul(&Z.invert());
+    let mut bytes = Y.to_bytes();
+    bytes[31] |= u8::from(sign) << 7;
+
+    CompressedEdwardsY(bytes)
+        .decompress()
+        .expect("The hash to point should be a valid Edwards Point")
+        .mul_by_cofactor()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators_bulletproof_plus::GENERATORS;
+    use crate::rct_types::bulletproofs_generators;
+
+    #[test]
+    fn test_h() {
+        assert_eq!(
+            hex::encode(H.compress().to_bytes()),
+            "8b655970153799af2aeadc9ff1add0ea6c7251d54154cfa92c173a0dd39c1f94"
+        );
+    }
+
+    #[test]
+    fn test_bulletproof_plus_generators() {
+        // the generators are too large for the default test thread stack
+        std::thread::Builder::new()
+            .stack_size(16 * 1024 * 1024)
+            .spawn(|| {
+                // the precomputed generators are hashed to points from H
+                let generators = bulletproofs_generators(b"bulletproof_plus").unwrap();
+                for i in [0, 1, 511, 1023] {
+                    assert_eq!(generators.G[i], GENERATORS.G[i]);
+                    assert_eq!(generators.H[i], GENERATORS.H[i]);
+                }
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+}
@@ -1,8 +1,8 @@
+use crate::hash_to_point::hash_to_point;
 use anyhow::anyhow;
 use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE as G_BASEPOINT;
 use curve25519_dalek::edwards::CompressedEdwardsY;
 use curve25519_dalek::scalar::Scalar;
-use monero_generators::hash_to_point;
 use thiserror::Error;
 
 use crate::{
@@ -34,7 +34,7 @@ impl KeyDerivation {
             return Err(anyhow!("Invalid byte length for key derivation"));
         }
 
-        Ok(KeyDerivation(CompressedEdwardsY::from_slice(bytes)))
+        Ok(KeyDerivation(CompressedEdwardsY::from_slice(bytes)?))
     }
 
     pub fn to_bytes(&self) -> [u8; KEY_IMAGE_BYTES] {
@@ -59,7 +59,7 @@ impl KeyDerivation {
         public_spend_key: &PublicKey,
     ) -> Result<PublicKey, Error> {
         let hash = self.hash_to_scalar(output_index);
-        let derived_public_key = &hash * &G_BASEPOINT + public_spend_key.to_edwards_point();
+        let derived_public_key = &hash * G_BASEPOINT + public_spend_key.to_edwards_point();
         let derived_pub_key = PublicKey::from_slice(derived_public_key.compress().as_bytes())?;
         Ok(derived_pub_key)
     }
@@ -132,13 +132,13 @@ impl KeyImage {
         // hash function, i is the output_index, D is the derivation variable previously
         // calculated, G is the ED25519_BASEPOINT, and B is the public spend key
         let hash = &KeyDerivation::hash_to_scalar(&key_derivation, output_index);
-        let derived_public_key = hash * &G_BASEPOINT + public_spend_key.to_edwards_point();
+        let derived_public_key = hash * G_BASEPOINT + public_spend_key.to_edwards_point();
 
         // compute x = Hs(D || i) + b, x is derived private key, b is private spend key
         let derived_private_key = hash + private_spend_key.as_scalar();
 
         // (and check if P==x*G)
-        let check_derived_public_key = &derived_private_key * &G_BASEPOINT;
+        let check_derived_public_key = &derived_private_key * G_BASEPOINT;
         if check_derived_public_key.compress() != derived_public_key.compress() {
             return Err(Error::MismatchInDerivedPublicKey);
         }
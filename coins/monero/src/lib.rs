@@ -8,7 +8,7 @@ pub use monero_amount::MoneroAmount;
 pub mod address;
 pub use address::{Address, AddressType, SubaddressIndex, SubaddressKeys};
 mod monero_wallet;
-pub use monero_wallet::MoneroWallet;
+pub use monero_wallet::{MoneroWallet, MoneroWalletBuilder};
 mod private_key;
 pub use private_key::PrivateKey;
 mod public_key;
@@ -30,6 +30,7 @@ pub use monero_lws::MoneroLWSConnection;
 pub mod mix_outs;
 pub use mix_outs::MixAmountAndOuts;
 pub mod generators_bulletproof_plus;
+pub mod hash_to_point;
 pub mod monero_serialize;
 pub use monero_serialize::{DoSerialize, SerializedArchive};
 pub mod rct_types;
@@ -13,8 +13,7 @@ use thiserror::Error;
 
 use crate::rct_types::RctKey;
 use crate::{
-    mix_outs, public_key, KeyDerivation, KeyImage, MixAmountAndOuts, MoneroWallet,
-    PublicKey,
+    mix_outs, public_key, KeyDerivation, KeyImage, MixAmountAndOuts, MoneroWallet, PublicKey,
 };
 
 /// The number of fake outputs to request from the LWS
@@ -133,9 +132,9 @@ impl UnspentOutput {
                             .spend_key()
                             .expect("expecting private spend key"),
                     )?;
-                    return Ok(RctKey::gen_commitment_mask(&RctKey::from_slice(
+                    Ok(RctKey::gen_commitment_mask(&RctKey::from_slice(
                         &derived_sec_key.to_bytes(),
-                    )));
+                    )))
                 } else if rct_string.len() == 128 {
                     let encrypted_mask = RctKey::from_slice(&hex::decode(&rct_string[64..128])?);
                     if encrypted_mask == RctKey::identity() {
@@ -145,9 +144,9 @@ impl UnspentOutput {
                         KeyDerivation::generate(tx_pub_key, &account.private_keys().view_key());
                     let decrypted_mask =
                         encrypted_mask.as_scalar() - key_deriv.hash_to_scalar(self.index);
-                    return Ok(RctKey::from_slice(&decrypted_mask.to_bytes()));
+                    Ok(RctKey::from_slice(&decrypted_mask.to_bytes()))
                 } else {
-                    return Err(Error::InvalidRctStringLength);
+                    Err(Error::InvalidRctStringLength)
                 }
             }
         }
@@ -169,12 +168,12 @@ impl MoneroLWSConnection {
         if status.is_client_error() && status.is_server_error() {
             Err(Error::ErrorClientSideAndServerSide(content))
         } else if status.is_client_error() {
-            return Err(Error::ClientSideError(content));
+            Err(Error::ClientSideError(content))
         } else if status.is_server_error() {
-            return Err(Error::ServerSideError(content));
+            Err(Error::ServerSideError(content))
         } else {
             let content_json = serde_json::from_str(content.as_str())?;
-            return Ok(content_json);
+            Ok(content_json)
         }
     }
 
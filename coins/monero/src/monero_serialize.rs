@@ -29,7 +29,7 @@ impl SerializedArchive {
 
     /// Serializes a vector of containing items of type T which implement the
     /// DoSerialize trait
-    pub fn serialize_vector<T>(&mut self, tag: &str, vec: &Vec<T>) -> Result<(), anyhow::Error>
+    pub fn serialize_vector<T>(&mut self, tag: &str, vec: &[T]) -> Result<(), anyhow::Error>
     where
         T: DoSerialize,
     {
@@ -51,7 +51,7 @@ impl SerializedArchive {
     pub fn serialize_vector_variant<T, V>(
         &mut self,
         tag: &str,
-        vec: &Vec<T>,
+        vec: &[T],
         variant: V,
     ) -> Result<(), anyhow::Error>
     where
@@ -11,16 +11,15 @@ use rand::prelude::SliceRandom;
 use rand::{thread_rng, Rng};
 use sha2::Sha512;
 use thiserror::Error;
-use walletd_hd_key::SlipCoin;
+use walletd_coin_core::{CryptoWallet, CryptoWalletBuilder, Seed as Seed39};
 
 use crate::monero_serialize::DoSerialize;
 use crate::transaction::{TransactionPrefix, TxInToKey, TxSourceEntry};
 use crate::{
-    fee_utils, hash, key_image, monero_lws, payment_id, public_key, rct_types, transaction,
-    KeyImage, PaymentId, PrivateKey, VarInt,
+    address, fee_utils, hash, key_image, monero_lws, payment_id, public_key, rct_types,
+    transaction, KeyImage, PaymentId, PrivateKey, VarInt,
 };
 type HmacSha512 = Hmac<Sha512>;
-use std::any::Any;
 
 use crate::monero_lws::{UnspentOutput, DEFAULT_DUST_THRESHOLD, FAKE_OUTPUTS_COUNT};
 use crate::private_key::KEY_LEN;
@@ -29,9 +28,8 @@ use crate::transaction::{
     GetOutsEntry, PendingTransaction, Priority, SendTransaction, TxDestinationEntry,
 };
 use crate::{
-    Address, AddressType, CryptoWallet, HDKey, MoneroAmount,
-    MoneroLWSConnection, MoneroPrivateKeys, MoneroPublicKeys, Network, HDNetworkType, PublicKey,
-    Seed, SerializedArchive,
+    Address, AddressType, HDKey, HDNetworkType, MoneroAmount, MoneroLWSConnection,
+    MoneroPrivateKeys, MoneroPublicKeys, Network, PublicKey, Seed, SerializedArchive,
 };
 
 const TX_EXTRA_TAG_PUBKEY: u8 = 0x01;
@@ -41,7 +39,6 @@ const EXPECTED_MINIMUM_HF_VERSION: u8 = 15;
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct MoneroWallet {
-    crypto_type: SlipCoin,
     address_format: AddressType,
     network: Network,
     public_address: Address,
@@ -109,82 +106,25 @@ pub enum Error {
     DifferentLengths(usize, usize),
     #[error("Transaction error, outputs value greater than inputs value: inputs {inputs:?}, outputs {outputs:?}")]
     TransactionValue { inputs: u64, outputs: u64 },
+    /// Error from the address module
+    #[error("Address error: {0}")]
+    Address(#[from] address::Error),
+    /// Error returned when some feature or option is currently not supported
+    #[error("Currently not supported: {0}")]
+    CurrentlyNotSupported(String),
 }
 
-#[async_trait]
+#[async_trait(?Send)]
 impl CryptoWallet for MoneroWallet {
-    type AddressFormat = AddressType;
-    type BlockchainClient = MoneroLWSConnection;
+    type ErrorType = Error;
     type CryptoAmount = MoneroAmount;
-    type HDKeyInfo = HDKey;
-    type MnemonicSeed = Seed;
+    type AddressFormat = AddressType;
     type NetworkType = Network;
+    type BlockchainClient = MoneroLWSConnection;
+    type WalletBuilder = MoneroWalletBuilder;
 
-    /// Returns the CryptoCoin type, for Monero, returns SlipCoin::XMR
-    fn crypto_type(&self) -> SlipCoin {
-        SlipCoin::XMR
-    }
-
-    /// Constructs a MoneroWallet given a hd key and address format
-    fn from_hd_key(
-        hd_keys: &HDKey,
-        address_format: Self::AddressFormat,
-    ) -> Result<Self, anyhow::Error> {
-        // uses BIP85 specification, https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki
-        let mut entropy = HmacSha512::new_from_slice(b"bip-entropy-from-k")?;
-        entropy.update(
-            &hd_keys
-                .extended_private_key
-                .expect("extended private key data missing"),
-        );
-
-        // Monero uses 256 bits for the seed, 32 bytes (KEY_LEN)
-        let entropy_bytes = &entropy.finalize().into_bytes()[..KEY_LEN];
-
-        let mut seed = [0u8; KEY_LEN];
-        seed.copy_from_slice(entropy_bytes);
-        let private_keys = MoneroPrivateKeys::from_seed(&seed)?;
-        let public_keys = MoneroPublicKeys::from_private_keys(&private_keys);
-
-        let network = match hd_keys.network {
-            HDNetworkType::MainNet => Network::Mainnet,
-            HDNetworkType::TestNet => Network::Stagenet,
-        };
-
-        let public_address = Address::new(&network, &public_keys, &address_format)?;
-
-        Ok(Self {
-            crypto_type: SlipCoin::XMR,
-            address_format,
-            private_keys,
-            public_keys,
-            public_address,
-            network,
-        })
-    }
-
-    fn from_mnemonic(
-        mnemonic_seed: &Seed,
-        network: Network,
-        address_format: AddressType,
-    ) -> Result<Self, anyhow::Error> {
-        let seed = mnemonic_seed.as_bytes();
-        let private_keys = MoneroPrivateKeys::from_seed(seed)?;
-        let public_keys = MoneroPublicKeys::from_private_keys(&private_keys);
-        let public_address = Address::new(&network, &public_keys, &address_format)?;
-
-        Ok(Self {
-            crypto_type: SlipCoin::XMR,
-            address_format,
-            private_keys,
-            public_keys,
-            public_address,
-            network,
-        })
-    }
-
-    fn public_address_string(&self) -> String {
-        self.public_address.to_string()
+    fn builder() -> Self::WalletBuilder {
+        MoneroWalletBuilder::new()
     }
 
     /// TODO(#68) add more nuanced balance reports for Monero (pending,unlocked,
@@ -192,7 +132,7 @@ impl CryptoWallet for MoneroWallet {
     async fn balance(
         &self,
         blockchain_client: &Self::BlockchainClient,
-    ) -> Result<Self::CryptoAmount, anyhow::Error> {
+    ) -> Result<Self::CryptoAmount, Self::ErrorType> {
         blockchain_client
             .login(
                 &self.public_address_string(),
@@ -219,6 +159,10 @@ impl CryptoWallet for MoneroWallet {
         return Ok(balance);
     }
 
+    fn receive_address(&self) -> Result<String, Self::ErrorType> {
+        Ok(self.public_address_string())
+    }
+
     /// TODO(#81) add transfer functionality for Monero
     /// to send to multiple destinations
     /// TODO(#68): fix this transfer function so that it is able to send a valid
@@ -227,10 +171,10 @@ impl CryptoWallet for MoneroWallet {
     async fn transfer(
         &self,
         blockchain_client: &Self::BlockchainClient,
-        send_amount: &Self::CryptoAmount,
-        public_address: &str,
-    ) -> Result<String, anyhow::Error> {
-        let receiver_address = Address::from_str(public_address)?;
+        send_amount: Self::CryptoAmount,
+        to_address: &str,
+    ) -> Result<String, Self::ErrorType> {
+        let receiver_address = Address::from_str(to_address)?;
         let send_amount_dest = TxDestinationEntry {
             amount: send_amount.as_piconero(),
             addr: receiver_address,
@@ -293,32 +237,188 @@ impl CryptoWallet for MoneroWallet {
         if validated {
             match blockchain_client
                 .submit_raw_tx(
-                    public_address,
+                    to_address,
                     &self.private_keys().view_key().to_string(),
                     &raw_tx_hex,
                 )
                 .await
             {
                 Ok(response) => {
-                    let tx_hash = response
-                        .as_str().expect("should be a string");
+                    let tx_hash = response.as_str().expect("should be a string");
                     Ok(tx_hash.to_string())
                 }
-                Err(e) => Err(anyhow!("Error: {}", e)),
+                Err(e) => Err(anyhow!("Error: {}", e).into()),
             }
         } else {
-            Err(anyhow!("Transaction is not valid"))
+            Err(anyhow!("Transaction is not valid").into())
         }
     }
+
+    /// Syncing is not supported yet, the balance and transfers query the light wallet server directly
+    async fn sync(
+        &mut self,
+        _blockchain_client: &Self::BlockchainClient,
+    ) -> Result<(), Self::ErrorType> {
+        Err(Error::CurrentlyNotSupported(
+            "Syncing a Monero wallet".to_string(),
+        ))
+    }
+
+    fn network(&self) -> Result<Self::NetworkType, Self::ErrorType> {
+        Ok(self.network)
+    }
+
+    fn address_format(&self) -> Self::AddressFormat {
+        self.address_format.clone()
+    }
+}
+
+/// Builder for [MoneroWallet], derives the Monero keys from a BIP39 mnemonic seed
+#[derive(Debug, Clone, Default)]
+pub struct MoneroWalletBuilder {
+    mnemonic_seed: Option<Seed39>,
+    network: Network,
+    address_format: AddressType,
+}
+
+impl MoneroWalletBuilder {
+    /// Creates a new MoneroWalletBuilder with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Specifies the BIP39 mnemonic seed the wallet is derived from
+    pub fn mnemonic_seed(&mut self, mnemonic_seed: Seed39) -> &mut Self {
+        self.mnemonic_seed = Some(mnemonic_seed);
+        self
+    }
+
+    /// Specifies the network for the wallet, the default is Network::Mainnet
+    pub fn network(&mut self, network: Network) -> &mut Self {
+        self.network = network;
+        self
+    }
+
+    /// Specifies the address format for the wallet, the default is AddressType::Standard
+    pub fn address_format(&mut self, address_format: AddressType) -> &mut Self {
+        self.address_format = address_format;
+        self
+    }
+
+    /// Builds the MoneroWallet with the specified options
+    pub fn build(&self) -> Result<MoneroWallet, Error> {
+        let mnemonic_seed = self
+            .mnemonic_seed
+            .clone()
+            .ok_or_else(|| anyhow!("The mnemonic seed was not provided"))?;
+        let hd_network = match self.network {
+            Network::Mainnet => HDNetworkType::MainNet,
+            Network::Stagenet | Network::Testnet => HDNetworkType::TestNet,
+        };
+        let master_hd_key = HDKey::new_master(mnemonic_seed, hd_network).map_err(|e| anyhow!(e))?;
+        let mut wallet = MoneroWallet::from_hd_key(&master_hd_key, self.address_format.clone())?;
+        if wallet.network != self.network {
+            wallet.public_address =
+                Address::new(&self.network, &wallet.public_keys, &self.address_format)?;
+            wallet.network = self.network;
+        }
+        Ok(wallet)
+    }
+}
+
+impl CryptoWalletBuilder<MoneroWallet> for MoneroWalletBuilder {
+    fn new() -> Self {
+        MoneroWalletBuilder::new()
+    }
+
+    fn mnemonic_seed(&mut self, mnemonic_seed: Seed39) -> &mut Self {
+        MoneroWalletBuilder::mnemonic_seed(self, mnemonic_seed)
+    }
+
+    /// Maps [HDNetworkType::MainNet] to [Network::Mainnet] and [HDNetworkType::TestNet], [HDNetworkType::Signet] and any other network type to [Network::Stagenet]
+    fn hd_network_type(&mut self, network_type: HDNetworkType) -> &mut Self {
+        match network_type {
+            HDNetworkType::MainNet => self.network(Network::Mainnet),
+            _ => self.network(Network::Stagenet),
+        }
+    }
+
+    fn build(&self) -> Result<MoneroWallet, Error> {
+        MoneroWalletBuilder::build(self)
+    }
 }
 
 impl MoneroWallet {
+    /// Constructs a MoneroWallet given a hd key and address format
+    pub fn from_hd_key(
+        hd_keys: &HDKey,
+        address_format: AddressType,
+    ) -> Result<Self, anyhow::Error> {
+        // uses BIP85 specification, https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki
+        let mut entropy = HmacSha512::new_from_slice(b"bip-entropy-from-k")?;
+        entropy.update(
+            &hd_keys
+                .extended_private_key
+                .as_ref()
+                .ok_or_else(|| anyhow!("The extended private key of the HD key is missing"))?
+                .to_bytes(),
+        );
+
+        // Monero uses 256 bits for the seed, 32 bytes (KEY_LEN)
+        let entropy_bytes = &entropy.finalize().into_bytes()[..KEY_LEN];
+
+        let mut seed = [0u8; KEY_LEN];
+        seed.copy_from_slice(entropy_bytes);
+        let private_keys = MoneroPrivateKeys::from_seed(&seed)?;
+        let public_keys = MoneroPublicKeys::from_private_keys(&private_keys);
+
+        let network = match hd_keys.network {
+            HDNetworkType::MainNet => Network::Mainnet,
+            _ => Network::Stagenet,
+        };
+
+        let public_address = Address::new(&network, &public_keys, &address_format)?;
+
+        Ok(Self {
+            address_format,
+            private_keys,
+            public_keys,
+            public_address,
+            network,
+        })
+    }
+
+    /// Constructs a MoneroWallet from a Monero mnemonic seed, network and address format
+    pub fn from_mnemonic(
+        mnemonic_seed: &Seed,
+        network: Network,
+        address_format: AddressType,
+    ) -> Result<Self, anyhow::Error> {
+        let seed = mnemonic_seed.as_bytes();
+        let private_keys = MoneroPrivateKeys::from_seed(seed)?;
+        let public_keys = MoneroPublicKeys::from_private_keys(&private_keys);
+        let public_address = Address::new(&network, &public_keys, &address_format)?;
+
+        Ok(Self {
+            address_format,
+            private_keys,
+            public_keys,
+            public_address,
+            network,
+        })
+    }
+
+    /// Returns the public address of the wallet as a string
+    pub fn public_address_string(&self) -> String {
+        self.public_address.to_string()
+    }
+
     /// Creates an unsigned pending transaction given info about the transaction
     /// to be made, available unspent outs, and mix outs to use
     pub fn create_transaction(
         &self,
         send_transfer: &SendTransaction,
-        unspent_outs: &mut Vec<UnspentOutput>,
+        unspent_outs: &mut [UnspentOutput],
         using_mix_outs: &[Vec<GetOutsEntry>],
     ) -> Result<PendingTransaction, Error> {
         if !send_transfer.sweep_all {
@@ -428,7 +528,7 @@ impl MoneroWallet {
 
         // sort unspent_outs to be in reverse order by amount (large amount to smaller
         // amount), TODO(#68): look into whether we are following best practices here
-        unspent_outs.sort_by(|a, b| b.amount.cmp(&a.amount));
+        unspent_outs.sort_by_key(|unspent_out| std::cmp::Reverse(unspent_out.amount));
 
         // This will keep track of which of our unspent outs we will actully use and
         // inputs to the tx
@@ -544,8 +644,7 @@ impl MoneroWallet {
             src.amount = unspent_out.amount;
             src.rct = unspent_out.is_rct();
 
-            for n in 0..mixin + 1 {
-                let outs_entry = &using_mix_outs[out_index][n];
+            for outs_entry in &using_mix_outs[out_index][..=mixin] {
                 let ctkey = rct_types::CtKey {
                     dest: RctKey::from_slice(&outs_entry.1.to_bytes()),
                     mask: outs_entry.2,
@@ -625,7 +724,7 @@ impl MoneroWallet {
             vin.push(input_to_key);
         }
         // sort vins by their key image
-        vin.sort_by(|a, b| a.k_image.key_image.cmp(&b.k_image.key_image));
+        vin.sort_by_key(|a| a.k_image.key_image);
 
         // TODO(#68): handle additional_tx_pub_keys
         // (we don't need to include additional tx keys if:
@@ -717,8 +816,7 @@ impl MoneroWallet {
 
         let mut amount_in: u64 = 0;
         let mut amount_out: u64 = 0;
-        let mut in_sk: Vec<rct_types::CtKey> = Vec::new();
-        in_sk.reserve(sources.len());
+        let mut in_sk: Vec<rct_types::CtKey> = Vec::with_capacity(sources.len());
         let mut destinations: Vec<RctKey> = Vec::new();
         let mut in_amounts: Vec<u64> = Vec::new();
         let mut out_amounts: Vec<u64> = Vec::new();
@@ -756,8 +854,8 @@ impl MoneroWallet {
         if use_simple_rct {
             for i in 0..sources.len() {
                 mix_ring[i].resize(sources[i].outputs.len(), rct_types::CtKey::default());
-                for n in 0..sources[i].outputs.len() {
-                    mix_ring[i][n] = sources[i].outputs[n].1.clone();
+                for (ring_member, output) in mix_ring[i].iter_mut().zip(&sources[i].outputs) {
+                    *ring_member = output.1.clone();
                 }
             }
         }
@@ -993,7 +1091,7 @@ impl MoneroWallet {
                 idx_out.push(GetOutsEntry(*global_index, output_public_key, rct_commit));
             }
             // Sort by global index
-            idx_out.sort_by(|a, b| a.0.cmp(&b.0));
+            idx_out.sort_by_key(|a| a.0);
             outs.push(idx_out);
         }
         Ok(outs)
@@ -1020,17 +1118,3 @@ impl Display for MoneroWallet {
         Ok(())
     }
 }
-
-impl CryptoAddressGeneral for MoneroWallet {
-    fn crypto_type(&self) -> SlipCoin {
-        SlipCoin::XMR
-    }
-
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-
-    fn box_clone(&self) -> Box<dyn CryptoAddressGeneral> {
-        Box::new(self.clone())
-    }
-}
@@ -50,7 +50,7 @@ impl PrivateKey {
         }
         let mut bytes = [0u8; KEY_LEN];
         bytes.copy_from_slice(data);
-        match Scalar::from_canonical_bytes(bytes) {
+        match Option::from(Scalar::from_canonical_bytes(bytes)) {
             Some(scalar) => Ok(PrivateKey(scalar)),
             None => Err(Error::NotCanonicalScalar),
         }
@@ -51,7 +51,7 @@ impl PublicKey {
                 found: data.len(),
             });
         }
-        let point = CompressedEdwardsY::from_slice(data);
+        let point = CompressedEdwardsY::from_slice(data).map_err(|_| Error::InvalidPoint)?;
         match point.decompress() {
             Some(_) => Ok(PublicKey(point)),
             None => Err(Error::InvalidPoint),
@@ -60,7 +60,7 @@ impl PublicKey {
 
     /// Generates a PublicKey derived from a given PrivateKey
     pub fn from_private_key(private_key: &PrivateKey) -> Self {
-        let point = &private_key.as_scalar() * &G_BASEPOINT;
+        let point = &private_key.as_scalar() * G_BASEPOINT;
         PublicKey(point.compress())
     }
 
@@ -127,7 +127,7 @@ mod tests {
     #[test]
     fn test_as_bytes() {
         let value = [1u8; 32];
-        let public_key = PublicKey(CompressedEdwardsY::from_slice(&value));
+        let public_key = PublicKey(CompressedEdwardsY::from_slice(&value).unwrap());
         let bytes = public_key.as_slice();
         assert_eq!(bytes.len(), KEY_LEN);
         assert_eq!(bytes, &value)
@@ -136,7 +136,7 @@ mod tests {
     #[test]
     fn test_to_bytes() {
         let value = [1u8; 32];
-        let public_key = PublicKey(CompressedEdwardsY::from_slice(&value));
+        let public_key = PublicKey(CompressedEdwardsY::from_slice(&value).unwrap());
         let bytes = public_key.to_bytes();
         assert_eq!(bytes.len(), KEY_LEN);
         assert_eq!(bytes, value);
@@ -148,7 +148,7 @@ mod tests {
         let value = [1u8; 32];
         let private_key = PrivateKey::from_scalar(&Scalar::from_bytes_mod_order(value));
         let public_key = PublicKey::from_private_key(&private_key);
-        let expected_point = &private_key.as_scalar() * &G_BASEPOINT;
+        let expected_point = &private_key.as_scalar() * G_BASEPOINT;
         let expected = PublicKey::from_slice(&expected_point.compress().to_bytes());
         assert!(expected.is_ok());
         let expected_pub_key = expected.unwrap();
@@ -166,7 +166,7 @@ mod tests {
     #[test]
     fn test_display() {
         let value = [1u8; 32];
-        let public_key = PublicKey(CompressedEdwardsY::from_slice(&value));
+        let public_key = PublicKey(CompressedEdwardsY::from_slice(&value).unwrap());
         let hex_string = format!("{}", public_key);
         assert_eq!(hex_string, hex::encode(value));
     }
@@ -176,7 +176,7 @@ mod tests {
         let value = [1u8; 32];
         let hex_string = hex::encode(value);
         let public_key = PublicKey::from_str(&hex_string).unwrap();
-        let expected = CompressedEdwardsY::from_slice(&value);
+        let expected = CompressedEdwardsY::from_slice(&value).unwrap();
         assert_eq!(public_key.0, expected);
 
         let hex_string = "not a hex string";
@@ -1,10 +1,8 @@
+use crate::hash_to_point::{hash_to_point, H as H_GENERATOR};
 use anyhow::anyhow;
 use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint, VartimeEdwardsPrecomputation};
 use curve25519_dalek::scalar::Scalar;
-use curve25519_dalek::traits::{Identity, VartimePrecomputedMultiscalarMul};
-use dalek_ff_group::{EdwardsPoint as EdwPntGrp, Scalar as ScGrp};
-use monero_generators::hash_to_point;
-use multiexp::multiexp;
+use curve25519_dalek::traits::{Identity, VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
 use rand::{thread_rng, Rng};
 use thiserror::Error;
 use zeroize::Zeroize;
@@ -163,7 +161,7 @@ impl RctKey {
     /// a valid edwards point This function should only be used with a key
     /// that can be converted to a valid edwards point
     pub fn as_point(&self) -> EdwardsPoint {
-        if let Some(point) = CompressedEdwardsY::from_slice(&self.bytes).decompress() {
+        if let Some(point) = CompressedEdwardsY(self.bytes).decompress() {
             point
         } else {
             panic!("Invalid edwards point for key: {:?}", self.bytes)
@@ -259,7 +257,7 @@ impl EcdhTuple {
     /// Encodes a EcdhTuple from a shared secret for v2 transactions
     pub fn encode(&mut self, shared_secret: &RctKey) {
         let mask = RctKey::zero();
-        let mut amount = &mut self.amount;
+        let amount = &mut self.amount;
         let hash_secret = Self::ecdh_hash(shared_secret);
         for i in 0..8 {
             amount.bytes[i] ^= hash_secret.bytes[i];
@@ -373,6 +371,14 @@ pub struct Bulletproof {
     pub t: RctKey,
 }
 
+/// Computes the sum of the products of the scalars and points in variable time
+fn multiexp(pairs: &[(Scalar, EdwardsPoint)]) -> EdwardsPoint {
+    EdwardsPoint::vartime_multiscalar_mul(
+        pairs.iter().map(|(scalar, _)| scalar),
+        pairs.iter().map(|(_, point)| point),
+    )
+}
+
 /// Adopted from the monero_generators crate
 /// Container struct for Bulletproofs(+) generators.
 #[allow(non_snake_case)]
@@ -384,9 +390,7 @@ pub struct Generators {
 /// Adopted from the monero_generators crate
 /// Generate generators as needed for Bulletproofs(+), as Monero does.
 pub fn bulletproofs_generators(dst: &'static [u8]) -> Result<Generators, anyhow::Error> {
-    let identity_point = CompressedEdwardsY::identity()
-        .decompress()
-        .expect("The identity should be valid Edwards Point");
+    let identity_point = EdwardsPoint::identity();
     let mut res = Generators {
         G: [identity_point; BPP_MAX_MN],
         H: [identity_point; BPP_MAX_MN],
@@ -394,7 +398,7 @@ pub fn bulletproofs_generators(dst: &'static [u8]) -> Result<Generators, anyhow:
     for i in 0..BPP_MAX_MN {
         let i = 2 * i;
 
-        let mut even = monero_generators::H.compress().to_bytes().to_vec();
+        let mut even = H_GENERATOR.compress().to_bytes().to_vec();
         even.extend(dst);
         let mut odd = even.clone();
         let i_0 = VarInt(i as u64);
@@ -460,7 +464,7 @@ impl BulletproofPlus {
     // This function calculates the sv variable values first with the sv values stemming from the
     // amounts argument and the masks argument represents the gamma variable.
     #[allow(non_snake_case)]
-    pub fn new_proof(amounts: &Vec<u64>, masks: &[RctKey]) -> Result<Self, Error> {
+    pub fn new_proof(amounts: &[u64], masks: &[RctKey]) -> Result<Self, Error> {
         let mut sv: Vec<RctKey> = Vec::new();
         for amount in amounts {
             let mut sv_key = RctKey::zero();
@@ -480,7 +484,7 @@ impl BulletproofPlus {
             return Err(Error::ErrorMessage("sv is empty".to_string()));
         }
         for sve in sv.iter() {
-            if Scalar::from_canonical_bytes(sve.bytes).is_none() {
+            if bool::from(Scalar::from_canonical_bytes(sve.bytes).is_none()) {
                 return Err(Error::ErrorMessage("Invalid sv input".to_string()));
             }
         }
@@ -591,7 +595,7 @@ impl BulletproofPlus {
         // d[j*N+i] = z**(2*(j+1)) * 2**i
         //
         // We compute this iteratively in order to reduce scalar operations.
-        let mut d: Vec<Scalar> = vec![Scalar::zero(); MN];
+        let mut d: Vec<Scalar> = vec![Scalar::ZERO; MN];
         d[0] = z_squared;
         for i in 1..N {
             d[i] = d[i - 1] * TWO.as_scalar();
@@ -628,17 +632,17 @@ impl BulletproofPlus {
 
         // Preparing for inner product
         let mut nprime = MN;
-        let mut GPrime = vec![EdwPntGrp(EdwardsPoint::identity()); MN];
-        let mut HPrime = vec![EdwPntGrp(EdwardsPoint::identity()); MN];
-        let mut aprime = vec![Scalar::zero(); MN];
-        let mut bprime = vec![Scalar::zero(); MN];
+        let mut GPrime = vec![EdwardsPoint::identity(); MN];
+        let mut HPrime = vec![EdwardsPoint::identity(); MN];
+        let mut aprime = vec![Scalar::ZERO; MN];
+        let mut bprime = vec![Scalar::ZERO; MN];
 
         let yinv = y.as_scalar().invert();
-        let mut yinvpow: Vec<Scalar> = vec![Scalar::zero(); MN];
+        let mut yinvpow: Vec<Scalar> = vec![Scalar::ZERO; MN];
         yinvpow[0] = ONE.as_scalar();
         for i in 0..MN {
-            GPrime[i] = EdwPntGrp(GENERATORS.G[i]);
-            HPrime[i] = EdwPntGrp(GENERATORS.H[i]);
+            GPrime[i] = GENERATORS.G[i];
+            HPrime[i] = GENERATORS.H[i];
             if i > 0 {
                 yinvpow[i] = yinvpow[i - 1] * yinv;
             }
@@ -649,8 +653,8 @@ impl BulletproofPlus {
         let logN = 6; // based on N = 64 always, log2(64)
         let logMN = logM + logN;
 
-        let mut L: Vec<EdwPntGrp> = vec![EdwPntGrp(EdwardsPoint::identity()); logMN];
-        let mut R: Vec<EdwPntGrp> = vec![EdwPntGrp(EdwardsPoint::identity()); logMN];
+        let mut L: Vec<EdwardsPoint> = vec![EdwardsPoint::identity(); logMN];
+        let mut R: Vec<EdwardsPoint> = vec![EdwardsPoint::identity(); logMN];
         let mut round = 0;
 
         // Inner product rounds
@@ -661,18 +665,13 @@ impl BulletproofPlus {
             let (bprime_left, bprime_right) = bprime.split_at(nprime);
             let (GPrime_left, _GPrime_right) = GPrime.split_at(nprime);
             let (_HPrime_left, HPrime_right) = HPrime.split_at(nprime);
-            let cL = Self::weighted_inner_product(
-                &aprime_left.to_vec(),
-                &bprime_right.to_vec(),
-                &y.as_scalar(),
-            );
+            let cL = Self::weighted_inner_product(aprime_left, bprime_right, &y.as_scalar());
             let vec_scalar = aprime_left
                 .to_vec()
                 .iter()
                 .map(|a| a * y_powers[nprime])
                 .collect::<Vec<Scalar>>();
-            let cR =
-                Self::weighted_inner_product(&vec_scalar, &bprime_left.to_vec(), &y.as_scalar());
+            let cR = Self::weighted_inner_product(&vec_scalar, bprime_left, &y.as_scalar());
             let dL = Scalar::from_bytes_mod_order(thread_rng().gen());
             let dR = Scalar::from_bytes_mod_order(thread_rng().gen());
 
@@ -710,12 +709,8 @@ impl BulletproofPlus {
 
             let temp = yinvpow[nprime] * challenge.as_scalar();
 
-            Self::hadamard_fold(&mut GPrime, ScGrp(challenge_inv), ScGrp(temp));
-            Self::hadamard_fold(
-                &mut HPrime,
-                ScGrp(challenge.as_scalar()),
-                ScGrp(challenge_inv),
-            );
+            Self::hadamard_fold(&mut GPrime, challenge_inv, temp);
+            Self::hadamard_fold(&mut HPrime, challenge.as_scalar(), challenge_inv);
 
             let temp = challenge_inv * y_powers[nprime];
 
@@ -745,27 +740,19 @@ impl BulletproofPlus {
         let d_ = PrivateKey::new().0;
         let eta = PrivateKey::new().0;
 
-        let mut A1_data: Vec<(ScGrp, EdwPntGrp)> = vec![
-            (
-                ScGrp(RctKey::zero().as_scalar()),
-                EdwPntGrp(RctKey::zero().as_point())
-            );
-            4
-        ];
-        A1_data[0] = (ScGrp(r * INV_EIGHT.as_scalar()), GPrime[0]);
-        A1_data[1] = (ScGrp(s * INV_EIGHT.as_scalar()), HPrime[0]);
-        A1_data[2] = (
-            ScGrp(d_ * INV_EIGHT.as_scalar()),
-            EdwPntGrp(G_BASEPOINT.hash_to_point()),
-        );
+        let mut A1_data: Vec<(Scalar, EdwardsPoint)> =
+            vec![(RctKey::zero().as_scalar(), RctKey::zero().as_point()); 4];
+        A1_data[0] = (r * INV_EIGHT.as_scalar(), GPrime[0]);
+        A1_data[1] = (s * INV_EIGHT.as_scalar(), HPrime[0]);
+        A1_data[2] = (d_ * INV_EIGHT.as_scalar(), G_BASEPOINT.hash_to_point());
 
         let mut temp = r * y.as_scalar();
         temp *= bprime[0];
         let mut temp2 = s * y.as_scalar();
         temp2 *= aprime[0];
         temp += temp2;
-        A1_data[3].0 = ScGrp(temp * INV_EIGHT.as_scalar());
-        A1_data[3].1 = EdwPntGrp(H_BASEPOINT.hash_to_point());
+        A1_data[3].0 = temp * INV_EIGHT.as_scalar();
+        A1_data[3].1 = H_BASEPOINT.hash_to_point();
 
         let A1 = multiexp(A1_data.as_slice());
 
@@ -804,10 +791,10 @@ impl BulletproofPlus {
         })
     }
 
-    fn hadamard_fold(v: &mut Vec<EdwPntGrp>, a: ScGrp, b: ScGrp) {
-        assert!(v.len() % 2 == 0, "Vector size should be even");
+    fn hadamard_fold(v: &mut Vec<EdwardsPoint>, a: Scalar, b: Scalar) {
+        assert!(v.len().is_multiple_of(2), "Vector size should be even");
         let sz = v.len() / 2;
-        let mut res = vec![EdwPntGrp(RctKey::zero().as_point()); sz];
+        let mut res = vec![RctKey::zero().as_point(); sz];
 
         for n in 0..sz {
             res[n] = multiexp(&[(a, v[n]), (b, v[sz + n])]);
@@ -820,30 +807,30 @@ impl BulletproofPlus {
     fn compute_LR(
         size: usize,
         y: &Scalar,
-        g: &[EdwPntGrp],
-        h: &[EdwPntGrp],
+        g: &[EdwardsPoint],
+        h: &[EdwardsPoint],
         a: &[Scalar],
         b: &[Scalar],
         c: &Scalar,
         d: &Scalar,
-    ) -> EdwPntGrp {
-        let mut multiexp_data: Vec<(ScGrp, EdwPntGrp)> =
-            vec![(ScGrp(Scalar::zero()), EdwPntGrp(EdwardsPoint::identity())); size * 2 + 2];
+    ) -> EdwardsPoint {
+        let mut multiexp_data: Vec<(Scalar, EdwardsPoint)> =
+            vec![(Scalar::ZERO, EdwardsPoint::identity()); size * 2 + 2];
         let inv_eight = INV_EIGHT.as_scalar();
         for i in 0..size {
             let temp = a[i] * y;
-            multiexp_data[i * 2].0 = ScGrp(temp * inv_eight);
+            multiexp_data[i * 2].0 = temp * inv_eight;
             multiexp_data[i * 2].1 = g[i];
 
-            multiexp_data[i * 2 + 1].0 = ScGrp(b[i] * inv_eight);
+            multiexp_data[i * 2 + 1].0 = b[i] * inv_eight;
             multiexp_data[i * 2 + 1].1 = h[i];
         }
 
-        multiexp_data[size * 2].0 = ScGrp(*c * inv_eight);
-        multiexp_data[size * 2].1 = EdwPntGrp(H_BASEPOINT.hash_to_point());
+        multiexp_data[size * 2].0 = *c * inv_eight;
+        multiexp_data[size * 2].1 = H_BASEPOINT.hash_to_point();
 
-        multiexp_data[size * 2 + 1].0 = ScGrp(*d * inv_eight);
-        multiexp_data[size * 2 + 1].1 = EdwPntGrp(G_BASEPOINT.hash_to_point());
+        multiexp_data[size * 2 + 1].0 = *d * inv_eight;
+        multiexp_data[size * 2 + 1].1 = G_BASEPOINT.hash_to_point();
 
         let result = multiexp(multiexp_data.as_slice());
         result
@@ -853,9 +840,9 @@ impl BulletproofPlus {
     /// another scalar
     ///
     /// Output a_0*b_0*y**1 + a_1*b_1*y**2 + ... + a_{n-1}*b_{n-1}*y**n
-    fn weighted_inner_product(a: &Vec<Scalar>, b: &Vec<Scalar>, y: &Scalar) -> Scalar {
+    fn weighted_inner_product(a: &[Scalar], b: &[Scalar], y: &Scalar) -> Scalar {
         assert_eq!(a.len(), b.len(), "expected a and b to be the same length");
-        let mut res = Scalar::zero();
+        let mut res = Scalar::ZERO;
         let mut y_power = ONE.as_scalar();
         for i in 0..a.len() {
             let temp = a[i] * b[i];
@@ -1042,11 +1029,11 @@ impl Clsag {
     #[allow(clippy::too_many_arguments)]
     pub fn generate(
         message: &RctKey,
-        P: &Vec<RctKey>,
+        P: &[RctKey],
         p: &RctKey,
-        C: &Vec<RctKey>,
+        C: &[RctKey],
         z: &RctKey,
-        C_nonzero: &Vec<RctKey>,
+        C_nonzero: &[RctKey],
         C_offset: &RctKey,
         l: usize,
     ) -> Result<Clsag, anyhow::Error> {
@@ -1300,8 +1287,7 @@ impl RctSig {
     #[allow(non_snake_case)]
     pub fn pre_mlsag_hash(&self) -> Result<RctKey, anyhow::Error> {
         let base = self.base.clone();
-        let mut hashes: Vec<RctKey> = Vec::new();
-        hashes.reserve(3);
+        let mut hashes: Vec<RctKey> = Vec::with_capacity(3);
         hashes.push(base.message);
         let mut _h: CryptoHash;
         if base.mix_ring.is_empty() {
@@ -1384,11 +1370,11 @@ impl RctSig {
             return Err(anyhow!("Signature scalar vector is the wrong size!"));
         }
         for i in 0..n {
-            if Scalar::from_canonical_bytes(sig.s[i].bytes).is_none() {
+            if bool::from(Scalar::from_canonical_bytes(sig.s[i].bytes).is_none()) {
                 return Err(anyhow!("Bad signature scalar!"));
             }
         }
-        if Scalar::from_canonical_bytes(sig.c1.bytes).is_none() {
+        if bool::from(Scalar::from_canonical_bytes(sig.c1.bytes).is_none()) {
             return Err(anyhow!("Bad signature commitment!"));
         }
         if sig.I == RctKey::default() {
@@ -1499,7 +1485,7 @@ impl RctSig {
             i += 1;
         }
         let c_new = c.as_scalar() - sig.c1.as_scalar();
-        if c_new == Scalar::zero() {
+        if c_new == Scalar::ZERO {
             Ok(true)
         } else {
             Ok(false)
@@ -1551,14 +1537,14 @@ impl RctSig {
     #[allow(clippy::too_many_arguments)]
     pub fn generate_rct_simple(
         message: &RctKey,
-        in_sk: &Vec<CtKey>,
-        destinations: &Vec<RctKey>,
-        in_amounts: &Vec<u64>,
-        out_amounts: &Vec<u64>,
+        in_sk: &[CtKey],
+        destinations: &[RctKey],
+        in_amounts: &[u64],
+        out_amounts: &[u64],
         txn_fee: u64,
-        mix_ring: &Vec<Vec<CtKey>>,
-        amount_keys: &Vec<RctKey>,
-        indx: &Vec<u64>,
+        mix_ring: &[Vec<CtKey>],
+        amount_keys: &[RctKey],
+        indx: &[u64],
         out_sk: &mut Vec<CtKey>,
         rct_config: RctConfig,
     ) -> Result<Self, anyhow::Error> {
@@ -1683,7 +1669,7 @@ impl RctSig {
             txn_fee,
             rct_type,
             message: *message,
-            mix_ring: mix_ring.clone(),
+            mix_ring: mix_ring.to_vec(),
             pseudo_outs: pseudo_outs.clone(),
             ecdh_info: rct_ecdh_info.clone(),
             out_pk: rct_out_pk.clone(),
@@ -10,6 +10,7 @@ use crate::Error;
 /// [`BIP44`][HDPurpose::BIP44] uses 44': <https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki>
 /// [`BIP49`][HDPurpose::BIP49] uses 49': <https://github.com/bitcoin/bips/blob/master/bip-0049.mediawiki>
 /// [`BIP84`][HDPurpose::BIP84] uses 84': <https://github.com/bitcoin/bips/blob/master/bip-0084.mediawiki>
+/// [`BIP86`][HDPurpose::BIP86] uses 86': <https://github.com/bitcoin/bips/blob/master/bip-0086.mediawiki>
 /// The [`HDPathBuilder`] struct can be used to set a default purpose value to
 /// use with particular cryptocurrency implementation.
 #[derive(Default, PartialEq, Eq, Copy, Clone, Debug)]
@@ -19,10 +20,14 @@ pub enum HDPurpose {
     BIP32,
     /// BIP44 uses a purpose value of 44'
     BIP44,
+    /// BIP48 uses a purpose value of 48', used for multisig wallets
+    BIP48,
     /// BIP49 uses a purpose value of 49'
     BIP49,
     /// BIP84 uses a purpose value of 84'
     BIP84,
+    /// BIP86 uses a purpose value of 86'
+    BIP86,
 }
 
 impl HDPurpose {
@@ -67,8 +72,10 @@ impl From<&HDPurpose> for HDPathIndex {
         match purpose {
             HDPurpose::BIP32 => HDPathIndex::IndexHardened(0),
             HDPurpose::BIP44 => HDPathIndex::IndexHardened(44),
+            HDPurpose::BIP48 => HDPathIndex::IndexHardened(48),
             HDPurpose::BIP49 => HDPathIndex::IndexHardened(49),
             HDPurpose::BIP84 => HDPathIndex::IndexHardened(84),
+            HDPurpose::BIP86 => HDPathIndex::IndexHardened(86),
         }
     }
 }
@@ -80,8 +87,10 @@ impl TryFrom<HDPathIndex> for HDPurpose {
         match path_index {
             HDPathIndex::IndexHardened(0) => Ok(HDPurpose::BIP32),
             HDPathIndex::IndexHardened(44) => Ok(HDPurpose::BIP44),
+            HDPathIndex::IndexHardened(48) => Ok(HDPurpose::BIP48),
             HDPathIndex::IndexHardened(49) => Ok(HDPurpose::BIP49),
             HDPathIndex::IndexHardened(84) => Ok(HDPurpose::BIP84),
+            HDPathIndex::IndexHardened(86) => Ok(HDPurpose::BIP86),
             _ => Err(Error::Invalid(format!(
                 "Cannot convert {} to HDPurpose",
                 path_index
@@ -97,8 +106,10 @@ impl FromStr for HDPurpose {
         match s {
             "0'" | "0h" => Ok(HDPurpose::BIP32),
             "44'" | "44h" => Ok(HDPurpose::BIP44),
+            "48'" | "48h" => Ok(HDPurpose::BIP48),
             "49'" | "49h" => Ok(HDPurpose::BIP49),
             "84'" | "84h" => Ok(HDPurpose::BIP84),
+            "86'" | "86h" => Ok(HDPurpose::BIP86),
             _ => Err(Error::FromStr(format!(
                 "Unknown purpose, unknown deriv type {}",
                 s
@@ -620,4 +631,22 @@ mod tests {
         let dt = HDPurpose::BIP32;
         assert_eq!(format!("{}", dt), "0'");
     }
+
+    #[test]
+    fn test_bip86_purpose() -> Result<(), Error> {
+        let purpose = HDPurpose::BIP86;
+        assert_eq!(format!("{}", purpose), "86'");
+        assert_eq!(purpose.to_shortform_num(), 86);
+        assert_eq!(HDPurpose::from_str("86h")?, purpose);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bip48_purpose() -> Result<(), Error> {
+        let purpose = HDPurpose::BIP48;
+        assert_eq!(format!("{}", purpose), "48'");
+        assert_eq!(purpose.to_shortform_num(), 48);
+        assert_eq!(HDPurpose::from_str("48'")?, purpose);
+        Ok(())
+    }
 }
@@ -95,13 +95,17 @@ impl fmt::LowerHex for ExtendedPublicKey {
 ///
 ///
 /// A [HDNetworkType] can be used to map to a more blockchain-specific network type when used with a specific cryptocurrency.
+/// More network types may be added without a breaking release, so matching on a [HDNetworkType] needs a wildcard arm.
 #[derive(Default, PartialEq, Eq, Copy, Clone, Debug)]
+#[non_exhaustive]
 pub enum HDNetworkType {
     #[default]
     /// MainNet used for the production network
     MainNet,
     /// TestNet used for any development or test network
     TestNet,
+    /// Signet used for signed test networks such as the Bitcoin signet, keys are serialized like [HDNetworkType::TestNet] keys
+    Signet,
 }
 
 impl fmt::Display for HDNetworkType {
@@ -109,6 +113,7 @@ impl fmt::Display for HDNetworkType {
         match self {
             HDNetworkType::MainNet => fmt.write_str("mainnet")?,
             HDNetworkType::TestNet => fmt.write_str("testnet")?,
+            HDNetworkType::Signet => fmt.write_str("signet")?,
         };
         Ok(())
     }
@@ -121,7 +126,7 @@ impl fmt::Display for HDNetworkType {
 /// [HDKey] follows the BIP32 scheme: <https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki>
 /// [HDKey] also follows the purpose scheme described in BIP43: <https://github.com/bitcoin/bips/blob/master/bip-0043.mediawiki>
 /// The [HDPurpose] enum supports the following purpose types: BIP32, BIP44,
-/// BIP49, and BIP84.
+/// BIP49, BIP84, and BIP86.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HDKey {
     /// The seed used to create the master node
@@ -319,7 +324,7 @@ impl HDKey {
         let mut private_key: Vec<u8> = Vec::new();
         match self.network {
             HDNetworkType::MainNet => private_key.push(0x80),
-            HDNetworkType::TestNet => private_key.push(0xef),
+            HDNetworkType::TestNet | HDNetworkType::Signet => private_key.push(0xef),
         }
         private_key.append(&mut self.extended_private_key()?.to_bytes().to_vec());
         // assuming public key is compressed
@@ -441,18 +446,26 @@ impl HDKey {
             || (purpose == HDPurpose::BIP44)
         {
             Ok([0x04, 0x88, 0xAD, 0xE4])
-        } else if self.network == HDNetworkType::TestNet && (purpose == HDPurpose::BIP32)
+        } else if self.network != HDNetworkType::MainNet && (purpose == HDPurpose::BIP32)
             || (purpose == HDPurpose::BIP44)
         {
             Ok([0x04, 0x35, 0x83, 0x94])
         } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP49 {
             Ok([0x04, 0x9D, 0x78, 0x78])
-        } else if self.network == HDNetworkType::TestNet && purpose == HDPurpose::BIP49 {
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP49 {
             Ok([0x04, 0x4A, 0x4E, 0x28])
         } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP84 {
             Ok([0x04, 0xB2, 0x43, 0x0C])
-        } else if self.network == HDNetworkType::TestNet && purpose == HDPurpose::BIP84 {
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP84 {
             Ok([0x04, 0x5F, 0x18, 0xBC])
+        } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP86 {
+            Ok([0x04, 0x88, 0xAD, 0xE4])
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP86 {
+            Ok([0x04, 0x35, 0x83, 0x94])
+        } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP48 {
+            Ok([0x04, 0x88, 0xAD, 0xE4])
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP48 {
+            Ok([0x04, 0x35, 0x83, 0x94])
         } else {
             Err(Error::CurrentlyNotSupported(
                 "Prefix is not set up for this yet".into(),
@@ -467,18 +480,26 @@ impl HDKey {
             || (purpose == HDPurpose::BIP44)
         {
             Ok([0x04, 0x88, 0xB2, 0x1E])
-        } else if self.network == HDNetworkType::TestNet && (purpose == HDPurpose::BIP32)
+        } else if self.network != HDNetworkType::MainNet && (purpose == HDPurpose::BIP32)
             || (purpose == HDPurpose::BIP44)
         {
             Ok([0x04, 0x35, 0x87, 0xCF])
         } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP49 {
             Ok([0x04, 0x9D, 0x7C, 0xB2])
-        } else if self.network == HDNetworkType::TestNet && purpose == HDPurpose::BIP49 {
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP49 {
             Ok([0x04, 0x4A, 0x52, 0x62])
         } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP84 {
             Ok([0x04, 0xB2, 0x47, 0x46])
-        } else if self.network == HDNetworkType::TestNet && purpose == HDPurpose::BIP84 {
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP84 {
             Ok([0x04, 0x5F, 0x1C, 0xF6])
+        } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP86 {
+            Ok([0x04, 0x88, 0xB2, 0x1E])
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP86 {
+            Ok([0x04, 0x35, 0x87, 0xCF])
+        } else if self.network == HDNetworkType::MainNet && purpose == HDPurpose::BIP48 {
+            Ok([0x04, 0x88, 0xB2, 0x1E])
+        } else if self.network != HDNetworkType::MainNet && purpose == HDPurpose::BIP48 {
+            Ok([0x04, 0x35, 0x87, 0xCF])
         } else {
             Err(Error::CurrentlyNotSupported(
                 "Prefix is not set up for this yet".into(),
@@ -677,4 +698,24 @@ mod tests {
         .unwrap();
         assert_eq!(keys.extended_public_key_serialized().unwrap(), "xpub661MyMwAqRbcFXMyiJX7c6ibHGtcUga5EJ5AGk2wpmtJToYC21K3osXhNPGsUzwLzHJDKShvbH6ZAHF4DB3eCKK9ya271pXyWABaBjRPorF")
     }
+
+    #[test]
+    fn test_signet_serialization() -> Result<(), Error> {
+        let seed = Seed::new(vec![
+            162, 253, 156, 5, 34, 216, 77, 82, 238, 76, 133, 51, 220, 2, 212, 182, 155, 77, 249,
+            182, 37, 94, 26, 242, 12, 159, 29, 77, 105, 22, 137, 242, 163, 134, 55, 235, 30, 199,
+            120, 151, 43, 248, 69, 195, 45, 90, 232, 60, 117, 54, 153, 155, 86, 102, 57, 122, 195,
+            32, 33, 178, 30, 10, 204, 238,
+        ]);
+        let testnet = HDKey::new_master(seed.clone(), HDNetworkType::TestNet)?;
+        let signet = HDKey::new_master(seed, HDNetworkType::Signet)?;
+        assert!(signet.extended_public_key_serialized()?.starts_with("tpub"));
+        assert_eq!(
+            signet.extended_private_key_serialized()?,
+            testnet.extended_private_key_serialized()?
+        );
+        assert_eq!(signet.to_wif()?, testnet.to_wif()?);
+        assert_eq!(signet.network.to_string(), "signet");
+        Ok(())
+    }
 }
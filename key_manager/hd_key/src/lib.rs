@@ -2,7 +2,7 @@
 //!
 //! Library for generating [BIP32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) compliant HD keys to facilitate
 //! Hierarchical Deterministic (HD) wallets. Supports multiple HD key derivation
-//! paths including BIP44, BIP49, and BIP84. Has support for customization of
+//! paths including BIP44, BIP48, BIP49, BIP84, and BIP86. Has support for customization of
 //! the derivation path.
 //!
 //! # Quickstart Guide
@@ -2,7 +2,7 @@ use walletd_monero_mnemonic::{
     Language, Mnemonic, MnemonicExt, MnemonicStyleBuilder, MnemonicType,
 };
 
-fn main() -> () {
+fn main() {
     // example of how to generate a mnemonic phrase using the builder pattern
     println!("Example of generating a mnemonic phrase using the builder pattern");
     let test = format!("{}", Language::English);
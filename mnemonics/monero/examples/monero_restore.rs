@@ -1,6 +1,6 @@
 use walletd_monero_mnemonic::{Language, Mnemonic, MnemonicExt, MnemonicStyleBuilder};
 
-fn main() -> () {
+fn main() {
     // Restore a mnemonic struct from a phrase using the builder pattern
     println!("Example of restoring a mnemonic struct from a phrase using the builder pattern");
     let phrase: &str = "buzzer eject zeal algebra adept arrow shipped mobile reorder light plus rover fawns fight aphid powder tufts niche plotting acumen equip civilian camp dialect algebra";
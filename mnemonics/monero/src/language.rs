@@ -15,9 +15,8 @@ use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
 
-use crc::{crc32, Ha
-use walletd_mnemonics_core::Languag
-use crate::Error;
+use crate::{Error, LanguageExt};
+use crc::{crc32, Hasher32};
 
 #[derive(Debug)]
 pub struct WordList {
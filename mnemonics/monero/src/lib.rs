@@ -27,10 +27,12 @@ mod errors;
 mod language;
 mod mnemonic;
 mod mnemonic_type;
+mod traits;
 
-pub use language::Language;
-pub use walletd_mnemonics_core::{MnemonicExt, MnemonicStyleBuilder, Seed};
 pub use self::errors::Error;
 use self::language::WordList;
 pub use self::mnemonic::{Mnemonic, MnemonicBuilder};
 pub use self::mnemonic_type::MnemonicType;
+pub use self::traits::{LanguageExt, MnemonicExt, MnemonicStyleBuilder};
+pub use language::Language;
+pub use walletd_mnemonics_core::Seed;
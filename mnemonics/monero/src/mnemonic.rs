@@ -1,11 +1,11 @@
 use core::str;
 use std::fmt;
 
+use crate::mnemonic_type::BITS_IN_BYTES;
+use crate::{Error, Language, MnemonicExt, MnemonicStyleBuilder, MnemonicType, WordList};
 use curve25519_dalek::scalar::Scalar;
 use rand::{thread_rng, Rng};
-use walletd_mnemonics_core::{MnemonicExt, MnemonicStyleBuilder, Seed};
-use crate::mnemonic_type::BITS_IN_BYTES;
-use crate::{Error, Language, MnemonicType, WordList};
+use walletd_mnemonics_core::Seed;
 
 /// The primary type in this crate, most tasks require creating or using one.
 ///
@@ -334,7 +334,7 @@ impl MnemonicStyleBuilder for MnemonicBuilder {
                     let wordlist_info = WordList::new(language);
                     let bytes_length = mnemonic_type.entropy_bits() / BITS_IN_BYTES;
                     let phrase = Mnemonic::bytes_to_words(
-                        &specified_seed.as_bytes()[0..bytes_length].to_vec(),
+                        &specified_seed.as_bytes()[0..bytes_length],
                         &wordlist_info,
                     )?;
                     // Final seed will be encypted if a passphrase is provided
@@ -439,9 +439,9 @@ impl Mnemonic {
     /// Directly converts bytes to words following the algorithm in Monero's
     /// codebase Assumes that the bytes given as an argument correspond to a
     /// valid mnemonic type length
-    fn bytes_to_words(entropy_bytes: &Vec<u8>, wordlist_info: &WordList) -> Result<String, Error> {
+    fn bytes_to_words(entropy_bytes: &[u8], wordlist_info: &WordList) -> Result<String, Error> {
         let wordlist = &wordlist_info.inner();
-        if entropy_bytes.len() % 4 != 0 || entropy_bytes.is_empty() {
+        if !entropy_bytes.len().is_multiple_of(4) || entropy_bytes.is_empty() {
             return Err(Error::ErrorInBytes(
                 "Length of secret_bytes must be greater than 0 and divisible by 4".into(),
             ));
@@ -552,7 +552,7 @@ mod tests {
     #[test]
     fn test_print() {
         let phrase: &str = "buzzer eject zeal algebra adept arrow shipped mobile reorder light plus rover fawns fight aphid powder tufts niche plotting acumen equip civilian camp dialect algebra";
-        let mnemonic = Mnemonic::builder().set_phrase(&phrase).restore().unwrap();
+        let mnemonic = Mnemonic::builder().set_phrase(phrase).restore().unwrap();
         assert_eq!(mnemonic.phrase(), phrase);
         assert_eq!(mnemonic.language(), Language::English);
         assert_eq!(mnemonic.mnemonic_type(), MnemonicType::Words25);
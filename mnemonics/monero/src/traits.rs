@@ -0,0 +1,103 @@
+use walletd_mnemonics_core::Seed;
+
+/// The MnemonicExt trait provides a common interface for creating, restoring
+/// and inspecting a mnemonic
+pub trait MnemonicExt {
+    /// The associated error type
+    type ErrorType;
+    /// The associated Language struct
+    type LanguageExt;
+    /// The associated Mnemonic struct
+    type MnemonicStyle;
+    /// The associated Builder struct for the Mnemonic
+    type MnemonicStyleBuilder;
+    /// The associated MnemonicType struct
+    type MnemonicTypeSpec;
+
+    /// Recovers a mnemonic given the language, mnemonic phrase, and optional
+    /// passphrase
+    fn from_phrase(
+        language: Self::LanguageExt,
+        phrase: &str,
+        specified_passphrase: Option<&str>,
+    ) -> Result<Self::MnemonicStyle, Self::ErrorType>;
+
+    /// Returns the builder for the mnemonic
+    fn builder() -> Self::MnemonicStyleBuilder;
+
+    /// Generates a new mnemonic given the language, length of mnemonic, and
+    /// optional passphrase
+    fn new(
+        language: Self::LanguageExt,
+        mnemonic_type: Self::MnemonicTypeSpec,
+        passphrase: Option<&str>,
+    ) -> Self::MnemonicStyle;
+
+    /// Recovers a mnemonic given the mnemonic phrase and optional passphrase,
+    /// attempts to auto detect the language of the mnemonic phrase
+    fn detect_language(
+        phrase: &str,
+        specified_passphrase: Option<&str>,
+    ) -> Result<Self::MnemonicStyle, Self::ErrorType>;
+
+    /// Returns the [Seed] associated with the mnemonic phrase
+    fn to_seed(&self) -> Seed;
+
+    /// Returns the language for the mnemonic
+    fn language(&self) -> Self::LanguageExt;
+
+    /// Returns the mnemonic phrase
+    fn phrase(&self) -> String;
+
+    /// Returns the mnemonic type
+    fn mnemonic_type(&self) -> Self::MnemonicTypeSpec;
+}
+
+/// The MnemonicStyleBuilder trait implements a builder pattern for creating a
+/// mnemonic
+pub trait MnemonicStyleBuilder {
+    /// The associated error type
+    type ErrorType;
+    /// The associated Language struct
+    type LanguageExt;
+    /// The associated Mnemonic struct
+    type MnemonicStyle;
+    /// The associated MnemonicType struct
+    type MnemonicTypeSpec;
+
+    /// Creates a new builder with default values
+    fn new() -> Self;
+
+    /// Specifies the seed the mnemonic is recovered from
+    fn set_seed(&mut self, seed: &Seed) -> Self;
+
+    /// Specifies the mnemonic phrase the mnemonic is recovered from
+    fn set_phrase(&mut self, mnemonic_phrase: &str) -> Self;
+
+    /// Specifies the language of the mnemonic
+    fn set_language(&mut self, language: Self::LanguageExt) -> Self;
+
+    /// Specifies the passphrase the seed is encrypted with
+    fn set_passphrase(&mut self, passphrase: &str) -> Self;
+
+    /// Specifies the mnemonic type
+    fn set_mnemonic_type(&mut self, mnemonic_type: Self::MnemonicTypeSpec) -> Self;
+
+    /// Clears the language so that it is detected from the phrase
+    fn detect_language(&mut self) -> Self;
+
+    /// Restores a previously generated mnemonic from its phrase or seed
+    fn restore(&self) -> Result<Self::MnemonicStyle, Self::ErrorType>;
+
+    /// Generates a new mnemonic with the specified options
+    fn generate(&self) -> Result<Self::MnemonicStyle, Self::ErrorType>;
+}
+
+/// The LanguageExt trait provides a common interface for mnemonic languages
+pub trait LanguageExt {
+    /// The associated Language enum
+    type Language;
+
+    /// Returns the default language
+    fn new() -> Self;
+}
@@ -22,7 +22,7 @@ async fn main() -> Result<(), walletd::Error> {
     let blockchain = ElectrumBlockchain::from(client);
     btc_wallet.sync(&blockchain).await?;
     println!(
-        "btc_wallet balance: {} satoshi",
+        "btc_wallet balance: {}",
         btc_wallet.balance().await?.confirmed
     );
 
@@ -15,16 +15,29 @@ async fn main() -> Result<(), Error> {
     let mnemonic_phrase = "joy tail arena mix other envelope diary achieve short nest true vocal";
     let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
 
-    // derive the Bitcoin wallet from the HD wallet
-    let mut btc_wallet = BitcoinWallet::builder()
-        .mnemonic(mnemonic.clone())
-        .network_type(Network::Testnet)
-        .build()?;
-
-    // Searches for past transactions on first account using default of HDPurpose::BIP84 (default for BTC), can have other options to specify a different deriv path to search with or to search past the first account or change the gap limit ex: .discover_accounts() or .set_derive_type(HDPurpose::BIP44)
-    // TODO(#79): Expose more user options on the sync of the BTC wallet
+    // Searches for the accounts with past transactions across the BIP44, BIP49, BIP84 and BIP86 derivation paths, the gap limit defaults to 20 and can be changed with .gap_limit()
     let client = Client::new("ssl://electrum.blockstream.info:60002").unwrap();
     let blockchain = ElectrumBlockchain::from(client);
+    let mut btc_builder = BitcoinWallet::builder();
+    btc_builder
+        .mnemonic(mnemonic.clone())
+        .network_type(Network::Testnet);
+    let accounts = btc_builder.discover_accounts(&blockchain).await?;
+    for account in &accounts {
+        println!(
+            "Found {} transactions on account {} of {:?}",
+            account.transaction_count, account.account_index, account.hd_purpose
+        );
+    }
+
+    // derive the Bitcoin wallet of the first account found, or of the first BIP84 account (default for BTC) if there is no history
+    if let Some(account) = accounts.first() {
+        btc_builder
+            .address_format(account.address_format)
+            .hd_purpose(account.hd_purpose)
+            .account_index(account.account_index);
+    }
+    let mut btc_wallet = btc_builder.build()?;
     btc_wallet.sync(&blockchain).await?;
 
     // Going to switch to ETH
@@ -36,8 +49,9 @@ async fn main() -> Result<(), Error> {
     // Gets the current balances for the BTC wallet and ETH wallet
     let current_btc_balance = btc_wallet.balance().await?;
     println!(
-        "Current BTC balance: {} satoshi",
-        current_btc_balance.confirmed
+        "Current BTC balance: {} ({} satoshi)",
+        current_btc_balance.confirmed,
+        current_btc_balance.confirmed.sat()
     );
     let current_eth_balance = eth_wallet.balance(&provider).await?;
     println!(
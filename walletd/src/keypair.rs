@@ -1,6 +1,7 @@
 use crate::{HDKey, HDNetworkType};
 use ::walletd_mnemonics_core::Seed;
 use bdk::keys::bip39::Mnemonic;
+use walletd_coin_core::{CryptoWallet, CryptoWalletBuilder};
 
 use crate::Error;
 
@@ -192,19 +193,20 @@ impl KeyPair {
         self.style
     }
 
-    // Derives a wallet of the specified generic type T from the [KeyPair] struct
-    // T must implement the CryptoWallet trait
-    // # Errors
-    // Returns an [Error] vairant if the wallet of type T could not be derived
-    // pub fn derive_wallet<T>(&self) -> Result<T, Error>
-    // where
-    //     T: WalletBuilder,
-    // {
-    //     let wallet: T = T::builder()
-    //         .master_hd_key(self.to_master_key())
-    //         .build()
-    //         .map_err(|e| Error::DeriveWallet(e.to_string()))
-    //         .unwrap();
-    //     Ok(wallet)
-    // }
+    /// Derives a wallet of the specified generic type T from the [KeyPair] struct
+    ///
+    /// T must implement the [CryptoWallet] trait, the wallet is built from the mnemonic seed and the network type of the [KeyPair]
+    ///
+    /// # Errors
+    /// Returns an [Error::DeriveWallet] variant if the wallet of type T could not be derived
+    pub fn derive_wallet<T>(&self) -> Result<T, Error>
+    where
+        T: CryptoWallet,
+    {
+        T::builder()
+            .mnemonic_seed(self.mnemonic_seed())
+            .hd_network_type(self.network_type)
+            .build()
+            .map_err(|e| Error::DeriveWallet(e.to_string()))
+    }
 }
@@ -42,19 +42,24 @@
 //!
 //! ### Derive Wallets
 //!
-//! The method can be used to derive a wallet for a specific cryptocurrency from a [KeyPair].
+//! The [`derive_wallet`][KeyPair::derive_wallet] method can be used to derive a wallet for a specific cryptocurrency from a [KeyPair].
 //! You can specify a concrete struct such as [BitcoinWallet]  or [EthereumWallet] to derive a cryptowallet from the `keypair` of the specified concrete type.
+//! Any type implementing the [CryptoWallet] trait can be derived this way.
 //! ```
 //! use walletd::prelude::*;
 //! use walletd_bitcoin::prelude::*;
 //! use walletd_ethereum::prelude::*;
-//! use bdk::bitcoin::Network;
 //! fn main() -> Result<(), walletd::Error> {
 //! let mnemonic_phrase = "outer ride neither foil glue number place usage ball shed dry point";
-//! let mnemonic = Mnemonic::parse(mnemonic_phrase).unwrap();
+//! let keypair = KeyPair::builder()
+//!     .mnemonic_phrase(mnemonic_phrase.into())
+//!     .network_type(HDNetworkType::TestNet)
+//!     .build()?;
 //!
-//! let mut btc_wallet = BitcoinWalletBuilder::new().mnemonic(mnemonic.clone()).network_type(Network::Testnet).build().unwrap();
-//! let mut eth_wallet = EthereumWalletBuilder::new().mnemonic(mnemonic).build().unwrap();
+//! let btc_wallet = keypair.derive_wallet::<BitcoinWallet>()?;
+//! let eth_wallet = keypair.derive_wallet::<EthereumWallet>()?;
+//! println!("bitcoin receive address: {}", btc_wallet.receive_address()?);
+//! println!("ethereum public address: {}", eth_wallet.public_address());
 //! Ok(())
 //! }
 //! ```
@@ -87,6 +92,8 @@ pub use walletd_mnemonics_core::Seed;
 mod keypair;
 pub use keypair::{KeyPair, KeyPairBuilder, MnemonicKeyPairType};
 
+pub use walletd_coin_core::{CryptoWallet, CryptoWalletBuilder};
+
 pub use walletd_bitcoin::BitcoinWallet;
 
 pub use walletd_ethereum::{EthClient, EthereumAmount, EthereumWallet};
@@ -96,7 +103,9 @@ pub use walletd_ethereum::{EthClient, EthereumAmount, EthereumWallet};
 // pub use walletd_solana::solana_account::SolanaAccount;
 
 pub use walletd_hd_key::{HDKey, HDNetworkType, HDPath, HDPathBuilder, HDPathIndex, HDPurpose};
-pub use {walletd_bitcoin, walletd_ethereum, walletd_hd_key, walletd_mnemonics_core};
+pub use {
+    walletd_bitcoin, walletd_coin_core, walletd_ethereum, walletd_hd_key, walletd_mnemonics_core,
+};
 
 // Due to package conflicts, solana is commented out at present
 
@@ -18,6 +18,9 @@
 
 pub use crate::{KeyPair, KeyPairBuilder, MnemonicKeyPairType};
 pub use bdk::keys::bip39::Mnemonic;
+pub use walletd_coin_core::prelude::*;
 pub use walletd_hd_key::prelude::*;
+// Seed is the only item of the mnemonics prelude for now and is also exported by the hd key prelude
+#[allow(unused_imports)]
 pub use walletd_mnemonics_core::prelude::*;
 pub use walletd_mnemonics_core::Seed;